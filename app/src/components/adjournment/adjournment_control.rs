@@ -0,0 +1,130 @@
+use leptos::*;
+use leptos_use::{use_interval_fn, utils::Pausable};
+use uuid::Uuid;
+
+use super::adjournment_server_function::{answer_adjournment, get_adjournment, offer_adjournment};
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::player_color::PlayerColor;
+
+const ADJOURNMENT_REFRESH_INTERVAL_IN_MS: u64 = 2000;
+
+
+#[component]
+pub fn AdjournmentControl(
+    #[prop(into)]
+    game_id: Signal<Uuid>,
+    #[prop(into)]
+    player_number: RwSignal<Option<usize>>,
+    #[prop(into)]
+    player_secret: RwSignal<Option<String>>,
+    #[prop(into)]
+    error_message: RwSignal<Option<String>>,
+    reassign_player: impl Fn() + Clone + 'static,
+) -> impl IntoView {
+    let locale = use_locale();
+    let adjournment = create_resource(
+        move || game_id.get(),
+        |game_id| async move { get_adjournment(game_id).await.ok() },
+    );
+    let Pausable { .. } = use_interval_fn(
+        move || {
+            adjournment.refetch();
+        },
+        ADJOURNMENT_REFRESH_INTERVAL_IN_MS,
+    );
+    let player = move || player_number.get().and_then(PlayerColor::from_player_number);
+    let (request_pending, set_request_pending) = create_signal(false);
+
+    let offer = move |_| {
+        let (Some(player_number), Some(player_secret)) = (player_number.get_untracked(), player_secret.get_untracked()) else {
+            return;
+        };
+        set_request_pending.set(true);
+        spawn_local(async move {
+            if let Err(error) = offer_adjournment(game_id.get_untracked(), player_number, player_secret).await {
+                logging::error!("Adjournment offer failed: {:?}", error);
+                error_message.set(Some(error.translate(locale.get_untracked())));
+            }
+            adjournment.refetch();
+            set_request_pending.set(false);
+        });
+    };
+    let answer = move |accept: bool| {
+        let (Some(player_number), Some(player_secret)) = (player_number.get_untracked(), player_secret.get_untracked()) else {
+            return;
+        };
+        set_request_pending.set(true);
+        spawn_local(async move {
+            if let Err(error) = answer_adjournment(game_id.get_untracked(), player_number, player_secret, accept).await {
+                logging::error!("Adjournment answer failed: {:?}", error);
+                error_message.set(Some(error.translate(locale.get_untracked())));
+            }
+            adjournment.refetch();
+            set_request_pending.set(false);
+        });
+    };
+
+    view! {
+        <Transition fallback=|| ()>
+            {
+                let reassign_player = reassign_player.clone();
+                move || adjournment.get().flatten().map(|adjournment| {
+                    let reassign_player = reassign_player.clone();
+                    let view = match player() {
+                        None if adjournment.is_adjourned => view! {
+                            <p>{Text::GameAdjourned.translate(locale.get())}</p>
+                        }.into_view(),
+                        None => ().into_view(),
+                        Some(player) if adjournment.is_adjourned && adjournment.resumed_by.contains(&player) => view! {
+                            <p>{Text::WaitingForOpponentToResume.translate(locale.get())}</p>
+                        }.into_view(),
+                        Some(_) if adjournment.is_adjourned => view! {
+                            <p>{Text::GameAdjourned.translate(locale.get())}</p>
+                            <button
+                                class="btn btn-primary btn-xs ml-2"
+                                disabled=request_pending
+                                on:click=move |_| reassign_player()
+                            >
+                                {Text::ResumeGame.translate(locale.get())}
+                            </button>
+                        }.into_view(),
+                        Some(player) if adjournment.offered_by == Some(player) => view! {
+                            <p>{Text::YouOfferedAdjournment.translate(locale.get())}</p>
+                        }.into_view(),
+                        Some(player) if adjournment.offered_by == Some(player.opponent()) => view! {
+                            <p>{Text::OpponentOffersAdjournment.translate(locale.get())}</p>
+                            <button
+                                class="btn btn-primary btn-xs ml-2"
+                                disabled=request_pending
+                                on:click=move |_| answer(true)
+                            >
+                                {Text::Accept.translate(locale.get())}
+                            </button>
+                            <button
+                                class="btn btn-xs ml-2"
+                                disabled=request_pending
+                                on:click=move |_| answer(false)
+                            >
+                                {Text::Decline.translate(locale.get())}
+                            </button>
+                        }.into_view(),
+                        Some(_) => view! {
+                            <button
+                                class="btn btn-xs"
+                                disabled=request_pending
+                                on:click=offer
+                            >
+                                {Text::OfferAdjournment.translate(locale.get())}
+                            </button>
+                        }.into_view(),
+                    };
+                    view! {
+                        <div class="p-2 w-full flex justify-center items-center">
+                            {view}
+                        </div>
+                    }
+                })
+            }
+        </Transition>
+    }
+}
@@ -0,0 +1,118 @@
+use leptos::*;
+use uuid::Uuid;
+
+use crate::game::adjournment::{Adjournment, AdjournmentError};
+#[cfg(feature = "ssr")]
+use crate::game::player_color::PlayerColor;
+#[cfg(feature = "ssr")]
+use crate::pages::game_page::Game;
+
+
+#[server(GetAdjournment, "/api")]
+pub async fn get_adjournment(
+    game_id: Uuid,
+) -> Result<Adjournment, ServerFnError> {
+    Ok(get_game(game_id).await?.adjournment().await)
+}
+
+#[server(OfferAdjournment, "/api")]
+pub async fn offer_adjournment(
+    game_id: Uuid,
+    player_number: usize,
+    player_secret: String,
+) -> Result<(), ServerFnError<AdjournmentError>> {
+    let game = get_game(game_id).await?;
+    let player = verify_player(&game, player_number, &player_secret).await?;
+    if game.result().await.is_some() {
+        return Err(ServerFnError::WrappedServerError(AdjournmentError::GameNotRunning));
+    }
+
+    logging::log!("Player {} offers adjournment in game {}", player, game_id);
+    let mut result = Ok(());
+    game.with_adjournment(|mut adjournment| {
+        result = adjournment.offer(player);
+        adjournment
+    }).await;
+    result.map_err(ServerFnError::WrappedServerError)
+}
+
+#[server(AnswerAdjournment, "/api")]
+pub async fn answer_adjournment(
+    game_id: Uuid,
+    player_number: usize,
+    player_secret: String,
+    accept: bool,
+) -> Result<(), ServerFnError<AdjournmentError>> {
+    use chrono::Utc;
+
+    let game = get_game(game_id).await?;
+    let player = verify_player(&game, player_number, &player_secret).await?;
+    if !accept {
+        logging::log!("Player {} declines adjournment in game {}", player, game_id);
+        game.with_adjournment(|mut adjournment| {
+            adjournment.decline();
+            adjournment
+        }).await;
+        return Ok(());
+    }
+
+    let _lock = game.lock().await;
+    let mut result = Ok(());
+    game.with_adjournment(|mut adjournment| {
+        result = adjournment.accept(player);
+        adjournment
+    }).await;
+    if result.is_ok() {
+        logging::log!("Game {} adjourned", game_id);
+        game.with_clock(|mut clock| {
+            clock.pause(Utc::now());
+            clock
+        }).await;
+    }
+    result.map_err(ServerFnError::WrappedServerError)
+}
+
+/// Called for every re-authenticated seat, restarts the clock once both seats are back.
+#[cfg(feature = "ssr")]
+pub async fn resume_adjourned_game(game: &Game, player: PlayerColor) {
+    use chrono::Utc;
+
+    if !game.adjournment().await.is_adjourned {
+        return;
+    }
+
+    let mut is_resumed = false;
+    game.with_adjournment(|mut adjournment| {
+        is_resumed = adjournment.resume(player).unwrap_or(false);
+        adjournment
+    }).await;
+    if is_resumed {
+        logging::log!("Game {} resumed", game.id().await);
+        game.with_clock(|mut clock| {
+            clock.resume(Utc::now());
+            clock
+        }).await;
+    }
+}
+
+#[cfg(feature = "ssr")]
+async fn verify_player(game: &Game, player_number: usize, player_secret: &str) -> Result<PlayerColor, ServerFnError<AdjournmentError>> {
+    let players = game.players().await;
+    match (PlayerColor::from_player_number(player_number), players.get(player_number)) {
+        (Some(player), Some(player_info)) if player_info.has_secret(player_secret) => Ok(player),
+        _ => Err(ServerFnError::WrappedServerError(AdjournmentError::InvalidPlayerSecret)),
+    }
+}
+
+#[cfg(feature = "ssr")]
+async fn get_game<E>(game_id: Uuid) -> Result<Game, ServerFnError<E>> {
+    use std::sync::Arc;
+    use leptos_axum::extract;
+    use axum::extract::Extension;
+    use crate::pages::game_page::GameState;
+
+    let game_state = extract::<Extension<Arc<GameState>>>().await
+        .expect("Cannot get the game-state extension.");
+    game_state.get_game(game_id).await
+        .ok_or_else(|| ServerFnError::ServerError(format!("Game {} not found.", game_id)))
+}
@@ -0,0 +1,6 @@
+mod adjournment_server_function;
+mod adjournment_control;
+
+pub use adjournment_control::AdjournmentControl as AdjournmentControl;
+#[cfg(feature = "ssr")]
+pub use adjournment_server_function::resume_adjourned_game as resume_adjourned_game;
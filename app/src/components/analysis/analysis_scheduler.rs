@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use leptos::*;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+use uuid::Uuid;
+
+use crate::components::hub::HubEngine;
+use crate::pages::game_page::{Game, GameState};
+use crate::game::analysis::{positions, Analyzer};
+use crate::game::board::Position;
+use crate::game::engine::{Score, SearchLimits};
+use crate::game::hub::{HubError, HubLevel};
+use crate::game::moves::Move;
+use crate::game::opening_book::OpeningBook;
+use crate::game::tablebase::Tablebase;
+
+const ANALYSIS_SCHEDULER_INTERVAL_IN_MS: u64 = 1000;
+/// Every position of a game is searched, so each search gets less time than a bot move.
+const ANALYSIS_LIMITS: SearchLimits = SearchLimits { max_depth: 10, max_nodes: 200_000 };
+/// The Hub engine counts nodes its own way, so it is limited by depth and time instead.
+const HUB_ANALYSIS_LEVEL: HubLevel = HubLevel {
+    depth: Some(ANALYSIS_LIMITS.max_depth),
+    nodes: None,
+    move_time: Some(Duration::from_millis(500)),
+    moves: None,
+    time: None,
+};
+
+
+/// Background task, which queues every finished game for the analysis worker. The games
+///  are analysed one after the other, so a burst of finished games does not take all cores
+///  from the bots. With a Hub engine the positions are evaluated by it, the built-in engine
+///  only analyses the games the Hub engine fails on.
+pub async fn run_analysis_scheduler(
+    game_state: Arc<GameState>,
+    opening_book: Option<Arc<OpeningBook>>,
+    tablebase: Option<Arc<Tablebase>>,
+    hub_engine: Option<Arc<HubEngine>>,
+) {
+    let (sender, receiver) = unbounded_channel();
+    tokio::spawn(run_analysis_worker(receiver, opening_book, tablebase, hub_engine));
+    let mut queued_games: HashSet<Uuid> = HashSet::new();
+    let mut interval = tokio::time::interval(Duration::from_millis(ANALYSIS_SCHEDULER_INTERVAL_IN_MS));
+    loop {
+        interval.tick().await;
+        for game in game_state.games().await {
+            if game.result().await.is_none() || game.analysis().await.is_some() {
+                continue;
+            }
+            if queued_games.insert(game.id().await) && sender.send(game).is_err() {
+                logging::error!("The analysis worker stopped.");
+                return;
+            }
+        }
+    }
+}
+
+async fn run_analysis_worker(
+    mut receiver: UnboundedReceiver<Game>,
+    opening_book: Option<Arc<OpeningBook>>,
+    tablebase: Option<Arc<Tablebase>>,
+    hub_engine: Option<Arc<HubEngine>>,
+) {
+    let new_analyzer = move || {
+        let mut analyzer = Analyzer::new(ANALYSIS_LIMITS);
+        analyzer.set_opening_book(opening_book.clone());
+        analyzer.set_tablebase(tablebase.clone());
+        analyzer
+    };
+    let mut analyzer = new_analyzer();
+    while let Some(game) = receiver.recv().await {
+        let game_id = game.id().await;
+        let initial_position = game.initial_position().await;
+        let moves: Vec<Move> = game.moves().await.into_iter().map(|recorded_move| recorded_move.mv).collect();
+        logging::log!("Analysing game {}", game_id);
+        if let Some(hub_engine) = &hub_engine {
+            match evaluate_with_hub_engine(hub_engine, game_id, initial_position, &moves).await {
+                Ok(evaluations) => {
+                    game.set_analysis(Some(analyzer.judge(initial_position, &moves, &evaluations))).await;
+                    continue;
+                }
+                Err(error) => logging::warn!("Hub engine failed to analyse game {}, using the built-in engine: {}", game_id, error),
+            }
+        }
+        let analysis = tokio::task::spawn_blocking(move || {
+            let analysis = analyzer.analyze(initial_position, &moves);
+            (analyzer, analysis)
+        }).await;
+        match analysis {
+            Ok((used_analyzer, analysis)) => {
+                analyzer = used_analyzer;
+                game.set_analysis(Some(analysis)).await;
+            }
+            Err(error) => {
+                logging::error!("Analysis failed in game {}: {:?}", game_id, error);
+                analyzer = new_analyzer();
+            }
+        }
+    }
+}
+
+/// The score and the best move of every position of the game, from the point of view of the
+///  side to move. The positions are searched from the last one, because engines answer a
+///  forced move at once without a score, which is then taken from the position after it.
+async fn evaluate_with_hub_engine(
+    hub_engine: &HubEngine,
+    game_id: Uuid,
+    initial_position: Position,
+    moves: &[Move],
+) -> Result<Vec<(Score, Option<Move>)>, HubError> {
+    let mut evaluations = Vec::new();
+    let mut next_score: Option<Score> = None;
+    for (ply, position) in positions(initial_position, moves).iter().enumerate().rev() {
+        let result = hub_engine.search(game_id, position, HUB_ANALYSIS_LEVEL, None).await?;
+        let score = result.score
+            .or_else(|| next_score.filter(|_| result.best_move.as_ref() == moves.get(ply)).map(|score| -score))
+            .ok_or_else(|| HubError::InvalidMessage(format!("No score for ply {}.", ply)))?;
+        next_score = Some(score);
+        evaluations.push((score, result.best_move));
+    }
+    evaluations.reverse();
+    Ok(evaluations)
+}
@@ -0,0 +1,22 @@
+use leptos::*;
+use uuid::Uuid;
+
+use crate::game::analysis::GameAnalysis;
+
+
+/// The analysis of a finished game, `None` while it is still running or queued.
+#[server(GetGameAnalysis, "/api")]
+pub async fn get_game_analysis(
+    game_id: Uuid,
+) -> Result<Option<GameAnalysis>, ServerFnError> {
+    use std::sync::Arc;
+    use leptos_axum::extract;
+    use axum::extract::Extension;
+    use crate::pages::game_page::GameState;
+
+    let game_state = extract::<Extension<Arc<GameState>>>().await
+        .expect("Cannot get the game-state extension.");
+    let game = game_state.get_game(game_id).await
+        .ok_or_else(|| ServerFnError::ServerError(format!("Game {} not found.", game_id)))?;
+    Ok(game.analysis().await)
+}
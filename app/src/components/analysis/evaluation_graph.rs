@@ -0,0 +1,90 @@
+use leptos::*;
+
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::analysis::{GameAnalysis, MoveJudgement};
+use crate::game::engine::Score;
+
+const GRAPH_WIDTH: f64 = 100.0;
+const GRAPH_HEIGHT: f64 = 40.0;
+/// Scores beyond three men are drawn at the edge of the graph.
+const MAX_GRAPH_SCORE: Score = 300;
+
+
+/// The score after every ply as a line, red's advantage upwards. Judged moves are marked
+///  and clicking the graph shows the position of the ply below the pointer.
+#[component]
+pub fn EvaluationGraph(
+    #[prop(into)]
+    analysis: Signal<GameAnalysis>,
+    #[prop(into)]
+    viewed_ply: RwSignal<Option<usize>>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let scores = move || analysis.with(GameAnalysis::scores);
+    let number_of_plies = move || analysis.with(|analysis| analysis.plies.len());
+    let x = move |ply: usize| ply as f64 * GRAPH_WIDTH / number_of_plies().max(1) as f64;
+    let y = |score: Score| {
+        let score = score.clamp(-MAX_GRAPH_SCORE, MAX_GRAPH_SCORE) as f64;
+        GRAPH_HEIGHT / 2.0 - score * GRAPH_HEIGHT / 2.0 / MAX_GRAPH_SCORE as f64
+    };
+    let points = move || {
+        scores().into_iter().enumerate()
+            .map(|(ply, score)| format!("{:.2},{:.2}", x(ply), y(score)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let area = move || format!("0,{:.2} {} {:.2},{:.2}", GRAPH_HEIGHT / 2.0, points(), x(number_of_plies()), GRAPH_HEIGHT / 2.0);
+    let current_ply = move || viewed_ply.get().unwrap_or_else(number_of_plies);
+    let show_ply = move |ply: usize| viewed_ply.set((ply < number_of_plies()).then_some(ply));
+
+    view! {
+        <svg
+            class="w-full h-32 bg-base-200 rounded"
+            viewBox=format!("0 0 {} {}", GRAPH_WIDTH, GRAPH_HEIGHT)
+            preserveAspectRatio="none"
+            role="img"
+            aria-label=move || Text::EvaluationGraph.translate(locale.get())
+        >
+            <polygon points=area class="fill-red-800 opacity-40"/>
+            <line x1="0" y1={GRAPH_HEIGHT / 2.0} x2=GRAPH_WIDTH y2={GRAPH_HEIGHT / 2.0} class="stroke-base-content opacity-30" stroke-width="0.2"/>
+            <polyline points=points fill="none" class="stroke-base-content" stroke-width="0.4" vector-effect="non-scaling-stroke"/>
+            <line
+                x1=move || x(current_ply()) y1="0" x2=move || x(current_ply()) y2=GRAPH_HEIGHT
+                class="stroke-primary" stroke-width="0.3"
+            />
+            {move || analysis.with(|analysis| {
+                analysis.plies.iter().enumerate()
+                    .filter_map(|(index, ply)| ply.judgement.map(|judgement| (index + 1, ply.score, judgement)))
+                    .map(|(ply, score, judgement)| view! {
+                        <circle cx=x(ply) cy=y(score) r="0.8" class=judgement_fill_class(judgement)/>
+                    })
+                    .collect_view()
+            })}
+            // one invisible column per ply, so the whole height is clickable
+            {move || (0..=number_of_plies()).map(|ply| view! {
+                <rect
+                    x={x(ply) - x(1) / 2.0} y="0" width=x(1) height=GRAPH_HEIGHT
+                    fill="transparent" class="cursor-pointer"
+                    on:click=move |_| show_ply(ply)
+                />
+            }).collect_view()}
+        </svg>
+    }
+}
+
+fn judgement_fill_class(judgement: MoveJudgement) -> &'static str {
+    match judgement {
+        MoveJudgement::Inaccuracy => "fill-info",
+        MoveJudgement::Mistake => "fill-warning",
+        MoveJudgement::Blunder => "fill-error",
+    }
+}
+
+/// The text colour of a move strength suffix.
+pub fn judgement_text_class(judgement: MoveJudgement) -> &'static str {
+    match judgement {
+        MoveJudgement::Inaccuracy => "text-info",
+        MoveJudgement::Mistake => "text-warning",
+        MoveJudgement::Blunder => "text-error",
+    }
+}
@@ -0,0 +1,89 @@
+use leptos::*;
+
+use super::evaluation_graph::{judgement_text_class, EvaluationGraph};
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::analysis::GameAnalysis;
+use crate::game::game_result::GameResult;
+use crate::game::player_color::PlayerColor;
+
+
+/// The analysis of a finished game: the evaluation graph and every judged move with the
+///  move the engine prefers. Clicking a move shows the position before it.
+#[component]
+pub fn GameAnalysisPanel(
+    #[prop(into)]
+    analysis: Signal<Option<GameAnalysis>>,
+    #[prop(into)]
+    result: Signal<Option<GameResult>>,
+    /// The moves of the game in numeric notation.
+    #[prop(into)]
+    moves: Signal<Vec<String>>,
+    #[prop(into)]
+    first_player: Signal<PlayerColor>,
+    #[prop(into)]
+    viewed_ply: RwSignal<Option<usize>>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let number_of_plies = move || moves.with(Vec::len);
+    let show_ply = move |ply: usize| viewed_ply.set((ply < number_of_plies()).then_some(ply));
+    let move_number = move |ply: usize| {
+        let offset = if first_player.get() == PlayerColor::Red { 0 } else { 1 };
+        let full_move = (ply - 1 + offset) / 2 + 1;
+        if (ply - 1 + offset) % 2 == 0 { format!("{}.", full_move) } else { format!("{}...", full_move) }
+    };
+    let judged_moves = move || {
+        let analysis = analysis.get().unwrap_or_default();
+        let moves = moves.get();
+        analysis.plies.into_iter().zip(moves).enumerate()
+            .filter_map(|(index, (ply_analysis, notation))| {
+                let judgement = ply_analysis.judgement?;
+                Some((index + 1, notation, judgement, ply_analysis.better_move))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <Show when=move || result.get().is_some()>
+            <section class="p-2 flex flex-col items-center w-full max-w-2xl mx-auto">
+                <h2 class="font-bold">{move || Text::Analysis.translate(locale.get())}</h2>
+                <Show
+                    when=move || analysis.get().is_some()
+                    fallback=move || view! {
+                        <div class="flex items-center m-1">
+                            <span class="loading loading-spinner text-primary mr-2" role="status"></span>
+                            <p>{move || Text::AnalysisPending.translate(locale.get())}</p>
+                        </div>
+                    }
+                >
+                    <EvaluationGraph
+                        analysis=Signal::derive(move || analysis.get().unwrap_or_default())
+                        viewed_ply=viewed_ply
+                    />
+                    <Show
+                        when=move || !judged_moves().is_empty()
+                        fallback=move || view! { <p class="m-1">{move || Text::NoMistakesFound.translate(locale.get())}</p> }
+                    >
+                        <table class="table table-xs">
+                            <tbody>
+                                {move || judged_moves().into_iter().map(|(ply, notation, judgement, better_move)| view! {
+                                    <tr>
+                                        <td>
+                                            <button class="btn btn-ghost btn-xs font-mono" on:click=move |_| show_ply(ply - 1)>
+                                                {move_number(ply)}" "{notation}
+                                                <span class=judgement_text_class(judgement)>{judgement.symbol()}</span>
+                                            </button>
+                                        </td>
+                                        <td class=judgement_text_class(judgement)>{move || judgement.translate(locale.get())}</td>
+                                        <td class="font-mono">
+                                            {better_move.clone().map(|better_move| Text::BetterMove(better_move).translate(locale.get()))}
+                                        </td>
+                                    </tr>
+                                }).collect_view()}
+                            </tbody>
+                        </table>
+                    </Show>
+                </Show>
+            </section>
+        </Show>
+    }
+}
@@ -0,0 +1,12 @@
+mod analysis_server_function;
+mod use_game_analysis;
+mod evaluation_graph;
+mod game_analysis;
+#[cfg(feature = "ssr")]
+mod analysis_scheduler;
+
+pub use use_game_analysis::use_game_analysis as use_game_analysis;
+pub use game_analysis::GameAnalysisPanel as GameAnalysisPanel;
+pub use evaluation_graph::judgement_text_class as judgement_text_class;
+#[cfg(feature = "ssr")]
+pub use analysis_scheduler::run_analysis_scheduler as run_analysis_scheduler;
@@ -0,0 +1,38 @@
+use leptos::*;
+use leptos_use::{use_interval_fn, utils::Pausable};
+use uuid::Uuid;
+
+use super::analysis_server_function::get_game_analysis;
+use crate::game::analysis::GameAnalysis;
+use crate::game::game_result::GameResult;
+
+/// Finished games wait in a queue for the analysis worker, so the client asks again until
+///  the analysis is there.
+const ANALYSIS_REFRESH_INTERVAL_IN_MS: u64 = 5000;
+
+
+/// The analysis of the game, `None` while the game is running or the analysis is pending.
+pub fn use_game_analysis(
+    game_id: Signal<Uuid>,
+    result: Signal<Option<GameResult>>,
+) -> Signal<Option<GameAnalysis>> {
+    let analysis = create_resource(
+        move || (game_id.get(), result.with(Option::is_some)),
+        |(game_id, is_finished)| async move {
+            if !is_finished {
+                return None;
+            }
+            get_game_analysis(game_id).await.ok().flatten()
+        },
+    );
+    let Pausable { .. } = use_interval_fn(
+        move || {
+            let is_pending = analysis.with_untracked(|analysis| matches!(analysis, Some(None)));
+            if is_pending && result.with_untracked(Option::is_some) {
+                analysis.refetch();
+            }
+        },
+        ANALYSIS_REFRESH_INTERVAL_IN_MS,
+    );
+    Signal::derive(move || analysis.get().flatten())
+}
@@ -0,0 +1,77 @@
+use leptos::*;
+
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::pdn::MOVE_STRENGTHS;
+use crate::game::variation_tree::{NodeId, VariationTree};
+
+
+/// Annotates the shown move with move strength glyphs and a comment, or removes its line.
+#[component]
+pub fn AnnotationEditor(
+    #[prop(into)]
+    tree: RwSignal<VariationTree>,
+    #[prop(into)]
+    current: RwSignal<NodeId>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let has_move = move || current.get() != VariationTree::ROOT;
+    let has_nag = move |nag: u8| tree.with(|tree| tree.node(current.get()).is_some_and(|node| node.nags.contains(&nag)));
+    let comment = move || tree.with(|tree| tree.node(current.get()).map(|node| node.comment.clone()).unwrap_or_default());
+    let is_main_move = move || tree.with(|tree| {
+        let id = current.get();
+        tree.node(id).and_then(|node| node.parent).and_then(|parent| tree.node(parent))
+            .map_or(true, |parent| parent.children.first() == Some(&id))
+    });
+
+    view! {
+        <div class="flex flex-col items-center p-2">
+            <Show when=has_move>
+                <div class="flex justify-center" role="group">
+                    {MOVE_STRENGTHS.into_iter().map(|(symbol, nag)| view! {
+                        <button
+                            class="btn btn-xs m-1 font-mono"
+                            class:btn-active=move || has_nag(nag)
+                            aria-pressed=move || has_nag(nag).to_string()
+                            on:click=move |_| tree.update(|tree| tree.toggle_nag(current.get_untracked(), nag))
+                        >
+                            {symbol}
+                        </button>
+                    }).collect_view()}
+                </div>
+            </Show>
+            <textarea
+                class="textarea textarea-bordered textarea-xs w-72 m-1"
+                placeholder=move || Text::Comment.translate(locale.get())
+                aria-label=move || Text::Comment.translate(locale.get())
+                prop:value=comment
+                on:change=move |ev| {
+                    let comment = event_target_value(&ev);
+                    tree.update(|tree| tree.set_comment(current.get_untracked(), comment));
+                }
+            />
+            <Show when=has_move>
+                <div class="flex justify-center">
+                    <Show when=move || !is_main_move()>
+                        <button
+                            class="btn btn-xs m-1"
+                            on:click=move |_| tree.update(|tree| tree.promote(current.get_untracked()))
+                        >
+                            {move || Text::PromoteVariation.translate(locale.get())}
+                        </button>
+                    </Show>
+                    <button
+                        class="btn btn-xs btn-error m-1"
+                        on:click=move |_| {
+                            let id = current.get_untracked();
+                            let parent = tree.with_untracked(|tree| tree.node(id).and_then(|node| node.parent));
+                            current.set(parent.unwrap_or(VariationTree::ROOT));
+                            tree.update(|tree| tree.delete(id));
+                        }
+                    >
+                        {move || Text::DeleteVariation.translate(locale.get())}
+                    </button>
+                </div>
+            </Show>
+        </div>
+    }
+}
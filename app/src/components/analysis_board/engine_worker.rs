@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::engine::Score;
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub struct PositionAnalysisRequest {
+    /// The position to analyse as FEN.
+    pub fen: String,
+    /// The worker answers after every depth up to this one.
+    pub depth: u8,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub struct PositionAnalysisResponse {
+    /// The position of the request, so answers to outdated requests can be ignored.
+    pub fen: String,
+    pub depth: u8,
+    /// The score from the point of view of the side to move.
+    pub score: Score,
+    /// The best move in numeric notation, `None` if the side to move cannot move.
+    pub best_move: Option<String>,
+}
+
+/// A search never visits more positions than this, so deep requests still finish.
+#[cfg(feature = "hydrate")]
+const MAX_NODES: u64 = 2_000_000;
+
+
+#[cfg(feature = "hydrate")]
+thread_local! {
+    static ENGINE: std::cell::RefCell<crate::game::engine::Engine> = std::cell::RefCell::new(crate::game::engine::Engine::new());
+}
+
+/// Entry point of the engine web worker in `public/analysis_worker.js`, which calls it once
+///  per depth. Takes and returns JSON, the engine is kept between calls, so every depth
+///  starts from the results of the previous one.
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn analyze_position(request: &str) -> String {
+    use crate::game::board::Position;
+    use crate::game::engine::SearchLimits;
+    use crate::game::notation::format_move;
+
+    let Ok(request) = serde_json::from_str::<PositionAnalysisRequest>(request) else {
+        return String::new();
+    };
+    let Ok(position) = Position::from_fen(&request.fen) else {
+        return String::new();
+    };
+    let result = ENGINE.with_borrow_mut(|engine| {
+        engine.search(&position, SearchLimits { max_depth: request.depth, max_nodes: MAX_NODES })
+    });
+    serde_json::to_string(&PositionAnalysisResponse {
+        fen: request.fen,
+        depth: result.depth,
+        score: result.score,
+        best_move: result.best_move.map(|mv| format_move(&position, &mv)),
+    }).unwrap_or_default()
+}
@@ -0,0 +1,76 @@
+use leptos::*;
+
+use super::engine_worker::{PositionAnalysisRequest, PositionAnalysisResponse};
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::board::Position;
+use crate::game::engine::{Score, WIN_SCORE};
+use crate::game::moves::Move;
+use crate::game::notation::parse_move;
+use crate::game::player_color::PlayerColor;
+use crate::utils::use_worker::{use_worker, UseWorkerResult};
+
+const ANALYSIS_WORKER_URL: &str = "/analysis_worker.js";
+const LIVE_ANALYSIS_DEPTH: u8 = 16;
+/// Scores this close to a win are forced wins found by the search.
+const FORCED_WIN_SCORE: Score = WIN_SCORE - 1000;
+
+
+/// The engine's evaluation of the position, which deepens while the position is shown.
+#[component]
+pub fn LiveEvaluation(
+    #[prop(into)]
+    position: Signal<Position>,
+    /// Plays the best move on the board.
+    #[prop(into)]
+    on_move: Callback<Move>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let UseWorkerResult { response, post, .. } = use_worker::<PositionAnalysisRequest, PositionAnalysisResponse>(ANALYSIS_WORKER_URL);
+    create_effect(move |_| {
+        post(PositionAnalysisRequest { fen: position.get().to_fen(), depth: LIVE_ANALYSIS_DEPTH });
+    });
+    // answers for an earlier position are outdated
+    let evaluation = Signal::derive(move || {
+        response.get().filter(|response| response.fen == position.with(Position::to_fen))
+    });
+    let best_move = move || {
+        let position = position.get();
+        evaluation.get()?.best_move.and_then(|notation| parse_move(&position, &notation).ok())
+    };
+    let score_text = move |response: &PositionAnalysisResponse| {
+        let side_to_move = position.with(|position| position.side_to_move);
+        // shown from red's point of view, like the evaluation graph
+        let score = if side_to_move == PlayerColor::Red { response.score } else { -response.score };
+        match score {
+            score if score > FORCED_WIN_SCORE => Text::ForcedWin(PlayerColor::Red).translate(locale.get()),
+            score if score < -FORCED_WIN_SCORE => Text::ForcedWin(PlayerColor::Blue).translate(locale.get()),
+            score => format!("{:+.2}", score as f64 / 100.0),
+        }
+    };
+
+    view! {
+        <div class="flex justify-center items-center p-2" aria-live="polite">
+            {move || match evaluation.get() {
+                Some(response) => view! {
+                    <p class="m-1 font-mono">{score_text(&response)}</p>
+                    <p class="m-1 text-xs">{Text::SearchDepth(response.depth).translate(locale.get())}</p>
+                    {response.best_move.map(|notation| view! {
+                        <button
+                            class="btn btn-xs m-1 font-mono"
+                            on:click=move |_| {
+                                if let Some(mv) = best_move() {
+                                    on_move.call(mv);
+                                }
+                            }
+                        >
+                            {Text::BestMove(notation).translate(locale.get())}
+                        </button>
+                    })}
+                }.into_view(),
+                None => view! {
+                    <span class="loading loading-spinner text-primary m-1" role="status" aria-label=move || Text::ComputerThinking.translate(locale.get())></span>
+                }.into_view(),
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,41 @@
+mod engine_worker;
+mod live_evaluation;
+mod variation_tree_view;
+mod annotation_editor;
+
+use crate::game::variation_tree::{NodeId, VariationTree};
+use crate::utils::url_encoding::encode_query_value;
+
+pub use engine_worker::PositionAnalysisRequest as PositionAnalysisRequest;
+pub use engine_worker::PositionAnalysisResponse as PositionAnalysisResponse;
+pub use live_evaluation::LiveEvaluation as LiveEvaluation;
+pub use variation_tree_view::VariationTreeView as VariationTreeView;
+pub use annotation_editor::AnnotationEditor as AnnotationEditor;
+
+pub const ANALYSIS_BOARD_PATH: &str = "/analysis";
+/// The query parameter holding the tree as PDN.
+pub const PDN_PARAMETER: &str = "pdn";
+/// The query parameter holding the path of the shown node, see `VariationTree::path`.
+pub const NODE_PARAMETER: &str = "node";
+
+
+/// A link to the analysis board showing the node of the tree, which works without storing
+///  anything on the server.
+pub fn analysis_board_url(tree: &VariationTree, node: NodeId) -> String {
+    let path = tree.path(node).iter().map(usize::to_string).collect::<Vec<_>>().join(".");
+    format!(
+        "{}?{}={}&{}={}",
+        ANALYSIS_BOARD_PATH,
+        PDN_PARAMETER, encode_query_value(&tree.to_pdn().to_string()),
+        NODE_PARAMETER, path,
+    )
+}
+
+/// The node of a link created by `analysis_board_url`, `None` if the path is invalid.
+pub fn parse_node_path(tree: &VariationTree, path: &str) -> Option<NodeId> {
+    if path.is_empty() {
+        return Some(VariationTree::ROOT);
+    }
+    let path = path.split('.').map(str::parse::<usize>).collect::<Result<Vec<_>, _>>().ok()?;
+    tree.node_at_path(&path)
+}
@@ -0,0 +1,82 @@
+use leptos::*;
+
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::notation::format_move;
+use crate::game::pdn::format_nag;
+use crate::game::player_color::PlayerColor;
+use crate::game::variation_tree::{NodeId, VariationTree};
+
+
+/// The explored lines as text like in a book: the main line with every alternative
+///  indented below the move it replaces. Clicking a move shows its position.
+#[component]
+pub fn VariationTreeView(
+    #[prop(into)]
+    tree: Signal<VariationTree>,
+    #[prop(into)]
+    current: RwSignal<NodeId>,
+) -> impl IntoView {
+    let locale = use_locale();
+
+    view! {
+        <nav class="flex flex-col w-72 overflow-y-auto max-h-96 text-sm" aria-label=move || Text::Variations.translate(locale.get())>
+            {move || tree.with(|tree| {
+                let root = tree.node(VariationTree::ROOT).expect("The root is never deleted.");
+                view! {
+                    {(!root.comment.is_empty()).then(|| view! { <p class="italic">{root.comment.clone()}</p> })}
+                    {root.children.first().map(|&first| line_view(tree, first, current))}
+                }
+            })}
+        </nav>
+    }
+}
+
+/// The line starting with the node, each main move followed by its alternatives.
+fn line_view(tree: &VariationTree, first: NodeId, current: RwSignal<NodeId>) -> View {
+    let first_player = tree.initial_position().side_to_move;
+    let mut views = Vec::new();
+    let mut is_line_start = true;
+    let mut next = Some(first);
+    while let Some(id) = next {
+        let node = tree.node(id).expect("Children of existing nodes exist.");
+        let parent = tree.node(node.parent.expect("Only the root has no parent.")).expect("The parent exists.");
+        let ply = tree.line(id).len() - 1;
+        let offset = if first_player == PlayerColor::Red { 0 } else { 1 };
+        let full_move = (ply - 1 + offset) / 2 + 1;
+        let move_number = match parent.position.side_to_move {
+            PlayerColor::Red => Some(format!("{}.", full_move)),
+            PlayerColor::Blue if is_line_start => Some(format!("{}...", full_move)),
+            PlayerColor::Blue => None,
+        };
+        let notation = format_move(&parent.position, node.mv.as_ref().expect("Only the root has no move."));
+        let nags: String = node.nags.iter().map(|&nag| format_nag(nag)).collect();
+        views.push(view! {
+            <span class="inline-flex items-center">
+                {move_number.map(|move_number| view! { <span class="mr-1">{move_number}</span> })}
+                <button
+                    class="btn btn-ghost btn-xs font-mono px-1"
+                    class:btn-active=move || current.get() == id
+                    aria-current=move || (current.get() == id).then_some("step")
+                    on:click=move |_| current.set(id)
+                >
+                    {notation}{nags}
+                </button>
+            </span>
+        }.into_view());
+        is_line_start = false;
+        if !node.comment.is_empty() {
+            views.push(view! { <span class="italic mx-1">{node.comment.clone()}</span> }.into_view());
+            is_line_start = true;
+        }
+        if parent.children.first() == Some(&id) && parent.children.len() > 1 {
+            for &sibling in &parent.children[1..] {
+                views.push(view! {
+                    <div class="ml-2 pl-2 border-l border-base-content/30">{line_view(tree, sibling, current)}</div>
+                }.into_view());
+            }
+            is_line_start = true;
+        }
+        next = node.children.first().copied();
+    }
+    views.into_view()
+}
@@ -0,0 +1,246 @@
+use core::fmt;
+use leptos::*;
+use leptos_use::{use_preferred_reduced_motion, ReducedMotionType, UseCookieOptions, SameSite};
+use codee::string::JsonSerdeCodec;
+use serde::{Deserialize, Serialize};
+
+use crate::game::player_color::PlayerColor;
+use crate::utils::use_cookie_signal::{use_cookie_signal, UseCookieSignalResult};
+
+const APPEARANCE_COOKIE_NAME: &str = "appearance";
+
+
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum BoardTheme {
+    #[default]
+    Wood,
+    Green,
+    Gray,
+}
+
+impl BoardTheme {
+    pub fn all() -> Vec<BoardTheme> {
+        vec![BoardTheme::Wood, BoardTheme::Green, BoardTheme::Gray]
+    }
+
+    pub fn light_square_class(&self) -> &'static str {
+        match self {
+            BoardTheme::Wood => "fill-amber-100",
+            BoardTheme::Green => "fill-lime-100",
+            BoardTheme::Gray => "fill-gray-300",
+        }
+    }
+
+    pub fn dark_square_class(&self) -> &'static str {
+        match self {
+            BoardTheme::Wood => "fill-amber-800",
+            BoardTheme::Green => "fill-green-700",
+            BoardTheme::Gray => "fill-gray-600",
+        }
+    }
+
+    pub fn coordinate_class(&self) -> &'static str {
+        match self {
+            BoardTheme::Wood => "fill-amber-200",
+            BoardTheme::Green => "fill-lime-200",
+            BoardTheme::Gray => "fill-gray-200",
+        }
+    }
+}
+
+impl fmt::Display for BoardTheme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BoardTheme::Wood => write!(f, "Wood"),
+            BoardTheme::Green => write!(f, "Green"),
+            BoardTheme::Gray => write!(f, "Gray"),
+        }
+    }
+}
+
+
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum PieceSet {
+    #[default]
+    Flat,
+    ThreeD,
+    HighContrast,
+}
+
+impl PieceSet {
+    pub fn all() -> Vec<PieceSet> {
+        vec![PieceSet::Flat, PieceSet::ThreeD, PieceSet::HighContrast]
+    }
+
+    pub fn piece_class(&self, color: PlayerColor) -> &'static str {
+        match (self, color) {
+            (PieceSet::HighContrast, PlayerColor::Red) => "fill-black stroke-white",
+            (PieceSet::HighContrast, PlayerColor::Blue) => "fill-white stroke-black",
+            (_, PlayerColor::Red) => "fill-red-700 stroke-red-950",
+            (_, PlayerColor::Blue) => "fill-blue-700 stroke-blue-950",
+        }
+    }
+
+    /// The id of the SVG gradient used as fill, only 3D pieces are shaded.
+    pub fn gradient_id(&self, color: PlayerColor) -> Option<&'static str> {
+        match (self, color) {
+            (PieceSet::ThreeD, PlayerColor::Red) => Some("red-piece-gradient"),
+            (PieceSet::ThreeD, PlayerColor::Blue) => Some("blue-piece-gradient"),
+            _ => None,
+        }
+    }
+
+    pub fn king_mark_class(&self) -> &'static str {
+        match self {
+            PieceSet::HighContrast => "fill-none stroke-yellow-400",
+            _ => "fill-none stroke-yellow-300",
+        }
+    }
+
+    pub fn stroke_width(&self) -> f64 {
+        match self {
+            PieceSet::HighContrast => 0.08,
+            _ => 0.04,
+        }
+    }
+}
+
+impl fmt::Display for PieceSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PieceSet::Flat => write!(f, "Flat"),
+            PieceSet::ThreeD => write!(f, "3D"),
+            PieceSet::HighContrast => write!(f, "High contrast"),
+        }
+    }
+}
+
+
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum CoordinateDisplay {
+    Hidden,
+    /// The standard square numbers from 1 to 32 on the playable squares.
+    #[default]
+    SquareNumbers,
+    /// Files and ranks along the edges of the board.
+    Edges,
+}
+
+impl CoordinateDisplay {
+    pub fn all() -> Vec<CoordinateDisplay> {
+        vec![CoordinateDisplay::Hidden, CoordinateDisplay::SquareNumbers, CoordinateDisplay::Edges]
+    }
+}
+
+impl fmt::Display for CoordinateDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CoordinateDisplay::Hidden => write!(f, "No coordinates"),
+            CoordinateDisplay::SquareNumbers => write!(f, "Square numbers"),
+            CoordinateDisplay::Edges => write!(f, "Files and ranks"),
+        }
+    }
+}
+
+
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum ColorTheme {
+    #[default]
+    Dark,
+    Light,
+}
+
+impl ColorTheme {
+    pub fn all() -> Vec<ColorTheme> {
+        vec![ColorTheme::Dark, ColorTheme::Light]
+    }
+
+    /// The daisyUI theme name.
+    pub fn data_theme(&self) -> &'static str {
+        match self {
+            ColorTheme::Dark => "dark",
+            ColorTheme::Light => "light",
+        }
+    }
+}
+
+impl fmt::Display for ColorTheme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ColorTheme::Dark => write!(f, "Dark"),
+            ColorTheme::Light => write!(f, "Light"),
+        }
+    }
+}
+
+
+/// How the pages and the board look and sound, chosen per browser.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+#[serde(default)]
+pub struct Appearance {
+    pub board_theme: BoardTheme,
+    pub piece_set: PieceSet,
+    pub coordinates: CoordinateDisplay,
+    pub color_theme: ColorTheme,
+    pub animations: bool,
+    pub sounds: bool,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            board_theme: BoardTheme::default(),
+            piece_set: PieceSet::default(),
+            coordinates: CoordinateDisplay::default(),
+            color_theme: ColorTheme::default(),
+            animations: true,
+            sounds: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct AppearanceContext {
+    pub appearance: ReadSignal<Appearance>,
+    pub set_appearance: WriteSignal<Appearance>,
+}
+
+
+/// Reads the appearance from its cookie and provides it to all components below, every
+///  change is written back to the cookie.
+pub fn provide_appearance() -> AppearanceContext {
+    let UseCookieSignalResult {
+        signal_reader: appearance,
+        signal_writer: set_appearance,
+        store_value: store_appearance,
+    } = use_cookie_signal::<Appearance, JsonSerdeCodec>(
+        Appearance::default(),
+        APPEARANCE_COOKIE_NAME,
+        UseCookieOptions::default()
+            .max_age::<i64>(Some(1000*60*60*24*365)) // 1 year
+            .same_site(SameSite::Lax)
+        );
+    create_effect(move |_| {
+        appearance.track();
+        store_appearance();
+    });
+
+    let context = AppearanceContext { appearance, set_appearance };
+    provide_context(context);
+    context
+}
+
+/// The appearance provided by `provide_appearance`, the default one without it.
+pub fn use_appearance() -> Signal<Appearance> {
+    match use_context::<AppearanceContext>() {
+        Some(context) => context.appearance.into(),
+        None => Signal::derive(Appearance::default),
+    }
+}
+
+/// Moves are animated unless turned off or the browser asks for reduced motion.
+pub fn use_animations_enabled() -> Signal<bool> {
+    let appearance = use_appearance();
+    let reduced_motion = use_preferred_reduced_motion();
+    Signal::derive(move || appearance.get().animations && reduced_motion.get() != ReducedMotionType::Reduce)
+}
@@ -0,0 +1,108 @@
+use leptos::*;
+
+use super::appearance::{Appearance, AppearanceContext, BoardTheme, ColorTheme, CoordinateDisplay, PieceSet};
+use crate::components::i18n::{use_locale, Locale, Text, Translate};
+
+
+#[component]
+pub fn AppearanceSettings() -> impl IntoView {
+    let AppearanceContext { appearance, set_appearance } = use_context::<AppearanceContext>()
+        .expect("The appearance should be provided by the landing page.");
+    let locale = use_locale();
+
+    view! {
+        <details class="dropdown dropdown-end">
+            <summary class="btn btn-sm">{move || Text::Appearance.translate(locale.get())}</summary>
+            <div class="dropdown-content z-10 p-2 shadow bg-base-200 rounded-box w-64 flex flex-col">
+                {appearance_select(
+                    Text::Colours, locale, ColorTheme::all(), appearance, set_appearance,
+                    |appearance| appearance.color_theme, |appearance, value| appearance.color_theme = value,
+                )}
+                {appearance_select(
+                    Text::Board, locale, BoardTheme::all(), appearance, set_appearance,
+                    |appearance| appearance.board_theme, |appearance, value| appearance.board_theme = value,
+                )}
+                {appearance_select(
+                    Text::Pieces, locale, PieceSet::all(), appearance, set_appearance,
+                    |appearance| appearance.piece_set, |appearance, value| appearance.piece_set = value,
+                )}
+                {appearance_select(
+                    Text::Coordinates, locale, CoordinateDisplay::all(), appearance, set_appearance,
+                    |appearance| appearance.coordinates, |appearance, value| appearance.coordinates = value,
+                )}
+                {appearance_toggle(
+                    Text::Animations, locale, appearance, set_appearance,
+                    |appearance| appearance.animations, |appearance, value| appearance.animations = value,
+                )}
+                {appearance_toggle(
+                    Text::Sounds, locale, appearance, set_appearance,
+                    |appearance| appearance.sounds, |appearance, value| appearance.sounds = value,
+                )}
+            </div>
+        </details>
+    }
+}
+
+fn appearance_select<T>(
+    label: Text,
+    locale: Signal<Locale>,
+    options: Vec<T>,
+    appearance: ReadSignal<Appearance>,
+    set_appearance: WriteSignal<Appearance>,
+    get_value: fn(&Appearance) -> T,
+    set_value: fn(&mut Appearance, T),
+) -> impl IntoView
+where
+    T: Translate + PartialEq + Copy + 'static,
+{
+    let options_for_change = options.clone();
+
+    view! {
+        <label class="flex justify-between items-center m-1">
+            <span class="mr-2 text-sm">{move || label.translate(locale.get())}</span>
+            <select
+                class="select select-bordered select-xs"
+                on:change=move |ev| {
+                    let value = event_target_value(&ev).parse::<usize>().ok()
+                        .and_then(|index| options_for_change.get(index).copied());
+                    if let Some(value) = value {
+                        set_appearance.update(|appearance| set_value(appearance, value));
+                    }
+                }
+            >
+                {options.into_iter().enumerate().map(|(index, option)| view! {
+                    <option
+                        value=index.to_string()
+                        selected=move || appearance.with(|appearance| get_value(appearance) == option)
+                    >
+                        {move || option.translate(locale.get())}
+                    </option>
+                }).collect_view()}
+            </select>
+        </label>
+    }
+}
+
+fn appearance_toggle(
+    label: Text,
+    locale: Signal<Locale>,
+    appearance: ReadSignal<Appearance>,
+    set_appearance: WriteSignal<Appearance>,
+    get_value: fn(&Appearance) -> bool,
+    set_value: fn(&mut Appearance, bool),
+) -> impl IntoView {
+    view! {
+        <label class="flex justify-between items-center m-1">
+            <span class="mr-2 text-sm">{move || label.translate(locale.get())}</span>
+            <input
+                type="checkbox"
+                class="toggle toggle-sm"
+                prop:checked=move || appearance.with(get_value)
+                on:change=move |ev| {
+                    let value = event_target_checked(&ev);
+                    set_appearance.update(|appearance| set_value(appearance, value));
+                }
+            />
+        </label>
+    }
+}
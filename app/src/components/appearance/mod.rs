@@ -0,0 +1,7 @@
+mod appearance;
+mod appearance_settings;
+
+pub use appearance::{
+    provide_appearance, use_animations_enabled, use_appearance, Appearance, BoardTheme, ColorTheme, CoordinateDisplay, PieceSet,
+};
+pub use appearance_settings::AppearanceSettings as AppearanceSettings;
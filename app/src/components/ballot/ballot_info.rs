@@ -0,0 +1,84 @@
+use leptos::*;
+use leptos_router::{use_navigate, NavigateOptions};
+use uuid::Uuid;
+
+use super::ballot_server_function::create_return_game;
+use crate::components::game_settings::get_game_settings;
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::components::player::{seat_navigation_state, use_player_seat};
+use crate::game::opening::Opening;
+use crate::game::player_color::PlayerColor;
+
+
+#[component]
+pub fn BallotInfo(
+    #[prop(into)]
+    game_id: Signal<Uuid>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let settings = create_resource(
+        move || game_id.get(),
+        |game_id| async move { get_game_settings(game_id).await.ok() },
+    );
+    let (return_game_pending, set_return_game_pending) = create_signal(false);
+    let navigate = use_navigate();
+    let player_seat = use_player_seat();
+    let open_return_game = move |_| {
+        let navigate = navigate.clone();
+        let Some((player_number, player_secret)) = player_seat.get_untracked() else {
+            return;
+        };
+        set_return_game_pending.set(true);
+        spawn_local(async move {
+            match create_return_game(game_id.get_untracked(), player_number, player_secret.clone()).await {
+                Ok(return_game_id) => {
+                    // the player takes the seat of the other colour in the return game
+                    let state = PlayerColor::from_player_number(player_number)
+                        .map(|color| seat_navigation_state(color.opponent().player_number(), player_secret))
+                        .unwrap_or_default();
+                    navigate(&format!("/games/{}", return_game_id), NavigateOptions { state, ..Default::default() });
+                }
+                Err(error) => {
+                    logging::error!("Cannot create return game: {:?}", error);
+                }
+            }
+            set_return_game_pending.set(false);
+        });
+    };
+
+    view! {
+        <Transition fallback=|| ()>
+            {
+                let open_return_game = open_return_game.clone();
+                move || settings.get().flatten().and_then(|settings| {
+                    let Opening::Ballot(ballot) = settings.opening else {
+                        return None;
+                    };
+                    let open_return_game = open_return_game.clone();
+                    Some(view! {
+                        <div class="flex justify-start items-center p-2">
+                            <p class="m-1 badge badge-outline">{ballot.translate(locale.get())}</p>
+                            {match settings.return_game_of {
+                                Some(first_game_id) => view! {
+                                    <p class="m-1">{Text::ReturnGameOf.translate(locale.get())}</p>
+                                    <a class="link m-1" href=format!("/games/{}", first_game_id)>
+                                        {first_game_id.to_string()}
+                                    </a>
+                                }.into_view(),
+                                None => view! {
+                                    <button
+                                        class="btn btn-xs m-1"
+                                        disabled=move || return_game_pending.get() || player_seat.get().is_none()
+                                        on:click=open_return_game
+                                    >
+                                        {Text::PlayReturnGame.translate(locale.get())}
+                                    </button>
+                                }.into_view(),
+                            }}
+                        </div>
+                    })
+                })
+            }
+        </Transition>
+    }
+}
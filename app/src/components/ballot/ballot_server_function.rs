@@ -0,0 +1,73 @@
+use leptos::*;
+use uuid::Uuid;
+
+
+/// Returns the return game of a finished ballot game, which is created by the first player
+///  asking for it. The players swap their seats and the handicap, so each plays the ballot
+///  with both colours.
+#[server(CreateReturnGame, "/api")]
+pub async fn create_return_game(
+    game_id: Uuid,
+    player_number: usize,
+    player_secret: String,
+) -> Result<Uuid, ServerFnError> {
+    use std::sync::Arc;
+    use leptos_axum::extract;
+    use axum::extract::Extension;
+    use crate::pages::game_page::GameState;
+    use crate::game::opening::Opening;
+    use crate::game::player_color::PlayerColor;
+
+    let game_state = extract::<Extension<Arc<GameState>>>().await
+        .expect("Cannot get the game-state extension.");
+    let game = game_state.get_game(game_id).await
+        .ok_or_else(|| ServerFnError::ServerError(format!("Game {} not found.", game_id)))?;
+    let is_seated = game.players().await.get(player_number).is_some_and(|player| player.has_secret(&player_secret));
+    if !is_seated {
+        return Err(ServerFnError::ServerError("Invalid player secret.".to_string()));
+    }
+    let settings = game.settings().await;
+    if !matches!(settings.opening, Opening::Ballot(_)) || settings.return_game_of.is_some() {
+        return Err(ServerFnError::ServerError("Only the first game of a ballot has a return game.".to_string()));
+    }
+    if game.result().await.is_none() {
+        return Err(ServerFnError::ServerError("The return game can only be played after the game.".to_string()));
+    }
+
+    let mut return_game_settings = settings.clone();
+    return_game_settings.return_game_of = Some(game_id);
+    return_game_settings.handicap.stronger_player = settings.handicap.stronger_player.opponent();
+    return_game_settings.validate().map_err(|error| ServerFnError::ServerError(error.to_string()))?;
+
+    let new_game_id = Uuid::now_v7();
+    game.with_return_game(|return_game| return_game.or(Some(new_game_id))).await;
+    let return_game_id = game.return_game().await.expect("Return game id was just set.");
+    if return_game_id == new_game_id {
+        logging::log!("Creating return game {} for game {}", return_game_id, game_id);
+        let return_game = game_state.create_game(return_game_id, return_game_settings).await
+            .map_err(|error| ServerFnError::ServerError(error.to_string()))?
+            .ok_or_else(|| ServerFnError::ServerError(format!("Game {} already exists.", return_game_id)))?;
+        // the players keep their secrets, so they can take the swapped seats without asking
+        //  for them again; external engines have to be asked for the new game. The seats
+        //  count as connected only once the players open the return game, which starts its clock.
+        let players = game.players().await;
+        let created_at = return_game.created_at().await;
+        return_game.with_player(|mut return_players| {
+            for mut player in players.into_iter().filter(|player| player.public_data.is_assigned && !player.public_data.is_engine) {
+                if !player.public_data.is_bot {
+                    player.public_data.last_ping = Some(created_at);
+                }
+                let Some(seat) = PlayerColor::from_player_number(player.public_data.player_number)
+                    .map(|color| color.opponent().player_number()) else {
+                    continue;
+                };
+                player.public_data.player_number = seat;
+                if let Some(return_player) = return_players.players.get_mut(seat) {
+                    *return_player = player;
+                }
+            }
+            return_players
+        }).await;
+    }
+    Ok(return_game_id)
+}
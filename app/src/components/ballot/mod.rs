@@ -0,0 +1,4 @@
+mod ballot_server_function;
+mod ballot_info;
+
+pub use ballot_info::BallotInfo as BallotInfo;
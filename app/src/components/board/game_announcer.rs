@@ -0,0 +1,45 @@
+use leptos::*;
+
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::game_result::GameResult;
+use crate::game::moves::Move;
+use crate::game::player_color::PlayerColor;
+
+
+/// Announces the moves of the opponent and the result of the game to screen readers.
+#[component]
+pub fn GameAnnouncer(
+    #[prop(into)]
+    moves: Signal<Vec<String>>,
+    /// The last move of the game, matching the last entry of the moves.
+    #[prop(into)]
+    last_move: Signal<Option<Move>>,
+    /// The player making the first move of the game.
+    #[prop(into)]
+    first_player: Signal<PlayerColor>,
+    #[prop(into)]
+    player_color: Signal<Option<PlayerColor>>,
+    #[prop(into)]
+    result: Signal<Option<GameResult>>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let move_announcement = move || {
+        let number_of_plies = moves.with(Vec::len);
+        let notation = moves.with(|moves| moves.last().cloned())?;
+        let mv = last_move.get()?;
+        let mover = if number_of_plies % 2 == 1 { first_player.get() } else { first_player.get().opponent() };
+        if player_color.get() == Some(mover) {
+            return None;
+        }
+        Some(Text::MovePlayed { player: mover, notation, number_of_captures: mv.captures.len() }.translate(locale.get()))
+    };
+
+    view! {
+        <div class="sr-only" aria-live="polite" aria-atomic="true">
+            {move_announcement}
+        </div>
+        <div class="sr-only" aria-live="assertive" aria-atomic="true">
+            {move || result.get().map(|result| Text::GameOver(result).translate(locale.get()))}
+        </div>
+    }
+}
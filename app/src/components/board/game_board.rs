@@ -0,0 +1,464 @@
+use leptos::*;
+use leptos::ev::{KeyboardEvent, PointerEvent};
+use leptos_use::{use_raf_fn_with_options, utils::Pausable, UseRafFnCallbackArgs, UseRafFnOptions};
+
+use super::move_selection::MoveSelection;
+use crate::components::appearance::{use_animations_enabled, use_appearance, CoordinateDisplay, PieceSet};
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::board::{Piece, Position, Square, BOARD_SIZE};
+use crate::game::moves::Move;
+use crate::game::player_color::PlayerColor;
+
+
+const PIECE_RADIUS: f64 = 0.38;
+const KING_MARK_RADIUS: f64 = 0.18;
+const DESTINATION_RADIUS: f64 = 0.14;
+const CAPTURED_PIECE_OPACITY: f64 = 0.4;
+const ANIMATION_TIME_PER_STEP_IN_MS: f64 = 180.0;
+
+
+/// A move shown step by step, starting in the position before the move.
+#[derive(Debug, Clone, PartialEq)]
+struct MoveAnimation {
+    position: Position,
+    mv: Move,
+    /// Timestamp of the first animation frame.
+    started_at: Option<f64>,
+}
+
+impl MoveAnimation {
+    fn steps(&self) -> usize {
+        self.mv.path.len() - 1
+    }
+
+    /// The number of steps done so far, including the fraction of the current one.
+    fn progress(&self, frame_time: f64) -> f64 {
+        let elapsed_time = self.started_at.map(|started_at| frame_time - started_at).unwrap_or(0.0);
+        (elapsed_time / ANIMATION_TIME_PER_STEP_IN_MS).clamp(0.0, self.steps() as f64)
+    }
+
+    fn is_finished(&self, frame_time: f64) -> bool {
+        self.progress(frame_time) >= self.steps() as f64
+    }
+}
+
+
+/// The board as SVG with one unit per square. Moves are entered by clicking or dragging the
+///  pieces, multi-jumps landing square by landing square. The board is shown from the side
+///  of the local player, so the player on the second seat sees it flipped.
+#[component]
+pub fn GameBoard(
+    #[prop(into)]
+    position: Signal<Position>,
+    #[prop(into)]
+    last_move: Signal<Option<Move>>,
+    /// The colour of the local player, who may move the pieces when it is their turn.
+    #[prop(into)]
+    player_color: Signal<Option<PlayerColor>>,
+    #[prop(into)]
+    on_move: Callback<Move>,
+    /// Shows the position without allowing moves, e.g. for an earlier position of the game.
+    #[prop(into, default = MaybeSignal::Static(false))]
+    is_read_only: MaybeSignal<bool>,
+    /// Called for moves entered during the opponent's turn, premoves are disabled without it.
+    #[prop(into, optional)]
+    on_premove: Option<Callback<Move>>,
+    /// The queued premove, it is highlighted until the opponent moved.
+    #[prop(into, optional)]
+    premove: Option<Signal<Option<Move>>>,
+    /// The side shown at the bottom, the side of the local player without it.
+    #[prop(into, optional)]
+    orientation: Option<Signal<PlayerColor>>,
+) -> impl IntoView {
+    let appearance = use_appearance();
+    let locale = use_locale();
+    let animations_enabled = use_animations_enabled();
+    let animation = create_rw_signal::<Option<MoveAnimation>>(None);
+    let frame_time = create_rw_signal(0.0);
+    // Moves entered on this board are not animated again once they come back from the server.
+    let entered_move = store_value::<Option<Move>>(None);
+    let Pausable { pause: pause_frames, resume: resume_frames, .. } = use_raf_fn_with_options(
+        move |args: UseRafFnCallbackArgs| {
+            if animation.with_untracked(|animation| animation.as_ref().is_some_and(|animation| animation.started_at.is_none())) {
+                animation.update(|animation| {
+                    if let Some(animation) = animation {
+                        animation.started_at = Some(args.timestamp);
+                    }
+                });
+            }
+            frame_time.set(args.timestamp);
+        },
+        UseRafFnOptions::default().immediate(false),
+    );
+    let selection = create_rw_signal(MoveSelection::default());
+    let drag_point = create_rw_signal::<Option<(f64, f64)>>(None);
+    // The square chosen with the keyboard, shown while the board has the focus.
+    let cursor = create_rw_signal::<Option<Square>>(None);
+    let (has_focus, set_has_focus) = create_signal(false);
+    let board_ref = create_node_ref::<svg::Svg>();
+    let is_flipped = move || match orientation {
+        Some(orientation) => orientation.get() == PlayerColor::Blue,
+        None => player_color.get() == Some(PlayerColor::Blue),
+    };
+    // The position moves are entered in, premoves are entered as if the opponent passed.
+    let move_position = move || {
+        let mut position = position.get();
+        let color = player_color.get().filter(|_| !is_read_only.get())?;
+        if color != position.side_to_move && on_premove.is_none() {
+            return None;
+        }
+        position.side_to_move = color;
+        Some(position)
+    };
+    let can_move = move || move_position().is_some();
+
+    // A half-entered move is meaningless in a new position. A new position reached by the
+    //  last move is animated.
+    create_effect(move |previous_position: Option<Position>| {
+        let current_position = position.get();
+        if previous_position == Some(current_position) {
+            return current_position;
+        }
+        selection.set(MoveSelection::default());
+        drag_point.set(None);
+
+        let mv = last_move.get_untracked();
+        let was_entered = entered_move.get_value().is_some() && entered_move.get_value() == mv;
+        entered_move.set_value(None);
+        let new_animation = previous_position.zip(mv)
+            .filter(|(previous_position, mv)| previous_position.is_legal(mv) && previous_position.after(mv) == current_position)
+            .filter(|_| animations_enabled.get_untracked() && !was_entered)
+            .map(|(previous_position, mv)| MoveAnimation { position: previous_position, mv, started_at: None });
+        if new_animation.is_some() {
+            resume_frames();
+        }
+        animation.set(new_animation);
+        current_position
+    });
+    create_effect(move |_| {
+        let frame_time = frame_time.get();
+        if animation.with_untracked(|animation| animation.as_ref().is_some_and(|animation| animation.is_finished(frame_time))) {
+            animation.set(None);
+            pause_frames();
+        }
+    });
+
+    let board_point = move |ev: &PointerEvent| {
+        let board = board_ref.get_untracked()?;
+        let rect = board.get_bounding_client_rect();
+        if rect.width() <= 0.0 || rect.height() <= 0.0 {
+            return None;
+        }
+        Some((
+            (ev.client_x() as f64 - rect.left()) / rect.width() * BOARD_SIZE as f64,
+            (ev.client_y() as f64 - rect.top()) / rect.height() * BOARD_SIZE as f64,
+        ))
+    };
+    let select_square = move |square: Square| {
+        let Some(move_position) = untrack(move_position) else { return };
+        let mut current_selection = selection.get_untracked();
+        let mv = current_selection.select(&move_position, square);
+        selection.set(current_selection);
+        match (mv, on_premove) {
+            (Some(mv), Some(on_premove)) if position.get_untracked().side_to_move != move_position.side_to_move => {
+                on_premove.call(mv);
+            }
+            (Some(mv), _) => {
+                entered_move.set_value(Some(mv.clone()));
+                on_move.call(mv);
+            }
+            (None, _) => {}
+        }
+    };
+
+    let on_pointer_down = move |ev: PointerEvent| {
+        if !can_move() {
+            return;
+        }
+        let Some(point) = board_point(&ev) else { return };
+        let Some(square) = square_at(point, is_flipped()) else { return };
+        select_square(square);
+        if selection.get_untracked().path().last() == Some(&square) {
+            ev.prevent_default();
+            if let Some(board) = board_ref.get_untracked() {
+                let _ = board.set_pointer_capture(ev.pointer_id());
+            }
+            drag_point.set(Some(point));
+        }
+    };
+    let on_pointer_move = move |ev: PointerEvent| {
+        if drag_point.get_untracked().is_some() {
+            drag_point.set(board_point(&ev));
+        }
+    };
+    let on_pointer_up = move |ev: PointerEvent| {
+        if drag_point.get_untracked().is_none() {
+            return;
+        }
+        drag_point.set(None);
+        let dropped_on = board_point(&ev).and_then(|point| square_at(point, is_flipped()));
+        let Some(move_position) = untrack(move_position) else { return };
+        if let Some(square) = dropped_on {
+            if selection.get_untracked().destinations(&move_position).contains(&square) {
+                select_square(square);
+            }
+        }
+    };
+
+    let on_key_down = move |ev: KeyboardEvent| {
+        let is_flipped = is_flipped();
+        let current = cursor.get_untracked()
+            .or_else(|| selection.get_untracked().path().last().copied())
+            .unwrap_or_else(|| Square::from_index(0));
+        let next = match ev.key().as_str() {
+            "ArrowLeft" => square_on_screen(current, &[(-2.0, 0.0)], is_flipped),
+            "ArrowRight" => square_on_screen(current, &[(2.0, 0.0)], is_flipped),
+            "ArrowUp" => square_on_screen(current, &[(-1.0, -1.0), (1.0, -1.0)], is_flipped),
+            "ArrowDown" => square_on_screen(current, &[(-1.0, 1.0), (1.0, 1.0)], is_flipped),
+            "Enter" | " " => {
+                if can_move() {
+                    select_square(current);
+                }
+                Some(current)
+            }
+            "Escape" => {
+                selection.set(MoveSelection::default());
+                Some(current)
+            }
+            _ => return,
+        };
+        // Arrow keys must not reach the move history while the board has the focus.
+        ev.prevent_default();
+        ev.stop_propagation();
+        cursor.set(Some(next.unwrap_or(current)));
+    };
+    let cursor_description = move || {
+        let square = cursor.get().filter(|_| has_focus.get())?;
+        let selection = selection.get();
+        let description = Text::SquareDescription {
+            square,
+            piece: position.get().board.get(square),
+            is_selected: selection.path().contains(&square),
+            is_destination: move_position().is_some_and(|move_position| selection.destinations(&move_position).contains(&square)),
+        };
+        Some(description.translate(locale.get()))
+    };
+
+    let squares = move || {
+        let is_flipped = is_flipped();
+        let appearance = appearance.get();
+        let show_numbers = appearance.coordinates == CoordinateDisplay::SquareNumbers;
+        Square::all().map(|square| {
+            let (x, y) = square_origin(square, is_flipped);
+            view! {
+                <rect x=x y=y width="1" height="1" class=appearance.board_theme.dark_square_class()/>
+                {show_numbers.then(|| view! {
+                    <text x={x + 0.06} y={y + 0.22} font-size="0.2" class=appearance.board_theme.coordinate_class()>
+                        {square.number()}
+                    </text>
+                })}
+            }
+        }).collect_view()
+    };
+    let edge_coordinates = move || {
+        if appearance.get().coordinates != CoordinateDisplay::Edges {
+            return ().into_view();
+        }
+        let is_flipped = is_flipped();
+        let last = BOARD_SIZE - 1;
+        (0..BOARD_SIZE).map(|index| {
+            let (column, row) = if is_flipped { (index, index) } else { (last - index, last - index) };
+            let file = char::from(b'a' + column as u8);
+            let rank = BOARD_SIZE - row;
+            view! {
+                <text x={index as f64 + 0.84} y={BOARD_SIZE as f64 - 0.06} font-size="0.2" class="fill-gray-500">{file.to_string()}</text>
+                <text x="0.04" y={index as f64 + 0.22} font-size="0.2" class="fill-gray-500">{rank}</text>
+            }
+        }).collect_view()
+    };
+    let highlights = move || {
+        let is_flipped = is_flipped();
+        let last_move_squares = last_move.get().map(|mv| mv.path).unwrap_or_default();
+        let selected_squares = selection.get().path().to_vec();
+        let premove_squares = premove.and_then(|premove| premove.get()).map(|mv| mv.path).unwrap_or_default();
+        let premove_view = premove_squares.into_iter().map(|square| {
+            let (x, y) = square_origin(square, is_flipped);
+            view! { <rect x=x y=y width="1" height="1" class="fill-sky-400 opacity-50"/> }
+        });
+        let last_move_view = last_move_squares.into_iter().map(|square| {
+            let (x, y) = square_origin(square, is_flipped);
+            view! { <rect x=x y=y width="1" height="1" class="fill-yellow-300 opacity-40"/> }
+        });
+        let selection_view = selected_squares.into_iter().map(|square| {
+            let (x, y) = square_origin(square, is_flipped);
+            view! { <rect x=x y=y width="1" height="1" class="fill-green-400 opacity-50"/> }
+        });
+        last_move_view.chain(premove_view).chain(selection_view).collect_view()
+    };
+    let destinations = move || {
+        let is_flipped = is_flipped();
+        let Some(move_position) = move_position() else {
+            return ().into_view();
+        };
+        selection.get().destinations(&move_position).into_iter().map(|square| {
+            let (cx, cy) = square_center(square, is_flipped);
+            view! { <circle cx=cx cy=cy r=DESTINATION_RADIUS class="fill-green-500 opacity-70"/> }
+        }).collect_view()
+    };
+    let pieces = move || {
+        let is_flipped = is_flipped();
+        let piece_set = appearance.get().piece_set;
+        if let Some(animation) = animation.get() {
+            return animated_pieces(&animation, frame_time.get(), is_flipped, piece_set).into_view();
+        }
+        let position = position.get();
+        let selection = selection.get();
+        let drag_point = drag_point.get();
+        let captured = move_position().map(|move_position| selection.captured(&move_position)).unwrap_or_default();
+        let moving_square = selection.path().first().copied();
+        let mut pieces: Vec<(Square, Piece)> = position.board.pieces().collect();
+        // The moving piece is drawn last to stay on top of the others.
+        pieces.sort_by_key(|(square, _)| Some(*square) == moving_square);
+        pieces.into_iter().map(|(square, piece)| {
+            let center = match (Some(square) == moving_square, drag_point) {
+                (true, Some(point)) => point,
+                (true, None) => square_center(*selection.path().last().expect("A selection has a square."), is_flipped),
+                _ => square_center(square, is_flipped),
+            };
+            let opacity = if captured.contains(&square) { CAPTURED_PIECE_OPACITY } else { 1.0 };
+            view! { <PieceView piece=piece center=center opacity=opacity piece_set=piece_set/> }
+        }).collect_view()
+    };
+
+    view! {
+        <svg
+            node_ref=board_ref
+            viewBox=format!("0 0 {} {}", BOARD_SIZE, BOARD_SIZE)
+            class="w-full max-w-lg aspect-square touch-none select-none"
+            class:cursor-pointer=can_move
+            on:pointerdown=on_pointer_down
+            on:pointermove=on_pointer_move
+            on:pointerup=on_pointer_up
+            on:pointercancel=move |_| drag_point.set(None)
+            on:keydown=on_key_down
+            on:focus=move |_| set_has_focus.set(true)
+            on:blur=move |_| set_has_focus.set(false)
+            tabindex="0"
+            role="application"
+            aria-label=move || Text::BoardDescription.translate(locale.get())
+        >
+            <defs>
+                <radialGradient id="red-piece-gradient" cx="35%" cy="35%">
+                    <stop offset="0%" stop-color="#f87171"/>
+                    <stop offset="100%" stop-color="#7f1d1d"/>
+                </radialGradient>
+                <radialGradient id="blue-piece-gradient" cx="35%" cy="35%">
+                    <stop offset="0%" stop-color="#60a5fa"/>
+                    <stop offset="100%" stop-color="#1e3a8a"/>
+                </radialGradient>
+            </defs>
+            <rect
+                x="0" y="0" width=BOARD_SIZE height=BOARD_SIZE
+                class=move || appearance.get().board_theme.light_square_class()
+            />
+            {squares}
+            {edge_coordinates}
+            {highlights}
+            {destinations}
+            {pieces}
+            {move || cursor.get().filter(|_| has_focus.get()).map(|square| {
+                let (x, y) = square_origin(square, is_flipped());
+                view! { <rect x=x y=y width="1" height="1" stroke-width="0.06" class="fill-none stroke-sky-300"/> }
+            })}
+        </svg>
+        <div class="sr-only" aria-live="polite">{cursor_description}</div>
+    }
+}
+
+#[component]
+fn PieceView(
+    piece: Piece,
+    center: (f64, f64),
+    opacity: f64,
+    piece_set: PieceSet,
+) -> impl IntoView {
+    let (cx, cy) = center;
+    // An inline fill wins over the fill of the class.
+    let gradient_fill = piece_set.gradient_id(piece.color).map(|id| format!("fill: url(#{})", id));
+
+    view! {
+        <g opacity=opacity>
+            <circle
+                cx=cx cy=cy r=PIECE_RADIUS
+                stroke-width=piece_set.stroke_width()
+                class=piece_set.piece_class(piece.color)
+                style=gradient_fill
+            />
+            {piece.is_king().then(|| view! {
+                <circle cx=cx cy=cy r=KING_MARK_RADIUS stroke-width="0.06" class=piece_set.king_mark_class()/>
+            })}
+        </g>
+    }
+}
+
+/// The pieces of the position before the animated move, the moving piece jumps from square
+///  to square and every captured piece fades out once it is jumped over.
+fn animated_pieces(animation: &MoveAnimation, frame_time: f64, is_flipped: bool, piece_set: PieceSet) -> View {
+    let progress = animation.progress(frame_time);
+    let path = &animation.mv.path;
+    let step = (progress.floor() as usize).min(animation.steps() - 1);
+    let fraction = progress - step as f64;
+    let (from_x, from_y) = square_center(path[step], is_flipped);
+    let (to_x, to_y) = square_center(path[step + 1], is_flipped);
+    let moving_center = (from_x + (to_x - from_x) * fraction, from_y + (to_y - from_y) * fraction);
+
+    let mut pieces: Vec<(Square, Piece)> = animation.position.board.pieces().collect();
+    pieces.sort_by_key(|(square, _)| *square == animation.mv.from());
+    pieces.into_iter().map(|(square, piece)| {
+        let (center, opacity) = if square == animation.mv.from() {
+            (moving_center, 1.0)
+        }
+        else {
+            let opacity = match animation.mv.captures.iter().position(|captured| *captured == square) {
+                Some(index) => (1.0 - (progress - index as f64 - 0.5) * 2.0).clamp(0.0, 1.0),
+                None => 1.0,
+            };
+            (square_center(square, is_flipped), opacity)
+        };
+        view! { <PieceView piece=piece center=center opacity=opacity piece_set=piece_set/> }
+    }).collect_view()
+}
+
+/// The top left corner of the square on the screen.
+fn square_origin(square: Square, is_flipped: bool) -> (f64, f64) {
+    let last = (BOARD_SIZE - 1) as f64;
+    let (row, column) = (square.row() as f64, square.column() as f64);
+    if is_flipped {
+        (column, row)
+    }
+    else {
+        (last - column, last - row)
+    }
+}
+
+fn square_center(square: Square, is_flipped: bool) -> (f64, f64) {
+    let (x, y) = square_origin(square, is_flipped);
+    (x + 0.5, y + 0.5)
+}
+
+/// The first of the given screen offsets leading from the square to another playable square.
+fn square_on_screen(square: Square, offsets: &[(f64, f64)], is_flipped: bool) -> Option<Square> {
+    let (x, y) = square_center(square, is_flipped);
+    offsets.iter().find_map(|(x_offset, y_offset)| square_at((x + x_offset, y + y_offset), is_flipped))
+}
+
+fn square_at((x, y): (f64, f64), is_flipped: bool) -> Option<Square> {
+    let last = BOARD_SIZE as isize - 1;
+    let (row, column) = (y.floor() as isize, x.floor() as isize);
+    if is_flipped {
+        Square::from_coordinates(row, column)
+    }
+    else {
+        Square::from_coordinates(last - row, last - column)
+    }
+}
@@ -0,0 +1,8 @@
+mod move_selection;
+mod game_board;
+mod move_input;
+mod game_announcer;
+
+pub use game_board::GameBoard as GameBoard;
+pub use move_input::MoveInput as MoveInput;
+pub use game_announcer::GameAnnouncer as GameAnnouncer;
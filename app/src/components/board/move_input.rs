@@ -0,0 +1,62 @@
+use leptos::*;
+
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::board::Position;
+use crate::game::moves::Move;
+use crate::game::notation::parse_move;
+
+
+/// A text field to type moves in numeric notation, like `11-15` or `22x15`. The move is
+///  checked against the position before it is handed on.
+#[component]
+pub fn MoveInput(
+    /// The position moves are entered in, `None` while the player cannot move.
+    #[prop(into)]
+    position: Signal<Option<Position>>,
+    #[prop(into)]
+    on_move: Callback<Move>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let (notation, set_notation) = create_signal("".to_string());
+    let (input_error, set_input_error) = create_signal::<Option<String>>(None);
+    let is_disabled = move || position.get().is_none();
+
+    let submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let Some(position) = position.get_untracked() else { return };
+        match parse_move(&position, &notation.get_untracked()) {
+            Ok(mv) => {
+                set_input_error.set(None);
+                set_notation.set("".to_string());
+                on_move.call(mv);
+            }
+            Err(error) => set_input_error.set(Some(error.translate(locale.get_untracked()))),
+        }
+    };
+
+    view! {
+        <form class="flex justify-center items-center p-2" on:submit=submit>
+            <label for="move-input" class="m-1 text-sm">{move || Text::YourMove.translate(locale.get())}</label>
+            <input
+                id="move-input"
+                type="text"
+                class="input input-bordered input-xs w-28 font-mono m-1"
+                placeholder="11-15"
+                autocomplete="off"
+                aria-describedby="move-input-error"
+                aria-invalid=move || input_error.get().is_some().to_string()
+                disabled=is_disabled
+                on:input=move |ev| {
+                    set_notation.set(event_target_value(&ev))
+                }
+                prop:value=notation
+            />
+            <button type="submit" class="btn btn-xs m-1" disabled=is_disabled>
+                {move || Text::Play.translate(locale.get())}
+            </button>
+            <p id="move-input-error" role="alert" class="m-1 text-error text-sm">
+                {move || input_error.get().unwrap_or_default()}
+            </p>
+        </form>
+    }
+}
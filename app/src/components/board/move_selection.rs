@@ -0,0 +1,74 @@
+use crate::game::board::{Position, Square};
+use crate::game::moves::Move;
+
+
+/// The squares a player has selected so far while entering a move. Multi-jumps are
+///  entered one landing square after the other.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MoveSelection {
+    path: Vec<Square>,
+}
+
+impl MoveSelection {
+    pub fn path(&self) -> &[Square] {
+        &self.path
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// The legal moves that continue the selected path.
+    pub fn candidates(&self, position: &Position) -> Vec<Move> {
+        position.legal_moves().into_iter()
+            .filter(|mv| mv.path.starts_with(&self.path))
+            .collect()
+    }
+
+    /// The squares the selected piece can go to next.
+    pub fn destinations(&self, position: &Position) -> Vec<Square> {
+        if self.path.is_empty() {
+            return Vec::new();
+        }
+        let mut destinations: Vec<Square> = self.candidates(position).iter()
+            .filter_map(|mv| mv.path.get(self.path.len()).copied())
+            .collect();
+        destinations.sort();
+        destinations.dedup();
+        destinations
+    }
+
+    /// The pieces jumped over on the way to the last selected square.
+    pub fn captured(&self, position: &Position) -> Vec<Square> {
+        self.candidates(position).first()
+            .map(|mv| mv.captures.iter().take(self.path.len().saturating_sub(1)).copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Selects the next square and returns the move once the path is complete. Selecting
+    ///  another piece starts a new selection, any other square clears it.
+    pub fn select(&mut self, position: &Position, square: Square) -> Option<Move> {
+        if self.path.last() == Some(&square) {
+            return None;
+        }
+
+        if self.destinations(position).contains(&square) {
+            self.path.push(square);
+            let candidates = self.candidates(position);
+            if let [mv] = candidates.as_slice() {
+                if mv.path == self.path {
+                    self.path.clear();
+                    return Some(mv.clone());
+                }
+            }
+            return None;
+        }
+
+        let can_restart = self.path.len() <= 1;
+        self.path.clear();
+        if can_restart && position.legal_moves().iter().any(|mv| mv.from() == square) {
+            self.path.push(square);
+        }
+        None
+    }
+}
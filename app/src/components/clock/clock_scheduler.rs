@@ -0,0 +1,57 @@
+use std::sync::Arc;
+use std::time::Duration;
+use leptos::*;
+use chrono::{DateTime, Utc};
+
+use crate::pages::game_page::{Game, GameState};
+use crate::game::game_result::{GameResult, WinReason};
+
+const CLOCK_SCHEDULER_INTERVAL_IN_SEC: u64 = 10;
+
+
+/// Background task, which starts the clock as soon as all seats are taken and their players
+///  connected to the game, and declares a timeout win when the player to move missed the deadline.
+pub async fn run_clock_scheduler(game_state: Arc<GameState>) {
+    let mut interval = tokio::time::interval(Duration::from_secs(CLOCK_SCHEDULER_INTERVAL_IN_SEC));
+    loop {
+        interval.tick().await;
+        let now = Utc::now();
+        for game in game_state.games().await {
+            check_game_clock(&game, now).await;
+        }
+    }
+}
+
+async fn check_game_clock(game: &Game, now: DateTime<Utc>) {
+    let _lock = game.lock().await;
+    if game.result().await.is_some() {
+        return;
+    }
+
+    let clock = game.clock().await;
+    if clock.is_paused {
+        return;
+    }
+    if !clock.is_started {
+        let created_at = game.created_at().await;
+        let all_players_connected = game.players().await.iter().all(|p| {
+            p.public_data.is_assigned && (p.public_data.is_bot || p.public_data.last_ping.is_some_and(|last_ping| last_ping > created_at))
+        });
+        if all_players_connected {
+            logging::log!("Starting clock of game {}", game.id().await);
+            game.with_clock(|mut clock| {
+                clock.start(now);
+                clock
+            }).await;
+        }
+    }
+    else if clock.is_expired(now) {
+        let winner = clock.active_player.opponent();
+        logging::log!("Player {} ran out of time in game {}", clock.active_player, game.id().await);
+        game.with_clock(|mut clock| {
+            clock.stop();
+            clock
+        }).await;
+        game.set_result(Some(GameResult::Win { winner, reason: WinReason::Timeout })).await;
+    }
+}
@@ -0,0 +1,32 @@
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::game::{clock::Clock, game_result::GameResult};
+
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug)]
+pub struct GameClockStatus {
+    pub clock: Clock,
+    pub result: Option<GameResult>,
+}
+
+#[server(GetGameClock, "/api")]
+pub async fn get_game_clock(
+    game_id: Uuid,
+) -> Result<GameClockStatus, ServerFnError> {
+    use std::sync::Arc;
+    use leptos_axum::extract;
+    use axum::extract::Extension;
+    use crate::pages::game_page::GameState;
+
+    let game_state = extract::<Extension<Arc<GameState>>>().await
+        .expect("Cannot get the game-state extension.");
+    let game = game_state.get_game(game_id).await
+        .ok_or_else(|| ServerFnError::ServerError(format!("Game {} not found.", game_id)))?;
+
+    Ok(GameClockStatus {
+        clock: game.clock().await,
+        result: game.result().await,
+    })
+}
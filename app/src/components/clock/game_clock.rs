@@ -0,0 +1,101 @@
+use leptos::*;
+use leptos_use::{use_interval_fn, utils::Pausable};
+use chrono::{TimeDelta, Utc};
+use uuid::Uuid;
+
+use super::clock_server_function::get_game_clock;
+use crate::components::appearance::use_appearance;
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::player_color::PlayerColor;
+use crate::utils::sound_effect::SoundEffect;
+
+const CLOCK_REFRESH_INTERVAL_IN_MS: u64 = 10_000;
+// The local player is warned once per move when less time than this is left.
+const LOW_TIME_WARNING_IN_PERCENT: i32 = 10;
+
+
+#[component]
+pub fn GameClock(
+    #[prop(into)]
+    game_id: Signal<Uuid>,
+    #[prop(into)]
+    player_number: Signal<Option<usize>>,
+) -> impl IntoView {
+    let clock_status = create_resource(
+        move || game_id.get(),
+        |game_id| async move { get_game_clock(game_id).await.ok() },
+    );
+    let (now, set_now) = create_signal(Utc::now());
+    let Pausable { .. } = use_interval_fn(
+        move || {
+            set_now.set(Utc::now());
+        },
+        1000,
+    );
+    let Pausable { .. } = use_interval_fn(
+        move || {
+            clock_status.refetch();
+        },
+        CLOCK_REFRESH_INTERVAL_IN_MS,
+    );
+    let appearance = use_appearance();
+    let locale = use_locale();
+    let warned_deadline = store_value(None);
+    create_effect(move |_| {
+        let now = now.get();
+        let Some(status) = clock_status.get().flatten() else { return };
+        let clock = status.clock;
+        let is_local_player = player_number.get().and_then(PlayerColor::from_player_number) == Some(clock.active_player);
+        let is_low_time = clock.remaining_time(now).zip(clock.time_control.time_per_move())
+            .is_some_and(|(remaining_time, time_per_move)| remaining_time < time_per_move * LOW_TIME_WARNING_IN_PERCENT / 100);
+        if status.result.is_none() && is_local_player && is_low_time && warned_deadline.get_value() != clock.deadline {
+            warned_deadline.set_value(clock.deadline);
+            if appearance.get_untracked().sounds {
+                SoundEffect::LowTime.play();
+            }
+        }
+    });
+
+    view! {
+        <Transition fallback=|| ()>
+            {move || clock_status.get().flatten().map(|status| {
+                let clock = status.clock.clone();
+                view! {
+                    <div class="flex justify-start p-2">
+                        <p class="m-1">{status.clock.time_control.translate(locale.get())}</p>
+                        {match status.result {
+                            Some(result) => view! {
+                                <p class="m-1 font-bold">{result.translate(locale.get())}</p>
+                            }.into_view(),
+                            None if !status.clock.is_started => view! {
+                                <p class="m-1">{Text::WaitingForPlayers.translate(locale.get())}</p>
+                            }.into_view(),
+                            None if status.clock.is_paused => view! {
+                                <p class="m-1">{Text::Adjourned.translate(locale.get())}</p>
+                            }.into_view(),
+                            None => view! {
+                                <p class="m-1">{Text::ToMove(status.clock.active_player).translate(locale.get())}</p>
+                                <code class="bg-base-200 m-1 px-1" role="timer" aria-label=Text::RemainingTime.translate(locale.get())>
+                                    {move || clock.remaining_time(now.get()).map(format_remaining_time)}
+                                </code>
+                            }.into_view(),
+                        }}
+                    </div>
+                }
+            })}
+        </Transition>
+    }
+}
+
+fn format_remaining_time(remaining_time: TimeDelta) -> String {
+    let days = remaining_time.num_days();
+    let hours = remaining_time.num_hours() % 24;
+    let minutes = remaining_time.num_minutes() % 60;
+    let seconds = remaining_time.num_seconds() % 60;
+    if days > 0 {
+        format!("{}d {:02}h {:02}m", days, hours, minutes)
+    }
+    else {
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    }
+}
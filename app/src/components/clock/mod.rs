@@ -0,0 +1,8 @@
+mod clock_server_function;
+mod game_clock;
+#[cfg(feature = "ssr")]
+mod clock_scheduler;
+
+pub use game_clock::GameClock as GameClock;
+#[cfg(feature = "ssr")]
+pub use clock_scheduler::run_clock_scheduler as run_clock_scheduler;
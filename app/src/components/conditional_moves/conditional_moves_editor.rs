@@ -0,0 +1,108 @@
+use leptos::*;
+use uuid::Uuid;
+
+use super::conditional_moves_server_function::{get_conditional_moves, set_conditional_lines};
+use crate::components::game_settings::get_game_settings;
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::conditional_moves::ConditionalMoves;
+use crate::game::time_control::TimeControl;
+
+
+/// Lets a player of a correspondence game prepare replies: every line alternates a move of
+///  the opponent and the reply to it, e.g. `23-19 8-11 22-17 9-13`.
+#[component]
+pub fn ConditionalMovesEditor(
+    #[prop(into)]
+    game_id: Signal<Uuid>,
+    #[prop(into)]
+    player_number: RwSignal<Option<usize>>,
+    #[prop(into)]
+    player_secret: RwSignal<Option<String>>,
+    /// The moves of the game, the prepared moves are reloaded after every move.
+    #[prop(into)]
+    moves: Signal<Vec<String>>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let settings = create_resource(
+        move || game_id.get(),
+        |game_id| async move { get_game_settings(game_id).await.ok() },
+    );
+    let is_correspondence = move || {
+        settings.get().flatten()
+            .is_some_and(|settings| matches!(settings.time_control, TimeControl::Correspondence { .. }))
+    };
+    let (lines, set_lines) = create_signal("".to_string());
+    let (save_error, set_save_error) = create_signal::<Option<String>>(None);
+    let (save_pending, set_save_pending) = create_signal(false);
+    let conditional_moves = create_resource(
+        move || (game_id.get(), player_number.get(), player_secret.get(), moves.with(Vec::len)),
+        |(game_id, player_number, player_secret, _)| async move {
+            match (player_number, player_secret) {
+                (Some(player_number), Some(player_secret)) => {
+                    get_conditional_moves(game_id, player_number, player_secret).await.ok()
+                }
+                _ => None,
+            }
+        },
+    );
+    create_effect(move |_| {
+        if let Some(conditional_moves) = conditional_moves.get().flatten() {
+            set_lines.set(format_lines(&conditional_moves));
+        }
+    });
+
+    let save = move |_| {
+        let (Some(player_number), Some(player_secret)) = (player_number.get_untracked(), player_secret.get_untracked()) else {
+            return;
+        };
+        set_save_pending.set(true);
+        set_save_error.set(None);
+        spawn_local(async move {
+            if let Err(error) = set_conditional_lines(game_id.get_untracked(), player_number, player_secret, parse_lines(&lines.get_untracked())).await {
+                logging::error!("Cannot save conditional moves: {:?}", error);
+                set_save_error.set(Some(error.translate(locale.get_untracked())));
+            }
+            conditional_moves.refetch();
+            set_save_pending.set(false);
+        });
+    };
+
+    view! {
+        <Transition fallback=|| ()>
+            <Show when=move || is_correspondence() && player_number.get().is_some()>
+                <div class="p-2 w-full flex flex-col items-center">
+                    <p class="text-sm">{move || Text::ConditionalMovesHint.translate(locale.get())}</p>
+                    <textarea
+                        class="textarea textarea-bordered w-full max-w-xl font-mono text-xs"
+                        rows=4
+                        placeholder="23-19 8-11 22-17 9-13"
+                        on:input=move |ev| {
+                            set_lines.set(event_target_value(&ev))
+                        }
+                        prop:value=lines
+                    />
+                    <button class="btn btn-xs m-2" disabled=save_pending on:click=save>
+                        {move || Text::SaveConditionalMoves.translate(locale.get())}
+                    </button>
+                    <Show when=move || save_error.get().is_some()>
+                        <p class="content-error">{move || Text::Error(save_error.get().unwrap_or_default()).translate(locale.get())}</p>
+                    </Show>
+                </div>
+            </Show>
+        </Transition>
+    }
+}
+
+fn format_lines(conditional_moves: &ConditionalMoves) -> String {
+    conditional_moves.lines().iter()
+        .map(|line| line.join(" "))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn parse_lines(text: &str) -> Vec<Vec<String>> {
+    text.lines()
+        .map(|line| line.split_whitespace().map(str::to_string).collect())
+        .filter(|line: &Vec<String>| !line.is_empty())
+        .collect()
+}
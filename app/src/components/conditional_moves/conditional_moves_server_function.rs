@@ -0,0 +1,100 @@
+use leptos::*;
+use uuid::Uuid;
+
+use crate::game::conditional_moves::{ConditionalMoves, ConditionalMovesError};
+#[cfg(feature = "ssr")]
+use crate::game::{player_color::PlayerColor, time_control::TimeControl};
+#[cfg(feature = "ssr")]
+use crate::pages::game_page::Game;
+
+
+#[server(GetConditionalMoves, "/api")]
+pub async fn get_conditional_moves(
+    game_id: Uuid,
+    player_number: usize,
+    player_secret: String,
+) -> Result<ConditionalMoves, ServerFnError<ConditionalMovesError>> {
+    let game = get_game(game_id).await?;
+    let player = verify_player(&game, player_number, &player_secret).await?;
+    Ok(game.conditional_moves(player).await)
+}
+
+/// Queues a move for the next turn of the player, `None` cancels the queued move.
+#[server(SetPremove, "/api")]
+pub async fn set_premove(
+    game_id: Uuid,
+    player_number: usize,
+    player_secret: String,
+    premove: Option<String>,
+) -> Result<(), ServerFnError<ConditionalMovesError>> {
+    let game = get_game(game_id).await?;
+    let player = verify_player(&game, player_number, &player_secret).await?;
+    update_conditional_moves(&game, player, |conditional_moves| conditional_moves.premove = premove).await
+}
+
+/// Replaces the conditional moves of the player, given as lines of alternating opponent
+///  moves and replies.
+#[server(SetConditionalLines, "/api")]
+pub async fn set_conditional_lines(
+    game_id: Uuid,
+    player_number: usize,
+    player_secret: String,
+    lines: Vec<Vec<String>>,
+) -> Result<(), ServerFnError<ConditionalMovesError>> {
+    let game = get_game(game_id).await?;
+    let player = verify_player(&game, player_number, &player_secret).await?;
+    if !matches!(game.settings().await.time_control, TimeControl::Correspondence { .. }) {
+        return Err(ServerFnError::WrappedServerError(ConditionalMovesError::OnlyForCorrespondence));
+    }
+    let branches = ConditionalMoves::branches_from_lines(&lines)
+        .map_err(ServerFnError::WrappedServerError)?;
+    update_conditional_moves(&game, player, |conditional_moves| conditional_moves.branches = branches).await
+}
+
+/// Changes the prepared moves of the player. They are checked and stored under the lock of the
+///  game, so the opponent cannot move in between.
+#[cfg(feature = "ssr")]
+async fn update_conditional_moves<F>(game: &Game, player: PlayerColor, update_func: F) -> Result<(), ServerFnError<ConditionalMovesError>>
+where
+    F: FnOnce(&mut ConditionalMoves)
+{
+    let _lock = game.lock().await;
+    let mut conditional_moves = game.conditional_moves(player).await;
+    update_func(&mut conditional_moves);
+    if !conditional_moves.is_empty() {
+        if game.result().await.is_some() {
+            return Err(ServerFnError::WrappedServerError(ConditionalMovesError::GameNotRunning));
+        }
+        let position = game.position().await;
+        if position.side_to_move != player.opponent() {
+            return Err(ServerFnError::WrappedServerError(ConditionalMovesError::NotOpponentsTurn));
+        }
+        conditional_moves.validate(&position).map_err(ServerFnError::WrappedServerError)?;
+    }
+
+    logging::log!("Player {} prepared moves in game {}", player, game.id().await);
+    game.with_conditional_moves(player, |_| conditional_moves).await;
+    Ok(())
+}
+
+#[cfg(feature = "ssr")]
+async fn verify_player(game: &Game, player_number: usize, player_secret: &str) -> Result<PlayerColor, ServerFnError<ConditionalMovesError>> {
+    let players = game.players().await;
+    match (PlayerColor::from_player_number(player_number), players.get(player_number)) {
+        (Some(player), Some(player_info)) if player_info.has_secret(player_secret) => Ok(player),
+        _ => Err(ServerFnError::WrappedServerError(ConditionalMovesError::InvalidPlayerSecret)),
+    }
+}
+
+#[cfg(feature = "ssr")]
+async fn get_game<E>(game_id: Uuid) -> Result<Game, ServerFnError<E>> {
+    use std::sync::Arc;
+    use leptos_axum::extract;
+    use axum::extract::Extension;
+    use crate::pages::game_page::GameState;
+
+    let game_state = extract::<Extension<Arc<GameState>>>().await
+        .expect("Cannot get the game-state extension.");
+    game_state.get_game(game_id).await
+        .ok_or_else(|| ServerFnError::ServerError(format!("Game {} not found.", game_id)))
+}
@@ -0,0 +1,5 @@
+mod conditional_moves_server_function;
+mod conditional_moves_editor;
+
+pub use conditional_moves_editor::ConditionalMovesEditor as ConditionalMovesEditor;
+pub use conditional_moves_server_function::set_premove as set_premove;
@@ -0,0 +1,67 @@
+use leptos::*;
+
+use super::dxp_connection::DxpConnection;
+use super::dxp_session::{release_engine_seat, run_dxp_game, seat_engine};
+use crate::pages::game_page::Game;
+use crate::game::board::Position;
+use crate::game::dxp::{DxpAcceptance, DxpError, DxpMessage, DXP_VERSION};
+use crate::game::player_color::PlayerColor;
+
+/// The environment variable with the address of the external engine, e.g. `localhost:27531`.
+pub const ENGINE_ADDRESS_VARIABLE: &str = "DXP_ENGINE_ADDRESS";
+/// The name the server introduces itself with to DXP peers.
+pub const DXP_NAME: &str = "Checkers server";
+/// The thinking time offered to the engine. It is well within every time control of the
+///  server, whose clocks give at least a day per move, and keeps the engine moving at a pace
+///  people like to watch.
+const ENGINE_MINUTES: u16 = 10;
+const ENGINE_MOVES: u16 = 50;
+
+
+/// The address of the external engine, `None` if none is configured.
+pub fn engine_address() -> Option<String> {
+    std::env::var(ENGINE_ADDRESS_VARIABLE).ok().filter(|address| !address.trim().is_empty())
+}
+
+/// Connects to the external engine and lets it play the seat for the rest of the game. The
+///  seat is released again if the engine refuses the game or leaves it unfinished.
+pub async fn play_engine_in_game(game: Game, engine_number: usize, address: String) {
+    let game_id = game.id().await;
+    match request_engine_game(&game, engine_number, &address).await {
+        Ok(()) => logging::log!("External engine at {} finished game {}", address, game_id),
+        Err(error) => logging::error!("External engine at {} stopped playing game {}: {}", address, game_id, error),
+    }
+    release_engine_seat(&game, engine_number).await;
+}
+
+async fn request_engine_game(game: &Game, engine_number: usize, address: &str) -> Result<(), DxpError> {
+    let engine_color = PlayerColor::from_player_number(engine_number)
+        .ok_or_else(|| DxpError::InvalidMessage(format!("Invalid seat {}.", engine_number)))?;
+    let mut connection = DxpConnection::connect(address).await?;
+    // the position and the number of moves are taken together, moves played before the engine
+    //  accepts are sent to it with the others
+    let record = game.record().await;
+    let position = record.position();
+    let known_plies = record.moves.len();
+    connection.send(&DxpMessage::GameRequest {
+        version: DXP_VERSION,
+        initiator_name: DXP_NAME.to_string(),
+        follower_color: engine_color,
+        minutes: ENGINE_MINUTES,
+        moves: ENGINE_MOVES,
+        position: (position != Position::initial()).then_some(position),
+    }).await?;
+
+    let follower_name = loop {
+        match connection.receive().await? {
+            DxpMessage::GameAcceptance { follower_name, acceptance: DxpAcceptance::Accepted } => break follower_name,
+            DxpMessage::GameAcceptance { acceptance, .. } => return Err(DxpError::GameRefused(acceptance)),
+            DxpMessage::Chat(text) => logging::log!("External engine at {} says: {}", address, text),
+            other => logging::warn!("Unexpected DXP message before the game: {}", other),
+        }
+    };
+    logging::log!("External engine {} plays {} in game {}", follower_name, engine_color, game.id().await);
+    seat_engine(game, engine_number, &follower_name).await;
+    run_dxp_game(game, engine_number, known_plies, &mut connection).await?;
+    Ok(())
+}
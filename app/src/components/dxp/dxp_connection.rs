@@ -0,0 +1,89 @@
+use leptos::*;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use crate::game::dxp::{DxpError, DxpMessage};
+
+const MESSAGE_TERMINATOR: u8 = 0;
+/// Longer messages are no DXP messages, the longest one is a chat message.
+const MAX_MESSAGE_LENGTH: u64 = 512;
+
+
+/// A TCP connection to a DXP peer. The messages are read by a background task, so waiting
+///  for the next message can be cancelled without losing a partly received one.
+pub struct DxpConnection {
+    messages: UnboundedReceiver<Result<DxpMessage, DxpError>>,
+    writer: OwnedWriteHalf,
+    peer: String,
+}
+
+impl DxpConnection {
+    pub async fn connect(address: &str) -> Result<Self, DxpError> {
+        let stream = TcpStream::connect(address).await.map_err(|error| DxpError::Io(error.to_string()))?;
+        Ok(DxpConnection::new(stream))
+    }
+
+    pub fn new(stream: TcpStream) -> Self {
+        let peer = stream.peer_addr().map(|address| address.to_string()).unwrap_or_else(|_| "unknown peer".to_string());
+        // DXP messages are short and answered one by one, so they should not wait for more data
+        let _ = stream.set_nodelay(true);
+        let (reader, writer) = stream.into_split();
+        let (sender, messages) = unbounded_channel();
+        tokio::spawn(read_messages(BufReader::new(reader), sender));
+        Self {
+            messages,
+            writer,
+            peer,
+        }
+    }
+
+    /// The address of the peer, for log messages.
+    pub fn peer(&self) -> &str {
+        &self.peer
+    }
+
+    pub async fn send(&mut self, message: &DxpMessage) -> Result<(), DxpError> {
+        let mut bytes = message.to_string().into_bytes();
+        bytes.push(MESSAGE_TERMINATOR);
+        self.writer.write_all(&bytes).await.map_err(|error| DxpError::Io(error.to_string()))
+    }
+
+    /// The next message of the peer. Messages which cannot be parsed are returned as error,
+    ///  the connection stays usable after them.
+    pub async fn receive(&mut self) -> Result<DxpMessage, DxpError> {
+        self.messages.recv().await.unwrap_or(Err(DxpError::ConnectionClosed))
+    }
+}
+
+async fn read_messages(mut reader: BufReader<OwnedReadHalf>, sender: UnboundedSender<Result<DxpMessage, DxpError>>) {
+    loop {
+        let mut buffer = Vec::new();
+        let read = (&mut reader).take(MAX_MESSAGE_LENGTH).read_until(MESSAGE_TERMINATOR, &mut buffer).await;
+        let message = match read {
+            Ok(0) => return,
+            Ok(_) if buffer.last() != Some(&MESSAGE_TERMINATOR) => {
+                if buffer.len() as u64 == MAX_MESSAGE_LENGTH {
+                    let _ = sender.send(Err(DxpError::InvalidMessage("The message is too long.".to_string())));
+                }
+                return;
+            }
+            Ok(_) => {
+                buffer.pop();
+                match String::from_utf8(buffer) {
+                    Ok(text) => DxpMessage::parse(&text),
+                    Err(_) => Err(DxpError::InvalidMessage("The message is not ASCII text.".to_string())),
+                }
+            }
+            Err(error) => {
+                logging::error!("Cannot read from DXP peer: {}", error);
+                let _ = sender.send(Err(DxpError::Io(error.to_string())));
+                return;
+            }
+        };
+        if sender.send(message).is_err() {
+            return;
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+use leptos::*;
+use tokio::net::TcpListener;
+use uuid::Uuid;
+
+use super::dxp_client::DXP_NAME;
+use super::dxp_connection::DxpConnection;
+use super::dxp_session::{release_engine_seat, run_dxp_game, seat_engine, SessionEnd};
+use crate::pages::game_page::{GameState, PlayerInfo};
+use crate::game::bot_settings::{BotLevel, BotSettings, BOT_NAME};
+use crate::game::dxp::{requested_time_control, DxpAcceptance, DxpError, DxpMessage};
+use crate::game::game_settings::GameSettings;
+use crate::game::opening::Opening;
+
+/// The environment variable with the address to wait for DXP engines on, e.g. `0.0.0.0:27531`.
+pub const DXP_ADDRESS_VARIABLE: &str = "DXP_ADDRESS";
+
+
+/// Background task, which lets external engines play matches against the bot. Every game
+///  request of a connected engine creates a new game, which can be watched like any other.
+pub async fn run_dxp_server(game_state: Arc<GameState>, address: String) {
+    let listener = match TcpListener::bind(&address).await {
+        Ok(listener) => listener,
+        Err(error) => {
+            logging::error!("Cannot wait for DXP engines on {}: {}", address, error);
+            return;
+        }
+    };
+    logging::log!("Waiting for DXP engines on {}", address);
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                tokio::spawn(handle_dxp_peer(game_state.clone(), DxpConnection::new(stream)));
+            }
+            Err(error) => logging::error!("Cannot accept DXP connection: {}", error),
+        }
+    }
+}
+
+/// Plays the games the peer requests, until it ends the session.
+async fn handle_dxp_peer(game_state: Arc<GameState>, mut connection: DxpConnection) {
+    let peer = connection.peer().to_string();
+    logging::log!("DXP engine connected from {}", peer);
+    loop {
+        match play_requested_game(&game_state, &mut connection).await {
+            Ok(SessionEnd::NextGame) => continue,
+            Ok(SessionEnd::Stop) => break,
+            Err(error) => {
+                logging::error!("DXP session with {} ended: {}", peer, error);
+                break;
+            }
+        }
+    }
+    logging::log!("DXP engine from {} disconnected", peer);
+}
+
+async fn play_requested_game(game_state: &GameState, connection: &mut DxpConnection) -> Result<SessionEnd, DxpError> {
+    let (initiator_name, follower_color, time_control, position) = loop {
+        match connection.receive().await {
+            Ok(DxpMessage::GameRequest { initiator_name, follower_color, minutes, moves, position, .. }) => {
+                let Some(time_control) = requested_time_control(minutes, moves) else {
+                    logging::warn!("Refusing game of DXP engine {} with {} minutes for {} moves", initiator_name, minutes, moves);
+                    refuse_game(connection, DxpAcceptance::TimeRefused).await?;
+                    continue;
+                };
+                break (initiator_name, follower_color, time_control, position);
+            }
+            Ok(DxpMessage::Chat(text)) => logging::log!("DXP engine at {} says: {}", connection.peer(), text),
+            Ok(other) => logging::warn!("Unexpected DXP message before the game: {}", other),
+            Err(error @ DxpError::UnsupportedVersion(_)) => {
+                logging::warn!("Refusing game of DXP engine at {}: {}", connection.peer(), error);
+                refuse_game(connection, DxpAcceptance::Refused).await?;
+            }
+            Err(error @ (DxpError::InvalidMessage(_) | DxpError::UnknownMessageType(_))) => {
+                logging::warn!("Ignoring message of DXP engine at {}: {}", connection.peer(), error);
+            }
+            Err(error) => return Err(error),
+        }
+    };
+
+    // engine matches are played against the bot at full strength
+    let settings = GameSettings {
+        time_control,
+        opening: position.map(Opening::Position).unwrap_or_default(),
+        bot: Some(BotSettings { level: BotLevel::Expert, ..Default::default() }),
+        ..Default::default()
+    };
+    let game = match game_state.create_game(Uuid::now_v7(), settings).await {
+        Ok(game) => game.expect("A new game id is not taken."),
+        Err(error) => {
+            logging::warn!("Refusing game of DXP engine {}: {}", initiator_name, error);
+            refuse_game(connection, DxpAcceptance::PositionRefused).await?;
+            return Ok(SessionEnd::NextGame);
+        }
+    };
+    let game_id = game.id().await;
+    let bot_number = follower_color.player_number();
+    let engine_number = follower_color.opponent().player_number();
+    game.with_player(|mut players| {
+        players.players[bot_number] = PlayerInfo::bot(bot_number, BOT_NAME);
+        players
+    }).await;
+    seat_engine(&game, engine_number, &initiator_name).await;
+    logging::log!("DXP engine {} plays game {} against the bot", initiator_name, game_id);
+    connection.send(&DxpMessage::GameAcceptance {
+        follower_name: format!("{} ({})", BOT_NAME, DXP_NAME),
+        acceptance: DxpAcceptance::Accepted,
+    }).await?;
+
+    // the engine requested the game from its starting position, so every move is new to it
+    let session_end = run_dxp_game(&game, engine_number, 0, connection).await;
+    release_engine_seat(&game, engine_number).await;
+    session_end
+}
+
+async fn refuse_game(connection: &mut DxpConnection, acceptance: DxpAcceptance) -> Result<(), DxpError> {
+    connection.send(&DxpMessage::GameAcceptance {
+        follower_name: DXP_NAME.to_string(),
+        acceptance,
+    }).await
+}
@@ -0,0 +1,181 @@
+use std::time::Duration;
+use leptos::*;
+use chrono::Utc;
+use tokio::time::{timeout, Instant};
+
+use super::dxp_connection::DxpConnection;
+use crate::pages::game_page::{Game, PlayerInfo};
+use crate::game::dxp::{backup_ply, DxpBackupAcceptance, DxpError, DxpGameEndReason, DxpMessage};
+use crate::game::game_result::{GameResult, WinReason};
+use crate::game::notation::format_move;
+use crate::game::player_color::PlayerColor;
+
+/// How often the session looks for moves of the engine's opponent.
+const SESSION_INTERVAL_IN_MS: u64 = 200;
+/// How long the peer may take to confirm the end of a game.
+const GAME_END_TIMEOUT_IN_SEC: u64 = 10;
+
+
+/// What the peer wants to do after a game.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SessionEnd {
+    NextGame,
+    Stop,
+}
+
+
+/// Gives the seat to the engine and starts the clock, if the other seat is taken as well.
+///  The clock scheduler would start it later, but the engine may move right away.
+pub async fn seat_engine(game: &Game, engine_number: usize, name: &str) {
+    game.with_player(|mut players| {
+        if let Some(player) = players.players.get_mut(engine_number) {
+            *player = PlayerInfo::engine(engine_number, name);
+        }
+        players
+    }).await;
+    let all_players_assigned = game.players().await.iter().all(|player| player.public_data.is_assigned);
+    if all_players_assigned && !game.clock().await.is_started {
+        game.with_clock(|mut clock| {
+            clock.start(Utc::now());
+            clock
+        }).await;
+    }
+}
+
+/// Frees the seat of an engine which left an unfinished game, so someone else can take over.
+pub async fn release_engine_seat(game: &Game, engine_number: usize) {
+    if game.result().await.is_some() {
+        return;
+    }
+    game.with_player(|mut players| {
+        if let Some(player) = players.players.get_mut(engine_number).filter(|player| player.public_data.is_engine) {
+            *player = PlayerInfo::new(engine_number);
+        }
+        players
+    }).await;
+}
+
+/// Relays a game between the engine on the seat and the game: the moves of the opponent are
+///  sent to the engine and its moves are played on its seat, until either side ends the game.
+///  The engine knows the position after the first `known_plies` moves, which the game request
+///  was sent for.
+pub async fn run_dxp_game(game: &Game, engine_number: usize, known_plies: usize, connection: &mut DxpConnection) -> Result<SessionEnd, DxpError> {
+    let game_id = game.id().await;
+    let engine_color = PlayerColor::from_player_number(engine_number)
+        .ok_or_else(|| DxpError::InvalidMessage(format!("Invalid seat {}.", engine_number)))?;
+    let first_player = game.initial_position().await.side_to_move;
+    let mut known_plies = known_plies;
+    let mut last_move_at = Instant::now();
+    let mut interval = tokio::time::interval(Duration::from_millis(SESSION_INTERVAL_IN_MS));
+    loop {
+        tokio::select! {
+            message = connection.receive() => {
+                let message = match message {
+                    Ok(message) => message,
+                    Err(error @ (DxpError::InvalidMessage(_) | DxpError::UnknownMessageType(_) | DxpError::UnsupportedVersion(_))) => {
+                        logging::warn!("Ignoring message of DXP engine in game {}: {}", game_id, error);
+                        continue;
+                    }
+                    Err(error) => return Err(error),
+                };
+                match message {
+                    DxpMessage::Move { .. } => {
+                        let position = game.position().await;
+                        if position.side_to_move != engine_color {
+                            return Err(DxpError::IllegalMove(format!("{} out of turn", message)));
+                        }
+                        let mv = message.to_move(&position)?;
+                        let notation = format_move(&position, &mv);
+                        logging::log!("DXP engine plays {} in game {}", notation, game_id);
+                        game.play_move(engine_number, &notation).await
+                            .map_err(|rejection| DxpError::IllegalMove(format!("{}: {}", notation, rejection)))?;
+                        last_move_at = Instant::now();
+                    }
+                    DxpMessage::GameEnd { reason, stop_session } => {
+                        end_game_for_engine(game, engine_color, reason).await;
+                        let result = game.result().await;
+                        connection.send(&DxpMessage::GameEnd { reason: game_end_reason(result, engine_color), stop_session }).await?;
+                        return Ok(if stop_session { SessionEnd::Stop } else { SessionEnd::NextGame });
+                    }
+                    DxpMessage::BackupRequest { move_number, side_to_move } => {
+                        // games are recorded move by move, so only going back to the current position works
+                        let number_of_plies = game.moves().await.len();
+                        let acceptance = match backup_ply(first_player, move_number, side_to_move) {
+                            Some(ply) if ply == number_of_plies => DxpBackupAcceptance::Accepted,
+                            _ => DxpBackupAcceptance::NotSupported,
+                        };
+                        connection.send(&DxpMessage::BackupAcceptance(acceptance)).await?;
+                    }
+                    DxpMessage::Chat(text) => logging::log!("DXP engine in game {} says: {}", game_id, text),
+                    other => logging::warn!("Unexpected DXP message in game {}: {}", game_id, other),
+                }
+            }
+            _ = interval.tick() => {
+                let moves = game.moves().await;
+                for (ply, recorded_move) in moves.iter().enumerate().skip(known_plies) {
+                    let player = if ply % 2 == 0 { first_player } else { first_player.opponent() };
+                    if player != engine_color {
+                        let seconds = last_move_at.elapsed().as_secs().min(u16::MAX as u64) as u16;
+                        connection.send(&DxpMessage::from_move(&recorded_move.mv, seconds)).await?;
+                    }
+                    last_move_at = Instant::now();
+                }
+                known_plies = known_plies.max(moves.len());
+                if let Some(result) = game.result().await {
+                    logging::log!("Game {} of DXP engine ended: {}", game_id, result);
+                    let reason = game_end_reason(Some(result), engine_color);
+                    connection.send(&DxpMessage::GameEnd { reason, stop_session: false }).await?;
+                    return wait_for_game_end(connection).await;
+                }
+            }
+        }
+    }
+}
+
+/// Applies the end of the game sent by the engine. The opponent never agreed to a draw, so
+///  an engine claiming one leaves the game like with a loss and resigns, but a claimed win
+///  has to be on the board already.
+async fn end_game_for_engine(game: &Game, engine_color: PlayerColor, reason: DxpGameEndReason) {
+    let _lock = game.lock().await;
+    if game.result().await.is_some() {
+        return;
+    }
+    let result = match reason {
+        DxpGameEndReason::Loss | DxpGameEndReason::Draw => {
+            GameResult::Win { winner: engine_color.opponent(), reason: WinReason::Resignation }
+        }
+        DxpGameEndReason::Win | DxpGameEndReason::Unknown => return,
+    };
+    game.with_clock(|mut clock| {
+        clock.stop();
+        clock
+    }).await;
+    game.set_result(Some(result)).await;
+}
+
+/// The result from our point of view, the opponent of the engine.
+fn game_end_reason(result: Option<GameResult>, engine_color: PlayerColor) -> DxpGameEndReason {
+    match result {
+        Some(GameResult::Win { winner, .. }) if winner == engine_color => DxpGameEndReason::Loss,
+        Some(GameResult::Win { .. }) => DxpGameEndReason::Win,
+        Some(GameResult::Draw { .. }) => DxpGameEndReason::Draw,
+        None => DxpGameEndReason::Unknown,
+    }
+}
+
+/// The peer confirms the end of a game with its own game end message.
+async fn wait_for_game_end(connection: &mut DxpConnection) -> Result<SessionEnd, DxpError> {
+    let confirmation = timeout(Duration::from_secs(GAME_END_TIMEOUT_IN_SEC), async {
+        loop {
+            match connection.receive().await {
+                Ok(DxpMessage::GameEnd { stop_session, .. }) => {
+                    return Ok(if stop_session { SessionEnd::Stop } else { SessionEnd::NextGame });
+                }
+                Ok(DxpMessage::Chat(text)) => logging::log!("DXP engine says: {}", text),
+                Ok(_) | Err(DxpError::InvalidMessage(_) | DxpError::UnknownMessageType(_) | DxpError::UnsupportedVersion(_)) => {}
+                Err(error) => return Err(error),
+            }
+        }
+    }).await;
+    confirmation.unwrap_or(Ok(SessionEnd::Stop))
+}
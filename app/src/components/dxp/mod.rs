@@ -0,0 +1,19 @@
+#[cfg(feature = "ssr")]
+mod dxp_connection;
+#[cfg(feature = "ssr")]
+mod dxp_session;
+#[cfg(feature = "ssr")]
+mod dxp_client;
+#[cfg(feature = "ssr")]
+mod dxp_server;
+
+#[cfg(feature = "ssr")]
+pub use dxp_connection::DxpConnection as DxpConnection;
+#[cfg(feature = "ssr")]
+pub use dxp_client::engine_address as engine_address;
+#[cfg(feature = "ssr")]
+pub use dxp_client::play_engine_in_game as play_engine_in_game;
+#[cfg(feature = "ssr")]
+pub use dxp_server::run_dxp_server as run_dxp_server;
+#[cfg(feature = "ssr")]
+pub use dxp_server::DXP_ADDRESS_VARIABLE as DXP_ADDRESS_VARIABLE;
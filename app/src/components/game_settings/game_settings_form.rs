@@ -0,0 +1,306 @@
+use leptos::*;
+
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::game_settings::GameSettings;
+use crate::game::time_control::TimeControl;
+use crate::game::abandonment::ABANDONMENT_GRACE_PERIODS_IN_SEC;
+use crate::game::handicap::{MAX_HANDICAP_PIECES, TIME_ODDS_IN_PERCENT};
+use crate::game::player_color::PlayerColor;
+use crate::game::ballot::{ballot, ballot_deck};
+use crate::game::opening::Opening;
+use crate::game::board::Position;
+use crate::game::bot_settings::{BotLevel, BotSettings, BotStyle};
+
+const STANDARD_OPENING_VALUE: &str = "standard";
+const RANDOM_BALLOT_VALUE: &str = "random";
+const CUSTOM_POSITION_VALUE: &str = "position";
+const NO_BOT_VALUE: &str = "none";
+
+
+/// The settings of a new game. `fen_error` holds the error of an entered position that
+///  cannot be played, the settings keep the last valid opening until it is fixed.
+#[component]
+pub fn GameSettingsForm(
+    #[prop(into)]
+    settings: RwSignal<GameSettings>,
+    #[prop(into)]
+    fen_error: RwSignal<Option<String>>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let (fen, set_fen) = create_signal(Position::initial().to_fen());
+    let (is_custom_position, set_is_custom_position) = create_signal(
+        settings.with_untracked(|settings| matches!(settings.opening, Opening::Position(_)))
+    );
+    let apply_fen = move |fen: String| {
+        match Position::from_fen(&fen).and_then(|position| position.validate().map(|_| position)) {
+            Ok(position) => {
+                fen_error.set(None);
+                settings.update(|settings| settings.opening = Opening::Position(position));
+            }
+            Err(error) => {
+                fen_error.set(Some(error.translate(locale.get_untracked())));
+            }
+        }
+        set_fen.set(fen);
+    };
+    // an invalid position keeps the previous opening in the settings, so the selected
+    //  option follows the choice of the user instead
+    let is_selected = move |opening: &Opening| {
+        !is_custom_position.get() && settings.with(|settings| settings.opening == *opening)
+    };
+    let has_bot = move || settings.with(|settings| settings.bot.is_some());
+
+    view! {
+        <div class="p-2 flex justify-center items-center">
+            <p class="mr-2">{move || Text::TimeControl.translate(locale.get())}</p>
+            <select
+                class="select select-bordered select-sm"
+                on:change=move |ev| {
+                    let time_control = event_target_value(&ev).parse::<usize>().ok()
+                        .and_then(|index| TimeControl::all().get(index).copied());
+                    if let Some(time_control) = time_control {
+                        settings.update(|settings| settings.time_control = time_control);
+                    }
+                }
+            >
+                {TimeControl::all().into_iter().enumerate().map(|(index, time_control)| view! {
+                    <option
+                        value=index.to_string()
+                        selected=move || settings.with(|settings| settings.time_control == time_control)
+                    >
+                        {move || time_control.translate(locale.get())}
+                    </option>
+                }).collect_view()}
+            </select>
+            <p class="ml-4 mr-2">{move || Text::AbandonmentAfter.translate(locale.get())}</p>
+            <select
+                class="select select-bordered select-sm"
+                on:change=move |ev| {
+                    if let Ok(grace_period_in_sec) = event_target_value(&ev).parse::<i64>() {
+                        settings.update(|settings| settings.abandonment.grace_period_in_sec = grace_period_in_sec);
+                    }
+                }
+            >
+                {ABANDONMENT_GRACE_PERIODS_IN_SEC.iter().map(|grace_period_in_sec| {
+                    let grace_period_in_sec = *grace_period_in_sec;
+                    view! {
+                        <option
+                            value=grace_period_in_sec.to_string()
+                            selected=move || settings.with(|settings| {
+                                settings.abandonment.grace_period_in_sec == grace_period_in_sec
+                            })
+                        >
+                            {move || Text::Minutes(grace_period_in_sec / 60).translate(locale.get())}
+                        </option>
+                    }
+                }).collect_view()}
+            </select>
+        </div>
+        <div class="p-2 flex justify-center items-center">
+            <p class="mr-2">{move || Text::Opening.translate(locale.get())}</p>
+            <select
+                class="select select-bordered select-sm"
+                on:change=move |ev| {
+                    let value = event_target_value(&ev);
+                    set_is_custom_position.set(value == CUSTOM_POSITION_VALUE);
+                    let opening = match value.as_str() {
+                        STANDARD_OPENING_VALUE => Some(Opening::Standard),
+                        RANDOM_BALLOT_VALUE => Some(Opening::RandomBallot),
+                        CUSTOM_POSITION_VALUE => {
+                            apply_fen(fen.get_untracked());
+                            None
+                        }
+                        number => number.parse::<usize>().ok().and_then(ballot).map(Opening::Ballot),
+                    };
+                    if let Some(opening) = opening {
+                        fen_error.set(None);
+                        settings.update(|settings| settings.opening = opening);
+                    }
+                }
+            >
+                <option
+                    value=STANDARD_OPENING_VALUE
+                    selected=move || is_selected(&Opening::Standard)
+                >
+                    {move || Opening::Standard.translate(locale.get())}
+                </option>
+                <option
+                    value=RANDOM_BALLOT_VALUE
+                    selected=move || is_selected(&Opening::RandomBallot)
+                >
+                    {move || Opening::RandomBallot.translate(locale.get())}
+                </option>
+                <option
+                    value=CUSTOM_POSITION_VALUE
+                    selected=move || is_custom_position.get()
+                >
+                    {move || Text::CustomPosition.translate(locale.get())}
+                </option>
+                {ballot_deck().into_iter().map(|ballot| {
+                    let number = ballot.number;
+                    view! {
+                        <option
+                            value=number.to_string()
+                            selected=move || !is_custom_position.get() && settings.with(|settings| {
+                                matches!(&settings.opening, Opening::Ballot(ballot) if ballot.number == number)
+                            })
+                        >
+                            {move || ballot.translate(locale.get())}
+                        </option>
+                    }
+                }).collect_view()}
+            </select>
+        </div>
+        <Show when=move || is_custom_position.get()>
+            <div class="p-2 w-full flex flex-col items-center">
+                <input
+                    type="text"
+                    class="input input-bordered input-sm w-full max-w-xl font-mono"
+                    on:input=move |ev| apply_fen(event_target_value(&ev))
+                    prop:value=fen
+                />
+                <Show when=move || fen_error.get().is_some()>
+                    <p class="content-error text-xs">{move || fen_error.get().unwrap_or_default()}</p>
+                </Show>
+            </div>
+        </Show>
+        <div class="p-2 flex justify-center items-center">
+            <p class="mr-2">{move || Text::HandicapFor.translate(locale.get())}</p>
+            <select
+                class="select select-bordered select-sm"
+                on:change=move |ev| {
+                    let stronger_player = event_target_value(&ev).parse::<usize>().ok()
+                        .and_then(PlayerColor::from_player_number);
+                    if let Some(stronger_player) = stronger_player {
+                        settings.update(|settings| settings.handicap.stronger_player = stronger_player);
+                    }
+                }
+            >
+                {[PlayerColor::Red, PlayerColor::Blue].into_iter().map(|player| view! {
+                    <option
+                        value=player.player_number().to_string()
+                        selected=move || settings.with(|settings| settings.handicap.stronger_player == player)
+                    >
+                        {move || player.translate(locale.get())}
+                    </option>
+                }).collect_view()}
+            </select>
+            <p class="ml-4 mr-2">{move || Text::MenRemoved.translate(locale.get())}</p>
+            <select
+                class="select select-bordered select-sm"
+                on:change=move |ev| {
+                    if let Ok(removed_men) = event_target_value(&ev).parse::<usize>() {
+                        settings.update(|settings| settings.handicap.removed_men = removed_men);
+                    }
+                }
+            >
+                {(0..=MAX_HANDICAP_PIECES).map(|removed_men| view! {
+                    <option
+                        value=removed_men.to_string()
+                        selected=move || settings.with(|settings| settings.handicap.removed_men == removed_men)
+                    >
+                        {removed_men.to_string()}
+                    </option>
+                }).collect_view()}
+            </select>
+            <p class="ml-4 mr-2">{move || Text::ExtraKings.translate(locale.get())}</p>
+            <select
+                class="select select-bordered select-sm"
+                on:change=move |ev| {
+                    if let Ok(extra_kings) = event_target_value(&ev).parse::<usize>() {
+                        settings.update(|settings| settings.handicap.extra_kings = extra_kings);
+                    }
+                }
+            >
+                {(0..=MAX_HANDICAP_PIECES).map(|extra_kings| view! {
+                    <option
+                        value=extra_kings.to_string()
+                        selected=move || settings.with(|settings| settings.handicap.extra_kings == extra_kings)
+                    >
+                        {extra_kings.to_string()}
+                    </option>
+                }).collect_view()}
+            </select>
+            <p class="ml-4 mr-2">{move || Text::Time.translate(locale.get())}</p>
+            <select
+                class="select select-bordered select-sm"
+                on:change=move |ev| {
+                    if let Ok(time_odds_in_percent) = event_target_value(&ev).parse::<u32>() {
+                        settings.update(|settings| settings.handicap.time_odds_in_percent = time_odds_in_percent);
+                    }
+                }
+            >
+                {TIME_ODDS_IN_PERCENT.iter().map(|time_odds_in_percent| {
+                    let time_odds_in_percent = *time_odds_in_percent;
+                    view! {
+                        <option
+                            value=time_odds_in_percent.to_string()
+                            selected=move || settings.with(|settings| {
+                                settings.handicap.time_odds_in_percent == time_odds_in_percent
+                            })
+                        >
+                            {format!("{}%", time_odds_in_percent)}
+                        </option>
+                    }
+                }).collect_view()}
+            </select>
+        </div>
+        <div class="p-2 flex justify-center items-center">
+            <p class="mr-2">{move || Text::ComputerOpponent.translate(locale.get())}</p>
+            <select
+                class="select select-bordered select-sm"
+                on:change=move |ev| {
+                    let level = event_target_value(&ev).parse::<usize>().ok()
+                        .and_then(|index| BotLevel::all().get(index).copied());
+                    settings.update(|settings| {
+                        settings.bot = level.map(|level| BotSettings {
+                            level,
+                            style: settings.bot.map(|bot| bot.style).unwrap_or_default(),
+                        });
+                    });
+                }
+            >
+                <option
+                    value=NO_BOT_VALUE
+                    selected=move || !has_bot()
+                >
+                    {move || Text::NoComputerOpponent.translate(locale.get())}
+                </option>
+                {BotLevel::all().into_iter().enumerate().map(|(index, level)| view! {
+                    <option
+                        value=index.to_string()
+                        selected=move || settings.with(|settings| settings.bot.is_some_and(|bot| bot.level == level))
+                    >
+                        {move || level.translate(locale.get())}
+                    </option>
+                }).collect_view()}
+            </select>
+            <Show when=has_bot>
+                <p class="ml-4 mr-2">{move || Text::PlayingStyle.translate(locale.get())}</p>
+                <select
+                    class="select select-bordered select-sm"
+                    on:change=move |ev| {
+                        let style = event_target_value(&ev).parse::<usize>().ok()
+                            .and_then(|index| BotStyle::all().get(index).copied());
+                        if let Some(style) = style {
+                            settings.update(|settings| {
+                                if let Some(bot) = settings.bot.as_mut() {
+                                    bot.style = style;
+                                }
+                            });
+                        }
+                    }
+                >
+                    {BotStyle::all().into_iter().enumerate().map(|(index, style)| view! {
+                        <option
+                            value=index.to_string()
+                            selected=move || settings.with(|settings| settings.bot.is_some_and(|bot| bot.style == style))
+                        >
+                            {move || style.translate(locale.get())}
+                        </option>
+                    }).collect_view()}
+                </select>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,50 @@
+use leptos::*;
+use uuid::Uuid;
+
+use crate::game::game_settings::{GameSettings, GameSettingsError};
+
+
+#[server(CreateGame, "/api")]
+pub async fn create_game(
+    game_id: Uuid,
+    settings: GameSettings,
+) -> Result<(), ServerFnError<GameSettingsError>> {
+    use std::sync::Arc;
+    use leptos_axum::extract;
+    use axum::extract::Extension;
+    use rand::seq::SliceRandom;
+    use crate::pages::game_page::GameState;
+    use crate::game::ballot::ballot_deck;
+    use crate::game::opening::Opening;
+
+    let game_state = extract::<Extension<Arc<GameState>>>().await
+        .expect("Cannot get the game-state extension.");
+    let mut settings = settings;
+    if settings.opening == Opening::RandomBallot {
+        settings.opening = ballot_deck().choose(&mut rand::thread_rng()).cloned()
+            .map(Opening::Ballot)
+            .unwrap_or_default();
+    }
+    settings.validate().map_err(ServerFnError::WrappedServerError)?;
+    logging::log!("Creating game {} with settings {:?}", game_id, settings);
+    game_state.create_game(game_id, settings).await
+        .map_err(ServerFnError::WrappedServerError)?
+        .ok_or_else(|| ServerFnError::ServerError(format!("Game {} already exists.", game_id)))?;
+    Ok(())
+}
+
+#[server(GetGameSettings, "/api")]
+pub async fn get_game_settings(
+    game_id: Uuid,
+) -> Result<GameSettings, ServerFnError> {
+    use std::sync::Arc;
+    use leptos_axum::extract;
+    use axum::extract::Extension;
+    use crate::pages::game_page::GameState;
+
+    let game_state = extract::<Extension<Arc<GameState>>>().await
+        .expect("Cannot get the game-state extension.");
+    let game = game_state.get_game(game_id).await
+        .ok_or_else(|| ServerFnError::ServerError(format!("Game {} not found.", game_id)))?;
+    Ok(game.settings().await)
+}
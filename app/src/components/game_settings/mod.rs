@@ -0,0 +1,6 @@
+mod game_settings_server_function;
+mod game_settings_form;
+
+pub use game_settings_server_function::create_game as create_game;
+pub use game_settings_server_function::get_game_settings as get_game_settings;
+pub use game_settings_form::GameSettingsForm as GameSettingsForm;
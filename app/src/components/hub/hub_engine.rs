@@ -0,0 +1,148 @@
+use std::time::Duration;
+use leptos::*;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+use super::hub_process::HubProcess;
+use crate::game::board::Position;
+use crate::game::engine::{Score, WIN_SCORE};
+use crate::game::hub::{parse_hub_move, HubClock, HubCommand, HubError, HubLevel, HubResponse};
+use crate::game::moves::Move;
+
+/// The environment variable with the command line of the engine playing the bot's moves,
+///  e.g. `scan hub`.
+pub const HUB_BOT_COMMAND_VARIABLE: &str = "HUB_BOT_COMMAND";
+/// The environment variable with the command line of the engine analysing finished games.
+pub const HUB_ANALYSIS_COMMAND_VARIABLE: &str = "HUB_ANALYSIS_COMMAND";
+/// How much longer than its move time the engine may think, before it is stopped.
+const MOVE_TIME_MARGIN_IN_MS: u64 = 1000;
+/// The longest search without a move time, the engine is stopped earlier when its clock runs out.
+const MAX_SEARCH_TIME_IN_SEC: u64 = 60;
+/// How long the engine may take to answer `stop` with its best move so far.
+const STOP_TIMEOUT_IN_MS: u64 = 1000;
+
+
+/// The outcome of a search of a Hub engine.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct HubSearchResult {
+    /// `None` if the side to move has no legal moves.
+    pub best_move: Option<Move>,
+    /// From the point of view of the side to move, `None` if the engine sent no score.
+    pub score: Option<Score>,
+    pub depth: Option<u8>,
+}
+
+
+/// An external engine, which is started on the first search and restarted on the next one
+///  after it crashed or hung. Searches are answered one after the other.
+pub struct HubEngine {
+    program: String,
+    arguments: Vec<String>,
+    process: Mutex<Option<HubProcess>>,
+}
+
+impl HubEngine {
+    pub fn new(program: String, arguments: Vec<String>) -> Self {
+        Self {
+            program,
+            arguments,
+            process: Mutex::new(None),
+        }
+    }
+
+    /// The engine started by the command line in the environment variable, `None` if it is
+    ///  not set.
+    pub fn from_variable(variable: &str) -> Option<Self> {
+        let command_line = std::env::var(variable).ok()?;
+        let mut words = command_line.split_whitespace().map(str::to_string);
+        let program = words.next()?;
+        Some(HubEngine::new(program, words.collect()))
+    }
+
+    /// The command line of the engine, for log messages.
+    pub fn command_line(&self) -> String {
+        std::iter::once(&self.program).chain(&self.arguments).cloned().collect::<Vec<_>>().join(" ")
+    }
+
+    /// Searches the position of the game until one of the limits is reached, with the clock of
+    ///  the game if it has one. A search which takes much longer than the move time is stopped,
+    ///  and the engine is restarted for the next one if it does not answer the stop either.
+    pub async fn search(&self, game_id: Uuid, position: &Position, level: HubLevel, clock: Option<HubClock>) -> Result<HubSearchResult, HubError> {
+        // the position is decided, there is nothing to ask the engine
+        if position.legal_moves().is_empty() {
+            return Ok(HubSearchResult { best_move: None, score: Some(-WIN_SCORE), depth: None });
+        }
+        let mut process = self.process.lock().await;
+        if process.is_none() {
+            let started = HubProcess::start(&self.program, &self.arguments).await?;
+            logging::log!("Started Hub engine {}", started.name());
+            *process = Some(started);
+        }
+        let result = match process.as_mut() {
+            Some(running) => search_with(running, game_id, position, level, clock).await,
+            None => Err(HubError::EngineStopped),
+        };
+        if let Err(error) = &result {
+            // an engine out of step with the protocol cannot be trusted with the next search
+            if let Some(failed) = process.take() {
+                logging::error!("Restarting Hub engine {}: {}", failed.name(), error);
+                failed.quit().await;
+            }
+        }
+        result
+    }
+}
+
+async fn search_with(
+    process: &mut HubProcess,
+    game_id: Uuid,
+    position: &Position,
+    level: HubLevel,
+    clock: Option<HubClock>,
+) -> Result<HubSearchResult, HubError> {
+    process.select_game(game_id).await?;
+    process.send(&HubCommand::Position(*position)).await?;
+    process.send(&HubCommand::Level(level)).await?;
+    if let Some(clock) = clock {
+        process.send(&HubCommand::Time(clock)).await?;
+    }
+    // lines left over from an earlier search must not be taken for answers to this one
+    process.synchronize().await?;
+    process.send(&HubCommand::Go).await?;
+
+    let max_search_time = clock
+        .map_or(Duration::from_secs(MAX_SEARCH_TIME_IN_SEC), |clock| clock.my_time.min(Duration::from_secs(MAX_SEARCH_TIME_IN_SEC)));
+    let search_time = level.move_time
+        .map(|move_time| move_time + Duration::from_millis(MOVE_TIME_MARGIN_IN_MS))
+        .unwrap_or(max_search_time);
+    let mut deadline = Instant::now() + search_time;
+    let mut is_stopped = false;
+    let (mut score, mut depth) = (None, None);
+    loop {
+        let response = match process.receive_until(deadline).await {
+            Err(HubError::Timeout) if !is_stopped => {
+                process.send(&HubCommand::Stop).await?;
+                is_stopped = true;
+                deadline = Instant::now() + Duration::from_millis(STOP_TIMEOUT_IN_MS);
+                continue;
+            }
+            response => response?,
+        };
+        match response {
+            HubResponse::Info(info) => {
+                score = info.score.or(score);
+                depth = info.depth.or(depth);
+            }
+            HubResponse::Done { best_move } => {
+                let best_move = match best_move {
+                    Some(notation) => Some(parse_hub_move(position, &notation)?),
+                    None => return Err(HubError::IllegalMove("no move".to_string())),
+                };
+                return Ok(HubSearchResult { best_move, score, depth });
+            }
+            HubResponse::Error(message) => return Err(HubError::EngineError(message)),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::process::Stdio;
+use std::time::Duration;
+use leptos::*;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdin, ChildStdout, Command};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio::time::{timeout, Instant};
+use uuid::Uuid;
+
+use crate::game::hub::{HubCommand, HubError, HubResponse, HUB_VARIANT, VARIANT_PARAM};
+
+/// How long the engine may take to introduce itself and to load its data, e.g. endgame
+///  databases.
+const HANDSHAKE_TIMEOUT_IN_SEC: u64 = 60;
+/// How long the engine may take to exit after `quit`, before it is killed.
+const QUIT_TIMEOUT_IN_MS: u64 = 1000;
+/// How long the engine may take to answer `ping`.
+const PING_TIMEOUT_IN_MS: u64 = 1000;
+
+
+/// A running engine, which talks the Hub protocol on its standard input and output. The
+///  lines are read by a background task, so waiting for the next one can be cancelled. The
+///  process is killed when this is dropped.
+pub struct HubProcess {
+    child: Child,
+    stdin: ChildStdin,
+    lines: UnboundedReceiver<String>,
+    name: String,
+    /// The game of the last search.
+    game_id: Option<Uuid>,
+}
+
+impl HubProcess {
+    /// Starts the engine for English checkers and waits until it is ready to search.
+    pub async fn start(program: &str, arguments: &[String]) -> Result<Self, HubError> {
+        let mut child = Command::new(program)
+            .args(arguments)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            // the diagnostics of the engine end up in the server log
+            .stderr(Stdio::inherit())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|error| HubError::Io(format!("Cannot start {}: {}", program, error)))?;
+        let stdin = child.stdin.take().ok_or_else(|| HubError::Io("No standard input.".to_string()))?;
+        let stdout = child.stdout.take().ok_or_else(|| HubError::Io("No standard output.".to_string()))?;
+        let (sender, lines) = unbounded_channel();
+        tokio::spawn(read_lines(BufReader::new(stdout), sender));
+        let mut process = Self {
+            child,
+            stdin,
+            lines,
+            name: program.to_string(),
+            game_id: None,
+        };
+
+        let deadline = Instant::now() + Duration::from_secs(HANDSHAKE_TIMEOUT_IN_SEC);
+        process.send(&HubCommand::Hub).await?;
+        loop {
+            match process.receive_until(deadline).await? {
+                HubResponse::Id { name: Some(name), version } => {
+                    process.name = match version {
+                        Some(version) => format!("{} {}", name, version),
+                        None => name,
+                    };
+                }
+                HubResponse::Wait => break,
+                HubResponse::Error(message) => return Err(HubError::EngineError(message)),
+                _ => {}
+            }
+        }
+        process.send(&HubCommand::SetParam { name: VARIANT_PARAM.to_string(), value: HUB_VARIANT.to_string() }).await?;
+        process.send(&HubCommand::Init).await?;
+        loop {
+            match process.receive_until(deadline).await? {
+                HubResponse::Ready => break,
+                HubResponse::Error(message) => return Err(HubError::EngineError(message)),
+                _ => {}
+            }
+        }
+        process.send(&HubCommand::NewGame).await?;
+        Ok(process)
+    }
+
+    /// Tells the engine to forget the previous game, if the next search is in another one.
+    pub async fn select_game(&mut self, game_id: Uuid) -> Result<(), HubError> {
+        if self.game_id.is_some_and(|last_game_id| last_game_id != game_id) {
+            self.send(&HubCommand::NewGame).await?;
+        }
+        self.game_id = Some(game_id);
+        Ok(())
+    }
+
+    /// Waits until the engine handled all commands sent before, the lines it sends meanwhile
+    ///  are skipped.
+    pub async fn synchronize(&mut self) -> Result<(), HubError> {
+        self.send(&HubCommand::Ping).await?;
+        let deadline = Instant::now() + Duration::from_millis(PING_TIMEOUT_IN_MS);
+        loop {
+            match self.receive_until(deadline).await? {
+                HubResponse::Pong => return Ok(()),
+                HubResponse::Error(message) => return Err(HubError::EngineError(message)),
+                _ => {}
+            }
+        }
+    }
+
+    /// The name and version the engine introduced itself with, for log messages.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub async fn send(&mut self, command: &HubCommand) -> Result<(), HubError> {
+        let line = format!("{}\n", command);
+        self.stdin.write_all(line.as_bytes()).await.map_err(|error| HubError::Io(error.to_string()))?;
+        self.stdin.flush().await.map_err(|error| HubError::Io(error.to_string()))
+    }
+
+    /// The next line of the engine, or `HubError::Timeout` after the deadline. Lines which
+    ///  cannot be parsed are skipped.
+    pub async fn receive_until(&mut self, deadline: Instant) -> Result<HubResponse, HubError> {
+        loop {
+            let line = match tokio::time::timeout_at(deadline, self.lines.recv()).await {
+                Ok(Some(line)) => line,
+                Ok(None) => return Err(HubError::EngineStopped),
+                Err(_) => return Err(HubError::Timeout),
+            };
+            match HubResponse::parse(&line) {
+                Ok(response) => return Ok(response),
+                Err(HubError::InvalidMessage(_)) if line.trim().is_empty() => {}
+                Err(error) => logging::warn!("Ignoring line of Hub engine {}: {}", self.name, error),
+            }
+        }
+    }
+
+    /// Asks the engine to exit and kills it, if it does not.
+    pub async fn quit(mut self) {
+        let _ = self.send(&HubCommand::Quit).await;
+        if timeout(Duration::from_millis(QUIT_TIMEOUT_IN_MS), self.child.wait()).await.is_err() {
+            let _ = self.child.kill().await;
+        }
+    }
+}
+
+async fn read_lines(mut reader: BufReader<ChildStdout>, sender: UnboundedSender<String>) {
+    loop {
+        let mut line = String::new();
+        match reader.read_line(&mut line).await {
+            Ok(0) => return,
+            Ok(_) => {
+                if sender.send(line.trim_end().to_string()).is_err() {
+                    return;
+                }
+            }
+            Err(error) => {
+                logging::error!("Cannot read from Hub engine: {}", error);
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,13 @@
+#[cfg(feature = "ssr")]
+mod hub_process;
+#[cfg(feature = "ssr")]
+mod hub_engine;
+
+#[cfg(feature = "ssr")]
+pub use hub_engine::HubEngine as HubEngine;
+#[cfg(feature = "ssr")]
+pub use hub_engine::HubSearchResult as HubSearchResult;
+#[cfg(feature = "ssr")]
+pub use hub_engine::HUB_BOT_COMMAND_VARIABLE as HUB_BOT_COMMAND_VARIABLE;
+#[cfg(feature = "ssr")]
+pub use hub_engine::HUB_ANALYSIS_COMMAND_VARIABLE as HUB_ANALYSIS_COMMAND_VARIABLE;
@@ -0,0 +1,36 @@
+use leptos::*;
+
+use super::locale::{Locale, LocaleContext};
+use super::text::Text;
+use super::translate::Translate;
+
+
+#[component]
+pub fn LanguageSelect() -> impl IntoView {
+    let LocaleContext { locale, set_chosen_locale } = use_context::<LocaleContext>()
+        .expect("The locale should be provided by the app.");
+
+    view! {
+        <select
+            class="select select-bordered select-sm"
+            aria-label=move || Text::Language.translate(locale.get())
+            on:change=move |ev| {
+                let chosen_locale = event_target_value(&ev).parse::<usize>().ok()
+                    .and_then(|index| Locale::all().get(index).copied());
+                if let Some(chosen_locale) = chosen_locale {
+                    set_chosen_locale.set(Some(chosen_locale));
+                }
+            }
+        >
+            {Locale::all().into_iter().enumerate().map(|(index, option)| view! {
+                <option
+                    value=index.to_string()
+                    lang=option.code()
+                    selected=move || locale.get() == option
+                >
+                    {option.to_string()}
+                </option>
+            }).collect_view()}
+        </select>
+    }
+}
@@ -0,0 +1,135 @@
+use core::fmt;
+use leptos::*;
+use leptos_use::{use_cookie_with_options, UseCookieOptions, SameSite};
+use codee::string::JsonSerdeCodec;
+use serde::{Deserialize, Serialize};
+
+const LOCALE_COOKIE_NAME: &str = "locale";
+
+
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Locale {
+    #[default]
+    English,
+    German,
+}
+
+/// The plural forms a count can take, named after the CLDR plural categories.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum PluralCategory {
+    One,
+    Other,
+}
+
+impl Locale {
+    pub fn all() -> Vec<Locale> {
+        vec![Locale::English, Locale::German]
+    }
+
+    /// The language code as used in `Accept-Language` and the `lang` attribute.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::German => "de",
+        }
+    }
+
+    pub fn plural_category(&self, count: i64) -> PluralCategory {
+        match (self, count) {
+            (Locale::English | Locale::German, 1) => PluralCategory::One,
+            (Locale::English | Locale::German, _) => PluralCategory::Other,
+        }
+    }
+
+    /// The supported locale of a language tag like `de-CH`, ignoring the region.
+    pub fn from_language_tag(language_tag: &str) -> Option<Locale> {
+        let language = language_tag.trim().split(['-', '_']).next()?;
+        Locale::all().into_iter().find(|locale| locale.code().eq_ignore_ascii_case(language))
+    }
+
+    /// The first supported locale of the language tags, which are ordered by preference.
+    pub fn from_language_tags<'a>(language_tags: impl IntoIterator<Item = &'a str>) -> Option<Locale> {
+        language_tags.into_iter().find_map(Locale::from_language_tag)
+    }
+
+    /// The preferred supported locale of an `Accept-Language` header like `de-CH,de;q=0.9,en;q=0.8`.
+    pub fn from_accept_language(accept_language: &str) -> Option<Locale> {
+        let mut language_tags: Vec<(&str, f32)> = accept_language.split(',')
+            .filter_map(|language_range| {
+                let mut parts = language_range.split(';');
+                let language_tag = parts.next()?.trim();
+                let quality = parts
+                    .find_map(|parameter| parameter.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |quality| quality.trim().parse::<f32>().ok())?;
+                Some((language_tag, quality))
+            })
+            .filter(|(_, quality)| *quality > 0.0)
+            .collect();
+        // The sort is stable, so tags with the same quality keep their order.
+        language_tags.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        Locale::from_language_tags(language_tags.into_iter().map(|(language_tag, _)| language_tag))
+    }
+}
+
+// The name of the language in the language itself, as shown in the language switcher.
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Locale::English => write!(f, "English"),
+            Locale::German => write!(f, "Deutsch"),
+        }
+    }
+}
+
+
+#[derive(Clone, Copy)]
+pub struct LocaleContext {
+    pub locale: Signal<Locale>,
+    /// The locale chosen in the language switcher, `None` to follow the browser.
+    pub set_chosen_locale: WriteSignal<Option<Locale>>,
+}
+
+
+/// The locale preferred by the browser: from the `Accept-Language` header while rendering on
+///  the server and from the navigator languages in the browser, so both render the same texts.
+fn detect_locale() -> Locale {
+    #[cfg(feature = "ssr")]
+    let locale = use_context::<http::request::Parts>().and_then(|parts| {
+        let accept_language = parts.headers.get(http::header::ACCEPT_LANGUAGE)?.to_str().ok()?;
+        Locale::from_accept_language(accept_language)
+    });
+    #[cfg(not(feature = "ssr"))]
+    let locale = {
+        let language_tags: Vec<String> = window().navigator().languages().iter()
+            .filter_map(|language_tag| language_tag.as_string())
+            .collect();
+        Locale::from_language_tags(language_tags.iter().map(String::as_str))
+    };
+    locale.unwrap_or_default()
+}
+
+/// Provides the locale to all components below: the one chosen in the language switcher, which
+///  is kept in a cookie, or the one detected from the browser languages.
+pub fn provide_locale() -> LocaleContext {
+    let detected_locale = detect_locale();
+    let (chosen_locale, set_chosen_locale) = use_cookie_with_options::<Locale, JsonSerdeCodec>(
+        LOCALE_COOKIE_NAME,
+        UseCookieOptions::default()
+            .max_age::<i64>(Some(1000*60*60*24*365)) // 1 year
+            .same_site(SameSite::Lax)
+            .path("/".to_string()),
+    );
+    let locale = Signal::derive(move || chosen_locale.get().unwrap_or(detected_locale));
+
+    let context = LocaleContext { locale, set_chosen_locale };
+    provide_context(context);
+    context
+}
+
+/// The locale provided by `provide_locale`, the default one without it.
+pub fn use_locale() -> Signal<Locale> {
+    match use_context::<LocaleContext>() {
+        Some(context) => context.locale,
+        None => Signal::derive(Locale::default),
+    }
+}
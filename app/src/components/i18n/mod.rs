@@ -0,0 +1,9 @@
+mod locale;
+mod translate;
+mod text;
+mod language_select;
+
+pub use locale::{provide_locale, use_locale, Locale, LocaleContext, PluralCategory};
+pub use translate::Translate as Translate;
+pub use text::Text as Text;
+pub use language_select::LanguageSelect as LanguageSelect;
@@ -0,0 +1,449 @@
+use crate::game::board::{Piece, PieceKind, Square};
+use crate::game::game_result::GameResult;
+use crate::game::player_color::PlayerColor;
+
+use super::locale::{Locale, PluralCategory};
+use super::translate::Translate;
+
+
+/// The texts of the user interface, parameters are translated together with the text.
+#[derive(Debug, Clone)]
+pub enum Text {
+    Language,
+    Error(String),
+    ErrorHeading { number_of_errors: usize },
+    InvalidGameId,
+    CreateNewGameHint,
+    Back,
+    NewGame,
+    JoinGame,
+    PlayOffline,
+    PlayHotseat,
+    PlayAs,
+    StartOfflineGame,
+    ComputerThinking,
+    UploadGame,
+    UploadFailed,
+    Resign,
+    OfferDraw,
+    DrawDeclined,
+
+    Appearance,
+    Colours,
+    Board,
+    Pieces,
+    Coordinates,
+    Animations,
+    Sounds,
+
+    ThisIsGame,
+    Share,
+    Copied,
+    DownloadPdn,
+    PlayerNumber(usize),
+    ReturnGameOf,
+    PlayReturnGame,
+
+    JoinGameAs,
+    /// The button to join the game on the seat of the colour.
+    JoinAs(PlayerColor),
+    DefaultPlayerName,
+    JoiningGame,
+    /// The badge of the seat a player occupies.
+    Seat(PlayerColor),
+    UnknownPlayerName,
+    UnknownSeat,
+    Online,
+    Away,
+    Offline,
+    /// The badge shown instead of the connection status for the computer.
+    Bot,
+    PlayAgainstComputer,
+    PlayAgainstEngine,
+    PlayerAssignmentFailed(String),
+    PlayerReassignmentFailed(String),
+    OpponentLeft,
+    ClaimWin,
+    ClaimDraw,
+
+    GameAdjourned,
+    WaitingForOpponentToResume,
+    ResumeGame,
+    YouOfferedAdjournment,
+    OpponentOffersAdjournment,
+    Accept,
+    Decline,
+    OfferAdjournment,
+
+    WaitingForPlayers,
+    Adjourned,
+    ToMove(PlayerColor),
+    RemainingTime,
+
+    BoardDescription,
+    SquareDescription { square: Square, piece: Option<Piece>, is_selected: bool, is_destination: bool },
+    YourMove,
+    Play,
+    Premove(String),
+    CancelPremove,
+    MoveHistory,
+    FirstPosition,
+    PreviousMove,
+    NextMove,
+    LivePosition,
+    BackToLive,
+    MovePlayed { player: PlayerColor, notation: String, number_of_captures: usize },
+    GameOver(GameResult),
+    ConditionalMovesHint,
+    SaveConditionalMoves,
+    Analysis,
+    AnalysisPending,
+    EvaluationGraph,
+    NoMistakesFound,
+    BetterMove(String),
+    AnalysisBoard,
+    LastPosition,
+    FlipBoard,
+    Variations,
+    Comment,
+    PromoteVariation,
+    DeleteVariation,
+    ForcedWin(PlayerColor),
+    SearchDepth(u8),
+    BestMove(String),
+
+    TimeControl,
+    AbandonmentAfter,
+    Minutes(i64),
+    Opening,
+    CustomPosition,
+    HandicapFor,
+    MenRemoved,
+    ExtraKings,
+    Time,
+    ComputerOpponent,
+    NoComputerOpponent,
+    PlayingStyle,
+    PastePdn,
+    ImportPdn,
+    ImportedGames(usize),
+}
+
+impl Translate for Text {
+    fn translate(&self, locale: Locale) -> String {
+        match locale {
+            Locale::English => self.english(locale),
+            Locale::German => self.german(locale),
+        }
+    }
+}
+
+impl Text {
+    fn english(&self, locale: Locale) -> String {
+        match self {
+            Text::Language => "Language".to_string(),
+            Text::Error(error) => format!("Error: {}", error),
+            Text::ErrorHeading { number_of_errors } => match locale.plural_category(*number_of_errors as i64) {
+                PluralCategory::One => "Error".to_string(),
+                PluralCategory::Other => "Errors".to_string(),
+            },
+            Text::InvalidGameId => "Invalid game ID!".to_string(),
+            Text::CreateNewGameHint => "Go back and create a new Game.".to_string(),
+            Text::Back => "Back".to_string(),
+            Text::NewGame => "New Game".to_string(),
+            Text::JoinGame => "Join Game".to_string(),
+            Text::PlayOffline => "Play offline against the computer".to_string(),
+            Text::PlayHotseat => "Pass and play on this device".to_string(),
+            Text::PlayAs => "Play as".to_string(),
+            Text::StartOfflineGame => "Start game".to_string(),
+            Text::ComputerThinking => "The computer is thinking".to_string(),
+            Text::UploadGame => "Upload game".to_string(),
+            Text::UploadFailed => "The game could not be uploaded.".to_string(),
+            Text::Resign => "Resign".to_string(),
+            Text::OfferDraw => "Offer draw".to_string(),
+            Text::DrawDeclined => "The computer declines the draw.".to_string(),
+
+            Text::Appearance => "Appearance".to_string(),
+            Text::Colours => "Colours".to_string(),
+            Text::Board => "Board".to_string(),
+            Text::Pieces => "Pieces".to_string(),
+            Text::Coordinates => "Coordinates".to_string(),
+            Text::Animations => "Animations".to_string(),
+            Text::Sounds => "Sounds".to_string(),
+
+            Text::ThisIsGame => "This is game ".to_string(),
+            Text::Share => "Share".to_string(),
+            Text::Copied => "Copied!".to_string(),
+            Text::DownloadPdn => "Download PDN".to_string(),
+            Text::PlayerNumber(player_number) => format!("Player: {}", player_number),
+            Text::ReturnGameOf => "Return game with reversed colours of ".to_string(),
+            Text::PlayReturnGame => "Play return game".to_string(),
+
+            Text::JoinGameAs => "Join game as ".to_string(),
+            Text::JoinAs(color) => format!("{} Player", color),
+            Text::DefaultPlayerName => "Player".to_string(),
+            Text::JoiningGame => "Joining game".to_string(),
+            Text::Seat(color) => format!("Player {}", color),
+            Text::UnknownPlayerName => "Unknown".to_string(),
+            Text::UnknownSeat => "Unknown player assignment".to_string(),
+            Text::Online => "Online".to_string(),
+            Text::Away => "Away".to_string(),
+            Text::Offline => "Offline".to_string(),
+            Text::Bot => "Bot".to_string(),
+            Text::PlayAgainstComputer => "Play against the computer".to_string(),
+            Text::PlayAgainstEngine => "Play against the external engine".to_string(),
+            Text::PlayerAssignmentFailed(reason) => format!("Player assignment failed: {}", reason),
+            Text::PlayerReassignmentFailed(reason) => format!("Player reassignment failed: {}", reason),
+            Text::OpponentLeft => "Your opponent has left the game.".to_string(),
+            Text::ClaimWin => "Claim win".to_string(),
+            Text::ClaimDraw => "Claim draw".to_string(),
+
+            Text::GameAdjourned => "This game is adjourned.".to_string(),
+            Text::WaitingForOpponentToResume => "Waiting for your opponent to resume the game.".to_string(),
+            Text::ResumeGame => "Resume game".to_string(),
+            Text::YouOfferedAdjournment => "You offered to adjourn the game.".to_string(),
+            Text::OpponentOffersAdjournment => "Your opponent offers to adjourn the game.".to_string(),
+            Text::Accept => "Accept".to_string(),
+            Text::Decline => "Decline".to_string(),
+            Text::OfferAdjournment => "Offer adjournment".to_string(),
+
+            Text::WaitingForPlayers => "Waiting for players".to_string(),
+            Text::Adjourned => "Adjourned".to_string(),
+            Text::ToMove(color) => format!("{} to move", color),
+            Text::RemainingTime => "Remaining time".to_string(),
+
+            Text::BoardDescription => {
+                "Checkers board. Use the arrow keys to move between squares and Enter to select a square.".to_string()
+            }
+            Text::SquareDescription { square, piece, is_selected, is_destination } => {
+                let contents = match piece {
+                    Some(Piece { color, kind: PieceKind::King }) => format!("{} king", color),
+                    Some(Piece { color, kind: PieceKind::Man }) => format!("{} man", color),
+                    None => "empty".to_string(),
+                };
+                let state = match (is_selected, is_destination) {
+                    (true, _) => ", selected",
+                    (false, true) => ", possible destination",
+                    (false, false) => "",
+                };
+                format!("Square {}: {}{}", square, contents, state)
+            }
+            Text::YourMove => "Your move".to_string(),
+            Text::Play => "Play".to_string(),
+            Text::Premove(notation) => format!("Premove: {}", notation),
+            Text::CancelPremove => "Cancel premove".to_string(),
+            Text::MoveHistory => "Move history".to_string(),
+            Text::FirstPosition => "First position".to_string(),
+            Text::PreviousMove => "Previous move".to_string(),
+            Text::NextMove => "Next move".to_string(),
+            Text::LivePosition => "Live position".to_string(),
+            Text::BackToLive => "Back to live".to_string(),
+            Text::MovePlayed { player, notation, number_of_captures: 0 } => format!("{} played {}.", player, notation),
+            Text::MovePlayed { player, notation, number_of_captures } => {
+                match locale.plural_category(*number_of_captures as i64) {
+                    PluralCategory::One => format!("{} played {}, capturing {} piece.", player, notation, number_of_captures),
+                    PluralCategory::Other => format!("{} played {}, capturing {} pieces.", player, notation, number_of_captures),
+                }
+            }
+            Text::GameOver(result) => format!("Game over: {}", result.translate(locale)),
+            Text::ConditionalMovesHint => {
+                "Conditional moves: one line per variation, opponent moves and your replies alternating.".to_string()
+            }
+            Text::SaveConditionalMoves => "Save conditional moves".to_string(),
+            Text::Analysis => "Analysis".to_string(),
+            Text::AnalysisPending => "The computer is analysing the game.".to_string(),
+            Text::EvaluationGraph => "Evaluation over the course of the game".to_string(),
+            Text::NoMistakesFound => "No mistakes found.".to_string(),
+            Text::BetterMove(notation) => format!("Better: {}", notation),
+            Text::AnalysisBoard => "Analysis board".to_string(),
+            Text::LastPosition => "Last position".to_string(),
+            Text::FlipBoard => "Flip board".to_string(),
+            Text::Variations => "Variations".to_string(),
+            Text::Comment => "Comment".to_string(),
+            Text::PromoteVariation => "Promote variation".to_string(),
+            Text::DeleteVariation => "Delete variation".to_string(),
+            Text::ForcedWin(player) => format!("{} wins", player),
+            Text::SearchDepth(depth) => format!("Depth {}", depth),
+            Text::BestMove(notation) => format!("Best move: {}", notation),
+
+            Text::TimeControl => "Time control".to_string(),
+            Text::AbandonmentAfter => "Abandonment after".to_string(),
+            Text::Minutes(minutes) => match locale.plural_category(*minutes) {
+                PluralCategory::One => format!("{} minute", minutes),
+                PluralCategory::Other => format!("{} minutes", minutes),
+            },
+            Text::Opening => "Opening".to_string(),
+            Text::CustomPosition => "Custom position (FEN)".to_string(),
+            Text::HandicapFor => "Handicap for".to_string(),
+            Text::MenRemoved => "Men removed".to_string(),
+            Text::ExtraKings => "Extra kings".to_string(),
+            Text::Time => "Time".to_string(),
+            Text::ComputerOpponent => "Computer opponent".to_string(),
+            Text::NoComputerOpponent => "None".to_string(),
+            Text::PlayingStyle => "Style".to_string(),
+            Text::PastePdn => "Paste PDN here".to_string(),
+            Text::ImportPdn => "Import PDN".to_string(),
+            Text::ImportedGames(number_of_games) => match locale.plural_category(*number_of_games as i64) {
+                PluralCategory::One => format!("{} game imported", number_of_games),
+                PluralCategory::Other => format!("{} games imported", number_of_games),
+            },
+        }
+    }
+
+    fn german(&self, locale: Locale) -> String {
+        match self {
+            Text::Language => "Sprache".to_string(),
+            Text::Error(error) => format!("Fehler: {}", error),
+            Text::ErrorHeading { .. } => "Fehler".to_string(),
+            Text::InvalidGameId => "Ungültige Partie-ID!".to_string(),
+            Text::CreateNewGameHint => "Geh zurück und erstelle eine neue Partie.".to_string(),
+            Text::Back => "Zurück".to_string(),
+            Text::NewGame => "Neue Partie".to_string(),
+            Text::JoinGame => "Partie beitreten".to_string(),
+            Text::PlayOffline => "Offline gegen den Computer spielen".to_string(),
+            Text::PlayHotseat => "Zu zweit an diesem Gerät spielen".to_string(),
+            Text::PlayAs => "Spielen als".to_string(),
+            Text::StartOfflineGame => "Partie starten".to_string(),
+            Text::ComputerThinking => "Der Computer denkt nach".to_string(),
+            Text::UploadGame => "Partie hochladen".to_string(),
+            Text::UploadFailed => "Die Partie konnte nicht hochgeladen werden.".to_string(),
+            Text::Resign => "Aufgeben".to_string(),
+            Text::OfferDraw => "Remis anbieten".to_string(),
+            Text::DrawDeclined => "Der Computer lehnt das Remis ab.".to_string(),
+
+            Text::Appearance => "Darstellung".to_string(),
+            Text::Colours => "Farben".to_string(),
+            Text::Board => "Brett".to_string(),
+            Text::Pieces => "Steine".to_string(),
+            Text::Coordinates => "Koordinaten".to_string(),
+            Text::Animations => "Animationen".to_string(),
+            Text::Sounds => "Töne".to_string(),
+
+            Text::ThisIsGame => "Das ist die Partie ".to_string(),
+            Text::Share => "Teilen".to_string(),
+            Text::Copied => "Kopiert!".to_string(),
+            Text::DownloadPdn => "PDN herunterladen".to_string(),
+            Text::PlayerNumber(player_number) => format!("Spieler: {}", player_number),
+            Text::ReturnGameOf => "Rückpartie mit getauschten Farben von ".to_string(),
+            Text::PlayReturnGame => "Rückpartie spielen".to_string(),
+
+            Text::JoinGameAs => "Partie beitreten als ".to_string(),
+            Text::JoinAs(PlayerColor::Red) => "Roter Spieler".to_string(),
+            Text::JoinAs(PlayerColor::Blue) => "Blauer Spieler".to_string(),
+            Text::DefaultPlayerName => "Spieler".to_string(),
+            Text::JoiningGame => "Partie wird beigetreten".to_string(),
+            Text::Seat(color) => format!("Spieler {}", color.translate(locale)),
+            Text::UnknownPlayerName => "Unbekannt".to_string(),
+            Text::UnknownSeat => "Unbekannte Spielerzuordnung".to_string(),
+            Text::Online => "Online".to_string(),
+            Text::Away => "Abwesend".to_string(),
+            Text::Offline => "Offline".to_string(),
+            Text::Bot => "Bot".to_string(),
+            Text::PlayAgainstComputer => "Gegen den Computer spielen".to_string(),
+            Text::PlayAgainstEngine => "Gegen die externe Engine spielen".to_string(),
+            Text::PlayerAssignmentFailed(reason) => format!("Beitritt zur Partie fehlgeschlagen: {}", reason),
+            Text::PlayerReassignmentFailed(reason) => format!("Erneuter Beitritt zur Partie fehlgeschlagen: {}", reason),
+            Text::OpponentLeft => "Dein Gegner hat die Partie verlassen.".to_string(),
+            Text::ClaimWin => "Sieg beanspruchen".to_string(),
+            Text::ClaimDraw => "Remis beanspruchen".to_string(),
+
+            Text::GameAdjourned => "Diese Partie ist vertagt.".to_string(),
+            Text::WaitingForOpponentToResume => "Warte darauf, dass dein Gegner die Partie fortsetzt.".to_string(),
+            Text::ResumeGame => "Partie fortsetzen".to_string(),
+            Text::YouOfferedAdjournment => "Du hast angeboten, die Partie zu vertagen.".to_string(),
+            Text::OpponentOffersAdjournment => "Dein Gegner bietet an, die Partie zu vertagen.".to_string(),
+            Text::Accept => "Annehmen".to_string(),
+            Text::Decline => "Ablehnen".to_string(),
+            Text::OfferAdjournment => "Vertagung anbieten".to_string(),
+
+            Text::WaitingForPlayers => "Warte auf Spieler".to_string(),
+            Text::Adjourned => "Vertagt".to_string(),
+            Text::ToMove(color) => format!("{} ist am Zug", color.translate(locale)),
+            Text::RemainingTime => "Verbleibende Zeit".to_string(),
+
+            Text::BoardDescription => {
+                "Damebrett. Wechsle mit den Pfeiltasten zwischen den Feldern und wähle ein Feld mit Enter.".to_string()
+            }
+            Text::SquareDescription { square, piece, is_selected, is_destination } => {
+                let contents = match piece {
+                    Some(Piece { color: PlayerColor::Red, kind: PieceKind::King }) => "rote Dame",
+                    Some(Piece { color: PlayerColor::Blue, kind: PieceKind::King }) => "blaue Dame",
+                    Some(Piece { color: PlayerColor::Red, kind: PieceKind::Man }) => "roter Stein",
+                    Some(Piece { color: PlayerColor::Blue, kind: PieceKind::Man }) => "blauer Stein",
+                    None => "leer",
+                };
+                let state = match (is_selected, is_destination) {
+                    (true, _) => ", ausgewählt",
+                    (false, true) => ", mögliches Ziel",
+                    (false, false) => "",
+                };
+                format!("Feld {}: {}{}", square, contents, state)
+            }
+            Text::YourMove => "Dein Zug".to_string(),
+            Text::Play => "Ziehen".to_string(),
+            Text::Premove(notation) => format!("Vorauszug: {}", notation),
+            Text::CancelPremove => "Vorauszug zurücknehmen".to_string(),
+            Text::MoveHistory => "Zugliste".to_string(),
+            Text::FirstPosition => "Anfangsstellung".to_string(),
+            Text::PreviousMove => "Vorheriger Zug".to_string(),
+            Text::NextMove => "Nächster Zug".to_string(),
+            Text::LivePosition => "Aktuelle Stellung".to_string(),
+            Text::BackToLive => "Zurück zur aktuellen Stellung".to_string(),
+            Text::MovePlayed { player, notation, number_of_captures: 0 } => {
+                format!("{} hat {} gezogen.", player.translate(locale), notation)
+            }
+            Text::MovePlayed { player, notation, number_of_captures } => {
+                match locale.plural_category(*number_of_captures as i64) {
+                    PluralCategory::One => format!(
+                        "{} hat {} gezogen und {} Stein geschlagen.", player.translate(locale), notation, number_of_captures,
+                    ),
+                    PluralCategory::Other => format!(
+                        "{} hat {} gezogen und {} Steine geschlagen.", player.translate(locale), notation, number_of_captures,
+                    ),
+                }
+            }
+            Text::GameOver(result) => format!("Partie beendet: {}", result.translate(locale)),
+            Text::ConditionalMovesHint => {
+                "Bedingte Züge: eine Zeile pro Variante, abwechselnd Züge des Gegners und deine Antworten.".to_string()
+            }
+            Text::SaveConditionalMoves => "Bedingte Züge speichern".to_string(),
+            Text::Analysis => "Analyse".to_string(),
+            Text::AnalysisPending => "Der Computer analysiert die Partie.".to_string(),
+            Text::EvaluationGraph => "Bewertung im Verlauf der Partie".to_string(),
+            Text::NoMistakesFound => "Keine Fehler gefunden.".to_string(),
+            Text::BetterMove(notation) => format!("Besser: {}", notation),
+            Text::AnalysisBoard => "Analysebrett".to_string(),
+            Text::LastPosition => "Letzte Stellung".to_string(),
+            Text::FlipBoard => "Brett drehen".to_string(),
+            Text::Variations => "Varianten".to_string(),
+            Text::Comment => "Kommentar".to_string(),
+            Text::PromoteVariation => "Variante zur Hauptvariante machen".to_string(),
+            Text::DeleteVariation => "Variante löschen".to_string(),
+            Text::ForcedWin(player) => format!("{} gewinnt", player.translate(locale)),
+            Text::SearchDepth(depth) => format!("Tiefe {}", depth),
+            Text::BestMove(notation) => format!("Bester Zug: {}", notation),
+
+            Text::TimeControl => "Bedenkzeit".to_string(),
+            Text::AbandonmentAfter => "Partie verlassen nach".to_string(),
+            Text::Minutes(minutes) => match locale.plural_category(*minutes) {
+                PluralCategory::One => format!("{} Minute", minutes),
+                PluralCategory::Other => format!("{} Minuten", minutes),
+            },
+            Text::Opening => "Eröffnung".to_string(),
+            Text::CustomPosition => "Eigene Stellung (FEN)".to_string(),
+            Text::HandicapFor => "Vorgabe durch".to_string(),
+            Text::MenRemoved => "Steine weniger".to_string(),
+            Text::ExtraKings => "Zusätzliche Damen".to_string(),
+            Text::Time => "Zeit".to_string(),
+            Text::ComputerOpponent => "Computergegner".to_string(),
+            Text::NoComputerOpponent => "Keiner".to_string(),
+            Text::PlayingStyle => "Spielweise".to_string(),
+            Text::PastePdn => "PDN hier einfügen".to_string(),
+            Text::ImportPdn => "PDN importieren".to_string(),
+            Text::ImportedGames(number_of_games) => match locale.plural_category(*number_of_games as i64) {
+                PluralCategory::One => format!("{} Partie importiert", number_of_games),
+                PluralCategory::Other => format!("{} Partien importiert", number_of_games),
+            },
+        }
+    }
+}
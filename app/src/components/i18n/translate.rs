@@ -0,0 +1,398 @@
+use core::fmt::Display;
+use leptos::ServerFnError;
+
+use super::locale::{Locale, PluralCategory};
+use crate::components::appearance::{BoardTheme, ColorTheme, CoordinateDisplay, PieceSet};
+use crate::components::player::{AbandonmentClaimError, PlayerAssingmentError};
+use crate::error_template::AppError;
+use crate::game::adjournment::AdjournmentError;
+use crate::game::analysis::MoveJudgement;
+use crate::game::ballot::Ballot;
+use crate::game::bot_settings::{BotLevel, BotSettings, BotStyle};
+use crate::game::conditional_moves::ConditionalMovesError;
+use crate::game::fen::{FenError, MAX_PIECES_PER_PLAYER};
+use crate::game::game_result::{DrawReason, GameResult, WinReason};
+use crate::game::game_settings::GameSettingsError;
+use crate::game::handicap::{Handicap, MAX_HANDICAP_PIECES};
+use crate::game::notation::NotationError;
+use crate::game::opening::Opening;
+use crate::game::pdn::PdnError;
+use crate::game::player_color::PlayerColor;
+use crate::game::time_control::TimeControl;
+use crate::pages::game_page::MoveRejection;
+
+
+/// Texts shown to the user. The `Display` implementations of the game types stay English, as
+///  they end up in logs and PDN files, so English translations mostly fall back to them.
+pub trait Translate {
+    fn translate(&self, locale: Locale) -> String;
+}
+
+impl<E> Translate for ServerFnError<E>
+where
+    E: Translate + Display,
+{
+    fn translate(&self, locale: Locale) -> String {
+        match self {
+            ServerFnError::WrappedServerError(error) => error.translate(locale),
+            error => error.to_string(),
+        }
+    }
+}
+
+
+impl Translate for PlayerColor {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, PlayerColor::Red) => "Rot".to_string(),
+            (Locale::German, PlayerColor::Blue) => "Blau".to_string(),
+        }
+    }
+}
+
+impl Translate for TimeControl {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, TimeControl::Unlimited) => "Unbegrenzt".to_string(),
+            (Locale::German, TimeControl::Correspondence { days_per_move }) => {
+                match locale.plural_category(*days_per_move as i64) {
+                    PluralCategory::One => format!("{} Tag pro Zug", days_per_move),
+                    PluralCategory::Other => format!("{} Tage pro Zug", days_per_move),
+                }
+            }
+        }
+    }
+}
+
+impl Translate for GameResult {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, GameResult::Win { winner, reason }) => {
+                let winner = winner.translate(locale);
+                match reason {
+                    WinReason::Timeout => format!("{} gewinnt durch Zeitüberschreitung", winner),
+                    WinReason::Abandonment => format!("{} gewinnt, der Gegner hat die Partie verlassen", winner),
+                    WinReason::NoMovesLeft => format!("{} gewinnt, der Gegner kann nicht mehr ziehen", winner),
+                    WinReason::Adjudication => format!("{} gewinnt laut Endspieldatenbank", winner),
+                    WinReason::Resignation => format!("{} gewinnt, der Gegner hat aufgegeben", winner),
+                    WinReason::Unspecified => format!("{} gewinnt", winner),
+                }
+            }
+            (Locale::German, GameResult::Draw { reason: DrawReason::Abandonment }) => {
+                "Remis, ein Spieler hat die Partie verlassen".to_string()
+            }
+            (Locale::German, GameResult::Draw { reason: DrawReason::Adjudication }) => "Remis laut Endspieldatenbank".to_string(),
+            (Locale::German, GameResult::Draw { reason: DrawReason::Agreement }) => "Remis nach Vereinbarung".to_string(),
+            (Locale::German, GameResult::Draw { reason: DrawReason::Unspecified }) => "Remis".to_string(),
+        }
+    }
+}
+
+impl Translate for Handicap {
+    fn translate(&self, locale: Locale) -> String {
+        if locale == Locale::English {
+            return self.to_string();
+        }
+        if self.is_none() {
+            return "Keine Vorgabe".to_string();
+        }
+        let mut odds = Vec::new();
+        if self.removed_men > 0 {
+            odds.push(match locale.plural_category(self.removed_men as i64) {
+                PluralCategory::One => format!("{} Stein", self.removed_men),
+                PluralCategory::Other => format!("{} Steine", self.removed_men),
+            });
+        }
+        if self.extra_kings > 0 {
+            odds.push(match locale.plural_category(self.extra_kings as i64) {
+                PluralCategory::One => format!("{} Dame", self.extra_kings),
+                PluralCategory::Other => format!("{} Damen", self.extra_kings),
+            });
+        }
+        if self.time_odds_in_percent != 100 {
+            odds.push(format!("{}% Zeit", self.time_odds_in_percent));
+        }
+        format!("{} gibt Vorgabe: {}", self.stronger_player.translate(locale), odds.join(", "))
+    }
+}
+
+impl Translate for Ballot {
+    fn translate(&self, locale: Locale) -> String {
+        match locale {
+            Locale::English => self.to_string(),
+            Locale::German => match &self.name {
+                Some(name) => format!("Eröffnung {}: {} ({})", self.number, self.moves, name),
+                None => format!("Eröffnung {}: {}", self.number, self.moves),
+            },
+        }
+    }
+}
+
+impl Translate for Opening {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (_, Opening::Ballot(ballot)) => ballot.translate(locale),
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, Opening::Standard) => "Standarderöffnung".to_string(),
+            (Locale::German, Opening::RandomBallot) => "Ausgeloste Eröffnung".to_string(),
+            (Locale::German, Opening::Position(position)) => format!("Stellung {}", position.to_fen()),
+        }
+    }
+}
+
+
+impl Translate for MoveJudgement {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, MoveJudgement::Inaccuracy) => "Ungenauigkeit".to_string(),
+            (Locale::German, MoveJudgement::Mistake) => "Fehler".to_string(),
+            (Locale::German, MoveJudgement::Blunder) => "Grober Fehler".to_string(),
+        }
+    }
+}
+
+impl Translate for BotLevel {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, BotLevel::Beginner) => "Anfänger".to_string(),
+            (Locale::German, BotLevel::Casual) => "Gelegenheitsspieler".to_string(),
+            (Locale::German, BotLevel::Intermediate) => "Fortgeschritten".to_string(),
+            (Locale::German, BotLevel::Advanced) => "Stark".to_string(),
+            (Locale::German, BotLevel::Expert) => "Experte".to_string(),
+        }
+    }
+}
+
+impl Translate for BotStyle {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, BotStyle::Balanced) => "ausgewogen".to_string(),
+            (Locale::German, BotStyle::Aggressive) => "angriffslustig".to_string(),
+            (Locale::German, BotStyle::Defensive) => "vorsichtig".to_string(),
+        }
+    }
+}
+
+impl Translate for BotSettings {
+    fn translate(&self, locale: Locale) -> String {
+        match locale {
+            Locale::English => self.to_string(),
+            Locale::German => format!("Computer: {}, {}", self.level.translate(locale), self.style.translate(locale)),
+        }
+    }
+}
+
+impl Translate for BoardTheme {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, BoardTheme::Wood) => "Holz".to_string(),
+            (Locale::German, BoardTheme::Green) => "Grün".to_string(),
+            (Locale::German, BoardTheme::Gray) => "Grau".to_string(),
+        }
+    }
+}
+
+impl Translate for PieceSet {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, PieceSet::Flat) => "Flach".to_string(),
+            (Locale::German, PieceSet::ThreeD) => "3D".to_string(),
+            (Locale::German, PieceSet::HighContrast) => "Hoher Kontrast".to_string(),
+        }
+    }
+}
+
+impl Translate for CoordinateDisplay {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, CoordinateDisplay::Hidden) => "Keine Koordinaten".to_string(),
+            (Locale::German, CoordinateDisplay::SquareNumbers) => "Feldnummern".to_string(),
+            (Locale::German, CoordinateDisplay::Edges) => "Linien und Reihen".to_string(),
+        }
+    }
+}
+
+impl Translate for ColorTheme {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, ColorTheme::Dark) => "Dunkel".to_string(),
+            (Locale::German, ColorTheme::Light) => "Hell".to_string(),
+        }
+    }
+}
+
+
+impl Translate for NotationError {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, NotationError::InvalidSyntax(notation)) => {
+                format!("'{}' ist kein Zug in numerischer Notation.", notation)
+            }
+            (Locale::German, NotationError::IllegalMove(notation)) => format!("'{}' ist kein erlaubter Zug.", notation),
+            (Locale::German, NotationError::AmbiguousMove { notation, candidates }) => {
+                format!("'{}' ist mehrdeutig, verwende einen von {}.", notation, candidates.join(", "))
+            }
+        }
+    }
+}
+
+impl Translate for FenError {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, FenError::InvalidFormat(s)) => format!("Ungültiges FEN: {}", s),
+            (Locale::German, FenError::InvalidSquare(s)) => format!("Ungültiges Feld '{}'.", s),
+            (Locale::German, FenError::DuplicateSquare(square)) => format!("Feld {} ist doppelt besetzt.", square),
+            (Locale::German, FenError::TooManyPieces(player)) => {
+                format!("{} hat mehr als {} Steine.", player.translate(locale), MAX_PIECES_PER_PLAYER)
+            }
+            (Locale::German, FenError::NoPieces(player)) => format!("{} hat keine Steine.", player.translate(locale)),
+            (Locale::German, FenError::ManOnPromotionRow(square)) => {
+                format!("Der Stein auf Feld {} müsste eine Dame sein.", square)
+            }
+            (Locale::German, FenError::NoLegalMoves(player)) => {
+                format!("{} hat keinen legalen Zug.", player.translate(locale))
+            }
+        }
+    }
+}
+
+impl Translate for GameSettingsError {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (_, GameSettingsError::InvalidPosition(error)) => error.translate(locale),
+            (Locale::English, _) | (_, GameSettingsError::UnknownError(_)) => self.to_string(),
+            (Locale::German, GameSettingsError::InvalidTimeControl) => "Ungültige Bedenkzeit.".to_string(),
+            (Locale::German, GameSettingsError::InvalidTimeOdds) => "Ungültige Zeitvorgabe.".to_string(),
+            (Locale::German, GameSettingsError::InvalidHandicap) => {
+                format!("Es können höchstens {} Steine entfernt oder gekrönt werden.", MAX_HANDICAP_PIECES)
+            }
+            (Locale::German, GameSettingsError::InvalidAbandonmentRules) => "Ungültige Wartezeiten beim Verlassen der Partie.".to_string(),
+            (Locale::German, GameSettingsError::UnknownBallot(number)) => {
+                format!("Eröffnung {} ist nicht im Eröffnungsstapel.", number)
+            }
+        }
+    }
+}
+
+impl Translate for PdnError {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) | (_, PdnError::UnknownError(_)) => self.to_string(),
+            (Locale::German, PdnError::Parse { line, column, message }) => {
+                format!("Zeile {}, Spalte {}: {}", line, column, message)
+            }
+            (Locale::German, PdnError::InvalidSettings { line, error }) => {
+                format!("Zeile {}: {}", line, error.translate(locale))
+            }
+            (Locale::German, PdnError::TooLong { max_length }) => {
+                format!("Die PDN ist länger als {} Zeichen.", max_length)
+            }
+            (Locale::German, PdnError::TooManyGames { max_games }) => {
+                format!("Die PDN enthält mehr als {} Partien.", max_games)
+            }
+            (Locale::German, PdnError::TooManyPlies { line, max_plies }) => {
+                format!("Zeile {}: Die Partie ist länger als {} Halbzüge.", line, max_plies)
+            }
+        }
+    }
+}
+
+impl Translate for ConditionalMovesError {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (_, ConditionalMovesError::InvalidMove(error)) => error.translate(locale),
+            (Locale::English, _) | (_, ConditionalMovesError::UnknownError(_)) => self.to_string(),
+            (Locale::German, ConditionalMovesError::InvalidPlayerSecret) => "Ungültiger Spielerschlüssel.".to_string(),
+            (Locale::German, ConditionalMovesError::GameNotRunning) => "Die Partie läuft nicht.".to_string(),
+            (Locale::German, ConditionalMovesError::NotOpponentsTurn) => {
+                "Züge können nur vorbereitet werden, während der Gegner am Zug ist.".to_string()
+            }
+            (Locale::German, ConditionalMovesError::OnlyForCorrespondence) => {
+                "Bedingte Züge gibt es nur in Fernpartien.".to_string()
+            }
+            (Locale::German, ConditionalMovesError::IncompleteLine(line)) => {
+                format!("'{}' braucht eine Antwort auf jeden Zug des Gegners.", line)
+            }
+            (Locale::German, ConditionalMovesError::ConflictingReplies(notation)) => {
+                format!("Es gibt verschiedene Antworten auf '{}'.", notation)
+            }
+        }
+    }
+}
+
+impl Translate for AdjournmentError {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) | (_, AdjournmentError::UnknownError(_)) => self.to_string(),
+            (Locale::German, AdjournmentError::InvalidPlayerSecret) => "Ungültiger Spielerschlüssel.".to_string(),
+            (Locale::German, AdjournmentError::GameNotRunning) => "Die Partie läuft nicht.".to_string(),
+            (Locale::German, AdjournmentError::AlreadyAdjourned) => "Die Partie ist bereits vertagt.".to_string(),
+            (Locale::German, AdjournmentError::NotAdjourned) => "Die Partie ist nicht vertagt.".to_string(),
+            (Locale::German, AdjournmentError::NoOfferFromOpponent) => {
+                "Der Gegner hat keine Vertagung angeboten.".to_string()
+            }
+        }
+    }
+}
+
+impl Translate for AbandonmentClaimError {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) | (_, AbandonmentClaimError::UnknownError(_)) => self.to_string(),
+            (Locale::German, AbandonmentClaimError::InvalidPlayerNumber) => "Ungültige Spielernummer.".to_string(),
+            (Locale::German, AbandonmentClaimError::InvalidPlayerSecret) => "Ungültiger Spielerschlüssel.".to_string(),
+            (Locale::German, AbandonmentClaimError::GameNotRunning) => "Die Partie läuft nicht.".to_string(),
+            (Locale::German, AbandonmentClaimError::OpponentStillConnected) => "Der Gegner ist noch verbunden.".to_string(),
+            (Locale::German, AbandonmentClaimError::NotAllowedForCorrespondence) => {
+                "In Fernpartien kann das Verlassen der Partie nicht geltend gemacht werden.".to_string()
+            }
+        }
+    }
+}
+
+impl Translate for PlayerAssingmentError {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) | (_, PlayerAssingmentError::UnknownError(_)) => self.to_string(),
+            (Locale::German, PlayerAssingmentError::InvalidPlayerNumber) => "Ungültige Spielernummer.".to_string(),
+            (Locale::German, PlayerAssingmentError::PlayerAllreadyAssigned) => "Der Platz ist bereits vergeben.".to_string(),
+            (Locale::German, PlayerAssingmentError::InvalidPlayerSecret) => "Ungültiger Spielerschlüssel.".to_string(),
+            (Locale::German, PlayerAssingmentError::NoEngineAvailable) => "Keine externe Engine verfügbar.".to_string(),
+        }
+    }
+}
+
+impl Translate for MoveRejection {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (_, MoveRejection::InvalidMove(error)) => error.translate(locale),
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, MoveRejection::GameFinished) => "Die Partie ist bereits beendet.".to_string(),
+            (Locale::German, MoveRejection::GameNotRunning) => "Die Partie läuft nicht.".to_string(),
+            (Locale::German, MoveRejection::NotYourTurn) => "Du bist nicht am Zug.".to_string(),
+            (Locale::German, MoveRejection::InvalidPlayerIdentity) => "Ungültige Spieleridentität.".to_string(),
+            (Locale::German, MoveRejection::NotJoined) => "Tritt der Partie bei, bevor du ziehst.".to_string(),
+        }
+    }
+}
+
+impl Translate for AppError {
+    fn translate(&self, locale: Locale) -> String {
+        match (locale, self) {
+            (Locale::English, _) => self.to_string(),
+            (Locale::German, AppError::NotFound) => "Nicht gefunden".to_string(),
+        }
+    }
+}
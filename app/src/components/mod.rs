@@ -1 +1,16 @@
-pub mod player;
\ No newline at end of file
+pub mod player;
+pub mod clock;
+pub mod game_settings;
+pub mod adjournment;
+pub mod ballot;
+pub mod pdn;
+pub mod board;
+pub mod move_history;
+pub mod conditional_moves;
+pub mod appearance;
+pub mod i18n;
+pub mod offline;
+pub mod analysis;
+pub mod analysis_board;
+pub mod dxp;
+pub mod hub;
\ No newline at end of file
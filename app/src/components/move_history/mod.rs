@@ -0,0 +1,3 @@
+mod move_history;
+
+pub use move_history::MoveHistory as MoveHistory;
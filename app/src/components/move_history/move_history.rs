@@ -0,0 +1,118 @@
+use leptos::*;
+use leptos::ev::KeyboardEvent;
+use wasm_bindgen::JsCast;
+
+use crate::components::analysis::judgement_text_class;
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::analysis::MoveJudgement;
+use crate::game::player_color::PlayerColor;
+
+
+/// The moves of the game in numeric notation. Clicking a move or using the arrow keys shows
+///  the position after it, `None` as viewed ply means the live position.
+#[component]
+pub fn MoveHistory(
+    #[prop(into)]
+    moves: Signal<Vec<String>>,
+    /// The player making the first move of the game.
+    #[prop(into)]
+    first_player: Signal<PlayerColor>,
+    #[prop(into)]
+    viewed_ply: RwSignal<Option<usize>>,
+    /// The judgement of the analysis for every ply, shown as move strength suffix.
+    #[prop(optional, into)]
+    judgements: MaybeSignal<Vec<Option<MoveJudgement>>>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let number_of_plies = move || moves.with(Vec::len);
+    let current_ply = move || viewed_ply.get().unwrap_or_else(number_of_plies);
+    let show_ply = move |ply: usize| {
+        let ply = ply.min(number_of_plies());
+        viewed_ply.set((ply < number_of_plies()).then_some(ply));
+    };
+    let is_live = move || viewed_ply.get().is_none();
+
+    let keydown_handle = window_event_listener(ev::keydown, move |ev: KeyboardEvent| {
+        if is_typing(&ev) {
+            return;
+        }
+        let current_ply = viewed_ply.get_untracked().unwrap_or_else(|| moves.with_untracked(Vec::len));
+        match ev.key().as_str() {
+            "ArrowLeft" => show_ply(current_ply.saturating_sub(1)),
+            "ArrowRight" => show_ply(current_ply + 1),
+            "Home" => show_ply(0),
+            "End" => viewed_ply.set(None),
+            _ => return,
+        }
+        ev.prevent_default();
+    });
+    on_cleanup(move || keydown_handle.remove());
+
+    // Full moves: the first player's move and the answer, a game started by blue leaves the first half empty.
+    let rows = move || {
+        let offset = if first_player.get() == PlayerColor::Red { 0 } else { 1 };
+        let plies: Vec<(usize, String)> = moves.get().into_iter().enumerate().map(|(index, notation)| (index + 1, notation)).collect();
+        let mut rows: Vec<[Option<(usize, String)>; 2]> = Vec::new();
+        for (ply, notation) in plies {
+            let slot = (ply - 1 + offset) % 2;
+            if slot == 0 || rows.is_empty() {
+                rows.push([None, None]);
+            }
+            rows.last_mut().expect("A row was added.")[slot] = Some((ply, notation));
+        }
+        rows.into_iter().enumerate().map(|(index, row)| {
+            let cells = row.into_iter().map(|cell| match cell {
+                Some((ply, notation)) => view! {
+                    <td>
+                        <button
+                            class="btn btn-ghost btn-xs font-mono"
+                            class:btn-active=move || current_ply() == ply
+                            aria-current=move || (current_ply() == ply).then_some("step")
+                            on:click=move |_| show_ply(ply)
+                        >
+                            {notation}
+                            {move || judgements.with(|judgements| judgements.get(ply - 1).copied().flatten()).map(|judgement| view! {
+                                <span class=judgement_text_class(judgement)>{judgement.symbol()}</span>
+                            })}
+                        </button>
+                    </td>
+                }.into_view(),
+                None => view! { <td>"..."</td> }.into_view(),
+            }).collect_view();
+            view! {
+                <tr>
+                    <th>{index + 1}"."</th>
+                    {cells}
+                </tr>
+            }
+        }).collect_view()
+    };
+
+    view! {
+        <nav class="flex flex-col w-64" aria-label=move || Text::MoveHistory.translate(locale.get())>
+            <div class="flex justify-center items-center">
+                <button class="btn btn-xs m-1" aria-label=move || Text::FirstPosition.translate(locale.get()) disabled=move || current_ply() == 0 on:click=move |_| show_ply(0)>"|<"</button>
+                <button class="btn btn-xs m-1" aria-label=move || Text::PreviousMove.translate(locale.get()) disabled=move || current_ply() == 0 on:click=move |_| show_ply(current_ply().saturating_sub(1))>"<"</button>
+                <span class="m-1 text-xs">{move || format!("{} / {}", current_ply(), number_of_plies())}</span>
+                <button class="btn btn-xs m-1" aria-label=move || Text::NextMove.translate(locale.get()) disabled=is_live on:click=move |_| show_ply(current_ply() + 1)>">"</button>
+                <button class="btn btn-xs m-1" aria-label=move || Text::LivePosition.translate(locale.get()) disabled=is_live on:click=move |_| viewed_ply.set(None)>">|"</button>
+            </div>
+            <div class="overflow-y-auto max-h-96">
+                <table class="table table-xs">
+                    <tbody>{rows}</tbody>
+                </table>
+            </div>
+            <Show when=move || !is_live()>
+                <button class="btn btn-primary btn-xs m-1" on:click=move |_| viewed_ply.set(None)>
+                    {move || Text::BackToLive.translate(locale.get())}
+                </button>
+            </Show>
+        </nav>
+    }
+}
+
+fn is_typing(ev: &KeyboardEvent) -> bool {
+    ev.target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .is_some_and(|element| matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT"))
+}
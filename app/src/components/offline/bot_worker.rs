@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::bot_settings::BotSettings;
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub struct BotMoveRequest {
+    pub settings: BotSettings,
+    /// The same for every move of a game. The worker creates a new bot for other settings
+    ///  or another seed.
+    pub seed: u64,
+    /// The position to move in as FEN.
+    pub fen: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub struct BotMoveResponse {
+    /// The position of the request, so answers to outdated requests can be ignored.
+    pub fen: String,
+    /// The chosen move in numeric notation, `None` if the bot cannot move.
+    pub notation: Option<String>,
+}
+
+
+#[cfg(feature = "hydrate")]
+thread_local! {
+    static BOT: std::cell::RefCell<Option<((BotSettings, u64), crate::game::engine::Bot)>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Entry point of the bot web worker in `public/bot_worker.js`, which loads the same wasm
+///  module as the page. Takes and returns JSON, the bot is kept between calls, so the engine
+///  profits from its earlier searches.
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn choose_bot_move(request: &str) -> String {
+    use crate::game::board::Position;
+    use crate::game::engine::Bot;
+    use crate::game::notation::format_move;
+
+    let Ok(request) = serde_json::from_str::<BotMoveRequest>(request) else {
+        return String::new();
+    };
+    let notation = Position::from_fen(&request.fen).ok().and_then(|position| {
+        BOT.with_borrow_mut(|bot| {
+            let key = (request.settings, request.seed);
+            if bot.as_ref().map(|(bot_key, _)| bot_key) != Some(&key) {
+                *bot = Some((key, Bot::new(request.settings, request.seed)));
+            }
+            let (_, bot) = bot.as_mut().expect("The bot was created above.");
+            bot.choose_move(&position).map(|mv| format_move(&position, &mv))
+        })
+    });
+    serde_json::to_string(&BotMoveResponse { fen: request.fen, notation }).unwrap_or_default()
+}
@@ -0,0 +1,12 @@
+mod bot_worker;
+mod use_bot_worker;
+mod use_local_game;
+mod upload_server_function;
+
+pub use bot_worker::BotMoveRequest as BotMoveRequest;
+pub use bot_worker::BotMoveResponse as BotMoveResponse;
+pub use use_bot_worker::use_bot_worker as use_bot_worker;
+pub use use_local_game::use_local_game as use_local_game;
+pub use use_local_game::LocalGame as LocalGame;
+pub use use_local_game::UseLocalGameResult as UseLocalGameResult;
+pub use upload_server_function::upload_game as upload_game;
@@ -0,0 +1,87 @@
+use leptos::*;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::game::board::Position;
+use crate::game::bot_settings::BotSettings;
+use crate::game::game_result::GameResult;
+use crate::game::moves::Move;
+use crate::game::player_color::PlayerColor;
+
+/// Longer uploads are refused, no real game is that long.
+const MAX_UPLOADED_PLIES: usize = 500;
+const MAX_PLAYER_NAME_LENGTH: usize = 32;
+
+
+/// Stores a finished game played offline against the bot, so it can be shared like any other
+///  game. The moves are replayed and the result is checked against the final position: the
+///  player may have resigned or the bot may have accepted a draw, otherwise the side to move
+///  must have no moves left. So only legal and finished games are accepted.
+#[server(UploadGame, "/api")]
+pub async fn upload_game(
+    bot: BotSettings,
+    bot_color: PlayerColor,
+    player_name: String,
+    created_at: DateTime<Utc>,
+    initial_position: Position,
+    moves: Vec<Move>,
+    result: GameResult,
+) -> Result<Uuid, ServerFnError> {
+    use std::sync::Arc;
+    use leptos_axum::extract;
+    use axum::extract::Extension;
+    use crate::pages::game_page::GameState;
+    use crate::game::bot_settings::BOT_NAME;
+    use crate::game::engine::accepts_draw;
+    use crate::game::game_record::{GameRecord, RecordedMove};
+    use crate::game::game_result::{DrawReason, WinReason};
+    use crate::game::game_settings::GameSettings;
+    use crate::game::opening::Opening;
+
+    let game_state = extract::<Extension<Arc<GameState>>>().await
+        .expect("Cannot get the game-state extension.");
+    if moves.len() > MAX_UPLOADED_PLIES {
+        return Err(ServerFnError::ServerError("The game is too long.".to_string()));
+    }
+    initial_position.validate().map_err(|error| ServerFnError::ServerError(error.to_string()))?;
+    let mut position = initial_position;
+    for mv in &moves {
+        if !position.is_legal(mv) {
+            return Err(ServerFnError::ServerError("The game contains an illegal move.".to_string()));
+        }
+        position.play(mv);
+    }
+    let is_finished = if position.legal_moves().is_empty() {
+        result == GameResult::Win { winner: position.side_to_move.opponent(), reason: WinReason::NoMovesLeft }
+    }
+    else {
+        match result {
+            GameResult::Win { winner, reason: WinReason::Resignation } => winner == bot_color,
+            GameResult::Draw { reason: DrawReason::Agreement } => accepts_draw(&position, bot_color),
+            _ => false,
+        }
+    };
+    if !is_finished {
+        return Err(ServerFnError::ServerError("The game is not finished.".to_string()));
+    }
+
+    let opening = if initial_position == Position::initial() { Opening::Standard } else { Opening::Position(initial_position) };
+    let player_name: String = player_name.trim().chars().take(MAX_PLAYER_NAME_LENGTH).collect();
+    let mut player_names = vec![None, None];
+    player_names[bot_color.player_number()] = Some(BOT_NAME.to_string());
+    player_names[bot_color.opponent().player_number()] = Some(player_name).filter(|name| !name.is_empty());
+    // a new id, so an upload never replaces an existing game
+    let record = GameRecord {
+        game_id: Uuid::now_v7(),
+        settings: GameSettings { bot: Some(bot), opening, ..Default::default() },
+        created_at: created_at.min(Utc::now()),
+        player_names,
+        initial_position,
+        moves: moves.into_iter().map(RecordedMove::new).collect(),
+        result: Some(result),
+    };
+    logging::log!("Uploading offline game {}", record.game_id);
+    game_state.import_game(&record).await
+        .map_err(|error| ServerFnError::ServerError(error.to_string()))?;
+    Ok(record.game_id)
+}
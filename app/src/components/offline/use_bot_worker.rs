@@ -0,0 +1,26 @@
+use leptos::*;
+
+use super::bot_worker::{BotMoveRequest, BotMoveResponse};
+use crate::utils::use_worker::{use_worker, UseWorkerResult};
+
+const BOT_WORKER_URL: &str = "/bot_worker.js";
+
+
+pub struct UseBotWorkerResult<RequestMoveFn>
+where
+    RequestMoveFn: Fn(BotMoveRequest) + Clone + 'static,
+{
+    /// The answer to the latest request.
+    pub response: Signal<Option<BotMoveResponse>>,
+    pub request_move: RequestMoveFn,
+}
+
+
+/// Runs the bot in a web worker, so the search never blocks the page.
+pub fn use_bot_worker() -> UseBotWorkerResult<impl Fn(BotMoveRequest) + Clone + 'static> {
+    let UseWorkerResult { response, post, .. } = use_worker::<BotMoveRequest, BotMoveResponse>(BOT_WORKER_URL);
+    UseBotWorkerResult {
+        response,
+        request_move: post,
+    }
+}
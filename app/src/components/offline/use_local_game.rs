@@ -0,0 +1,97 @@
+use leptos::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game::board::Position;
+use crate::game::game_result::{GameResult, WinReason};
+use crate::game::moves::Move;
+use crate::game::notation::format_moves;
+use crate::game::player_color::PlayerColor;
+
+
+/// A game kept in the browser only, e.g. against the bot in the web worker or for two
+///  players on the same device.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub struct LocalGame {
+    pub initial_position: Position,
+    pub moves: Vec<Move>,
+    /// The end of the game before the board decided it, e.g. by resignation.
+    #[serde(default)]
+    pub result: Option<GameResult>,
+}
+
+impl LocalGame {
+    /// Whether every move is legal in its position. A stored game may have been written by
+    ///  an older version or edited by hand.
+    pub fn is_valid(&self) -> bool {
+        if self.initial_position.validate().is_err() {
+            return false;
+        }
+        let mut position = self.initial_position;
+        self.moves.iter().all(|mv| {
+            let is_legal = position.is_legal(mv);
+            position.play(mv);
+            is_legal
+        })
+    }
+}
+
+impl Default for LocalGame {
+    fn default() -> Self {
+        Self {
+            initial_position: Position::initial(),
+            moves: Vec::new(),
+            result: None,
+        }
+    }
+}
+
+
+pub struct UseLocalGameResult {
+    /// The positions after every ply, starting with the initial position.
+    pub positions: Memo<Vec<Position>>,
+    pub position: Memo<Position>,
+    pub result: Memo<Option<GameResult>>,
+    /// The moves in numeric notation, as shown in the move history.
+    pub notations: Signal<Vec<String>>,
+    pub last_move: Signal<Option<Move>>,
+    pub first_player: Signal<PlayerColor>,
+}
+
+
+/// Derives the positions and the result of a local game. The game ends when the side to
+///  move has no moves left or the players ended it, there is no clock.
+pub fn use_local_game(game: Signal<LocalGame>) -> UseLocalGameResult {
+    let positions = create_memo(move |_| {
+        game.with(|game| {
+            game.moves.iter().fold(vec![game.initial_position], |mut positions, mv| {
+                let position = positions.last().expect("There is at least the initial position.").after(mv);
+                positions.push(position);
+                positions
+            })
+        })
+    });
+    let position = create_memo(move |_| {
+        positions.with(|positions| *positions.last().expect("There is at least the initial position."))
+    });
+    let result = create_memo(move |_| {
+        let position = position.get();
+        game.with(|game| game.result).or_else(|| {
+            position.legal_moves().is_empty().then_some(GameResult::Win {
+                winner: position.side_to_move.opponent(),
+                reason: WinReason::NoMovesLeft,
+            })
+        })
+    });
+    let notations = Signal::derive(move || game.with(|game| format_moves(&game.initial_position, &game.moves)));
+    let last_move = Signal::derive(move || game.with(|game| game.moves.last().cloned()));
+    let first_player = Signal::derive(move || game.with(|game| game.initial_position.side_to_move));
+
+    UseLocalGameResult {
+        positions,
+        position,
+        result,
+        notations,
+        last_move,
+        first_player,
+    }
+}
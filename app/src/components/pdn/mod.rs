@@ -0,0 +1,4 @@
+mod pdn_server_function;
+mod pdn_import;
+
+pub use pdn_import::PdnImport as PdnImport;
@@ -0,0 +1,69 @@
+use leptos::*;
+
+use super::pdn_server_function::{import_pdn, ImportedGame};
+use crate::components::i18n::{use_locale, Text, Translate};
+
+
+#[component]
+pub fn PdnImport() -> impl IntoView {
+    let locale = use_locale();
+    let (pdn, set_pdn) = create_signal("".to_string());
+    let (imported_games, set_imported_games) = create_signal::<Vec<ImportedGame>>(Vec::new());
+    let (import_error, set_import_error) = create_signal::<Option<String>>(None);
+    let (import_pending, set_import_pending) = create_signal(false);
+
+    view! {
+        <div class="p-2 w-full flex flex-col items-center">
+            <textarea
+                class="textarea textarea-bordered w-full max-w-xl font-mono text-xs"
+                rows=6
+                placeholder=move || Text::PastePdn.translate(locale.get())
+                on:input=move |ev| {
+                    set_pdn.set(event_target_value(&ev))
+                }
+                prop:value=pdn
+            />
+            <button
+                class="btn btn-primary m-2"
+                disabled=move || import_pending.get() || pdn.get().trim().is_empty()
+                on:click=move |_| {
+                    set_import_pending.set(true);
+                    set_import_error.set(None);
+                    spawn_local(async move {
+                        match import_pdn(pdn.get_untracked()).await {
+                            Ok(games) => {
+                                set_imported_games.set(games);
+                            }
+                            Err(error) => {
+                                logging::error!("PDN import failed: {:?}", error);
+                                set_import_error.set(Some(error.translate(locale.get_untracked())));
+                            }
+                        }
+                        set_import_pending.set(false);
+                    });
+                }
+            >
+                {move || Text::ImportPdn.translate(locale.get())}
+            </button>
+            <Show when=move || import_error.get().is_some()>
+                <p class="content-error">{move || Text::Error(import_error.get().unwrap_or_default()).translate(locale.get())}</p>
+            </Show>
+            <Show when=move || !imported_games.with(Vec::is_empty)>
+                <p>{move || Text::ImportedGames(imported_games.with(Vec::len)).translate(locale.get())}</p>
+            </Show>
+            // the index keeps equal games apart, e.g. the same unfinished game pasted twice
+            <For
+                each=move || imported_games.get().into_iter().enumerate()
+                key=|entry| entry.clone()
+                children=move |(_, imported_game)| match imported_game {
+                    ImportedGame::Game(game_id) => view! {
+                        <a class="link" href=format!("/games/{}", game_id)>{game_id.to_string()}</a>
+                    },
+                    ImportedGame::Analysis(url) => view! {
+                        <a class="link" href=url>{move || Text::AnalysisBoard.translate(locale.get())}</a>
+                    },
+                }
+            />
+        </div>
+    }
+}
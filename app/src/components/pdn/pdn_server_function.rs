@@ -0,0 +1,71 @@
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::game::pdn::PdnError;
+
+/// Imported games are kept in memory and analysed, so the size of an import is limited.
+const MAX_PDN_LENGTH: usize = 1_000_000;
+const MAX_IMPORTED_GAMES: usize = 100;
+const MAX_IMPORTED_PLIES: usize = 500;
+
+
+/// Where an imported game is shown. A game without a result cannot be continued, as nobody
+///  has its seats, so it goes to the analysis board instead of becoming a game.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ImportedGame {
+    Game(Uuid),
+    /// The link to the analysis board with the game.
+    Analysis(String),
+}
+
+
+/// Imports all games of a PDN file, returns where each of them is shown. Either all games
+///  are imported or none of them.
+#[server(ImportPdn, "/api")]
+pub async fn import_pdn(
+    pdn: String,
+) -> Result<Vec<ImportedGame>, ServerFnError<PdnError>> {
+    use std::sync::Arc;
+    use leptos_axum::extract;
+    use axum::extract::Extension;
+    use crate::pages::game_page::GameState;
+    use crate::components::analysis_board::analysis_board_url;
+    use crate::game::pdn::parse_pdn;
+    use crate::game::variation_tree::VariationTree;
+
+    let game_state = extract::<Extension<Arc<GameState>>>().await
+        .expect("Cannot get the game-state extension.");
+    if pdn.len() > MAX_PDN_LENGTH {
+        return Err(ServerFnError::WrappedServerError(PdnError::TooLong { max_length: MAX_PDN_LENGTH }));
+    }
+    let games = parse_pdn(&pdn).map_err(ServerFnError::WrappedServerError)?;
+    if games.len() > MAX_IMPORTED_GAMES {
+        return Err(ServerFnError::WrappedServerError(PdnError::TooManyGames { max_games: MAX_IMPORTED_GAMES }));
+    }
+    if let Some(game) = games.iter().find(|game| game.moves.len() > MAX_IMPORTED_PLIES) {
+        return Err(ServerFnError::WrappedServerError(PdnError::TooManyPlies { line: game.line, max_plies: MAX_IMPORTED_PLIES }));
+    }
+
+    // every game is checked before the first one is stored
+    let mut imported_games = Vec::new();
+    let mut records = Vec::new();
+    for game in &games {
+        let record = game.to_record(Uuid::now_v7()).map_err(ServerFnError::WrappedServerError)?;
+        if record.result.is_none() {
+            let tree = VariationTree::from_pdn(game).map_err(ServerFnError::WrappedServerError)?;
+            let last_node = tree.node_at_path(&vec![0; record.moves.len()]).unwrap_or(VariationTree::ROOT);
+            imported_games.push(ImportedGame::Analysis(analysis_board_url(&tree, last_node)));
+        }
+        else {
+            imported_games.push(ImportedGame::Game(record.game_id));
+            records.push((game.line, record));
+        }
+    }
+
+    for (line, record) in records {
+        game_state.import_game(&record).await
+            .map_err(|error| ServerFnError::WrappedServerError(PdnError::InvalidSettings { line, error }))?;
+    }
+    Ok(imported_games)
+}
@@ -0,0 +1,90 @@
+use leptos::*;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::abandonment_server_function::claim_abandonment;
+use crate::components::game_settings::get_game_settings;
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::abandonment::AbandonmentClaim as Claim;
+use crate::game::player_color::PlayerColor;
+use crate::game::time_control::TimeControl;
+use crate::pages::game_page::Player;
+
+
+#[component]
+pub fn AbandonmentClaim(
+    #[prop(into)]
+    game_id: Signal<Uuid>,
+    #[prop(into)]
+    players: Signal<Vec<Player>>,
+    #[prop(into)]
+    player_number: RwSignal<Option<usize>>,
+    #[prop(into)]
+    player_secret: RwSignal<Option<String>>,
+    #[prop(into)]
+    now: Signal<DateTime<Utc>>,
+    #[prop(into)]
+    error_message: RwSignal<Option<String>>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let settings = create_resource(
+        move || game_id.get(),
+        |game_id| async move { get_game_settings(game_id).await.ok() },
+    );
+    let (claim_pending, set_claim_pending) = create_signal(false);
+    let is_opponent_disconnected = move || {
+        let Some(settings) = settings.get().flatten() else {
+            return false;
+        };
+        if let TimeControl::Correspondence { .. } = settings.time_control {
+            return false;
+        }
+        let Some(opponent) = player_number.get()
+            .and_then(PlayerColor::from_player_number)
+            .map(|player_color| player_color.opponent().player_number()) else {
+            return false;
+        };
+        players.with(|players| {
+            players.iter()
+                .find(|player| player.player_number == opponent && player.is_assigned && !player.is_bot)
+                .is_some_and(|player| settings.abandonment.is_disconnected(player.last_ping, now.get()))
+        })
+    };
+    let claim = move |claim: Claim| {
+        let (Some(player_number), Some(player_secret)) = (player_number.get_untracked(), player_secret.get_untracked()) else {
+            return;
+        };
+        set_claim_pending.set(true);
+        spawn_local(async move {
+            if let Err(error) = claim_abandonment(game_id.get_untracked(), player_number, player_secret, claim).await {
+                logging::error!("Abandonment claim failed: {:?}", error);
+                error_message.set(Some(error.translate(locale.get_untracked())));
+            }
+            set_claim_pending.set(false);
+        });
+    };
+
+    view! {
+        <Transition fallback=|| ()>
+            <Show when=is_opponent_disconnected>
+                <div class="p-2 w-full flex justify-center items-center">
+                    <p>{move || Text::OpponentLeft.translate(locale.get())}</p>
+                    <button
+                        class="btn btn-primary btn-xs ml-2"
+                        disabled=claim_pending
+                        on:click=move |_| claim(Claim::Win)
+                    >
+                        {move || Text::ClaimWin.translate(locale.get())}
+                    </button>
+                    <button
+                        class="btn btn-xs ml-2"
+                        disabled=claim_pending
+                        on:click=move |_| claim(Claim::Draw)
+                    >
+                        {move || Text::ClaimDraw.translate(locale.get())}
+                    </button>
+                </div>
+            </Show>
+        </Transition>
+    }
+}
@@ -0,0 +1,95 @@
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use core::fmt;
+use uuid::Uuid;
+use std::str::FromStr;
+
+use crate::game::abandonment::AbandonmentClaim;
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+pub enum AbandonmentClaimError {
+    UnknownError(String),
+    InvalidPlayerNumber,
+    InvalidPlayerSecret,
+    GameNotRunning,
+    OpponentStillConnected,
+    NotAllowedForCorrespondence,
+}
+
+impl fmt::Display for AbandonmentClaimError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AbandonmentClaimError::UnknownError(s) => write!(f, "{}", s),
+            AbandonmentClaimError::InvalidPlayerNumber => write!(f, "Invalid player number."),
+            AbandonmentClaimError::InvalidPlayerSecret => write!(f, "Invalid player secret."),
+            AbandonmentClaimError::GameNotRunning => write!(f, "Game is not running."),
+            AbandonmentClaimError::OpponentStillConnected => write!(f, "Opponent is still connected."),
+            AbandonmentClaimError::NotAllowedForCorrespondence => write!(f, "Abandonment cannot be claimed in correspondence games."),
+        }
+    }
+}
+
+impl FromStr for AbandonmentClaimError {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(AbandonmentClaimError::UnknownError(s.to_string()))
+    }
+}
+
+#[server(ClaimAbandonment, "/api")]
+pub async fn claim_abandonment(
+    game_id: Uuid,
+    player_number: usize,
+    player_secret: String,
+    claim: AbandonmentClaim,
+) -> Result<(), ServerFnError<AbandonmentClaimError>> {
+    use std::sync::Arc;
+    use chrono::Utc;
+    use leptos_axum::extract;
+    use axum::extract::Extension;
+    use crate::pages::game_page::GameState;
+    use crate::game::player_color::PlayerColor;
+    use crate::game::time_control::TimeControl;
+    use crate::game::game_result::{GameResult, WinReason, DrawReason};
+
+    let game_state = extract::<Extension<Arc<GameState>>>().await
+        .expect("Cannot get the game-state extension.");
+    let game = game_state.get_game(game_id).await
+        .ok_or_else(|| ServerFnError::ServerError(format!("Game {} not found.", game_id)))?;
+    // the seats are read under the lock, so none is released between the check and the result
+    let _lock = game.lock().await;
+    let settings = game.settings().await;
+    let players = game.players().await;
+    let now = Utc::now();
+
+    let claimant = PlayerColor::from_player_number(player_number)
+        .ok_or(ServerFnError::WrappedServerError(AbandonmentClaimError::InvalidPlayerNumber))?;
+    if !players[claimant.player_number()].has_secret(&player_secret) {
+        return Err(ServerFnError::WrappedServerError(AbandonmentClaimError::InvalidPlayerSecret));
+    }
+    // In correspondence games a player is expected to be offline, the deadline decides instead.
+    if let TimeControl::Correspondence { .. } = settings.time_control {
+        return Err(ServerFnError::WrappedServerError(AbandonmentClaimError::NotAllowedForCorrespondence));
+    }
+    let clock = game.clock().await;
+    if game.result().await.is_some() || !clock.is_started || clock.is_paused {
+        return Err(ServerFnError::WrappedServerError(AbandonmentClaimError::GameNotRunning));
+    }
+    let opponent = &players[claimant.opponent().player_number()];
+    if opponent.public_data.is_bot || !settings.abandonment.is_disconnected(opponent.public_data.last_ping, now) {
+        return Err(ServerFnError::WrappedServerError(AbandonmentClaimError::OpponentStillConnected));
+    }
+
+    logging::log!("Player {} claims {:?} by abandonment in game {}", claimant, claim, game_id);
+    game.with_clock(|mut clock| {
+        clock.stop();
+        clock
+    }).await;
+    game.set_result(Some(match claim {
+        AbandonmentClaim::Win => GameResult::Win { winner: claimant, reason: WinReason::Abandonment },
+        AbandonmentClaim::Draw => GameResult::Draw { reason: DrawReason::Abandonment },
+    })).await;
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use leptos::*;
+use uuid::Uuid;
+
+use super::player_assignment_server_function::{assign_bot_to_game, assign_engine_to_game, is_external_engine_available};
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::player_color::PlayerColor;
+use crate::pages::game_page::Player;
+
+
+/// Offers a seated player to play against the computer while the other seat is free, or
+///  against the external engine, if the server has one.
+#[component]
+pub fn BotAssignment(
+    #[prop(into)]
+    game_id: Signal<Uuid>,
+    #[prop(into)]
+    players: Signal<Vec<Player>>,
+    #[prop(into)]
+    player_number: RwSignal<Option<usize>>,
+    #[prop(into)]
+    player_secret: RwSignal<Option<String>>,
+    #[prop(into)]
+    error_message: RwSignal<Option<String>>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let (assignment_pending, set_assignment_pending) = create_signal(false);
+    let is_opponent_seat_free = move || {
+        let Some(opponent) = player_number.get()
+            .and_then(PlayerColor::from_player_number)
+            .map(|player_color| player_color.opponent().player_number()) else {
+            return false;
+        };
+        players.with(|players| {
+            players.iter().any(|player| player.player_number == opponent && !player.is_assigned)
+        })
+    };
+    let is_engine_available = create_resource(|| (), |_| is_external_engine_available());
+    let assign_computer = move |use_engine: bool| {
+        let (Some(player_number), Some(player_secret)) = (player_number.get_untracked(), player_secret.get_untracked()) else {
+            return;
+        };
+        set_assignment_pending.set(true);
+        spawn_local(async move {
+            let assignment = if use_engine {
+                assign_engine_to_game(game_id.get_untracked(), player_number, player_secret).await
+            }
+            else {
+                assign_bot_to_game(game_id.get_untracked(), player_number, player_secret).await
+            };
+            if let Err(error) = assignment {
+                logging::error!("Bot assignment failed: {:?}", error);
+                let reason = error.translate(locale.get_untracked());
+                error_message.set(Some(Text::PlayerAssignmentFailed(reason).translate(locale.get_untracked())));
+            }
+            set_assignment_pending.set(false);
+        });
+    };
+
+    view! {
+        <Show when=is_opponent_seat_free>
+            <div class="p-2 w-full flex justify-center">
+                <button
+                    class="btn btn-neutral btn-xs"
+                    disabled=assignment_pending
+                    on:click=move |_| assign_computer(false)
+                >
+                    {move || Text::PlayAgainstComputer.translate(locale.get())}
+                </button>
+                <Show when=move || is_engine_available.get().is_some_and(|available| available.unwrap_or(false))>
+                    <button
+                        class="btn btn-neutral btn-xs ml-2"
+                        disabled=assignment_pending
+                        on:click=move |_| assign_computer(true)
+                    >
+                        {move || Text::PlayAgainstEngine.translate(locale.get())}
+                    </button>
+                </Show>
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use leptos::*;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+use uuid::Uuid;
+
+use crate::components::hub::HubEngine;
+use crate::pages::game_page::{Game, GameState};
+use crate::game::engine::Bot;
+use crate::game::hub::{HubClock, HubLevel};
+use crate::game::moves::Move;
+use crate::game::notation::format_move;
+use crate::game::opening_book::OpeningBook;
+use crate::game::tablebase::Tablebase;
+
+const BOT_SCHEDULER_INTERVAL_IN_MS: u64 = 200;
+
+
+/// Background task, which lets the computer move in every game where it is the bot's turn.
+///  The search runs on a blocking worker, so neither this loop nor the players websockets
+///  wait for it. Each game keeps its bot, so the engine profits from earlier searches. All
+///  bots share the opening book and the endgame tablebase, if they are loaded. With a Hub
+///  engine the bots search with it, one game after the other, and only fall back to their
+///  own search if it fails. Bots of finished, adjourned or removed games are dropped.
+pub async fn run_bot_scheduler(
+    game_state: Arc<GameState>,
+    opening_book: Option<Arc<OpeningBook>>,
+    tablebase: Option<Arc<Tablebase>>,
+    hub_engine: Option<Arc<HubEngine>>,
+) {
+    let mut bots: HashMap<Uuid, Arc<Mutex<Bot>>> = HashMap::new();
+    let mut interval = tokio::time::interval(Duration::from_millis(BOT_SCHEDULER_INTERVAL_IN_MS));
+    loop {
+        interval.tick().await;
+        let mut game_ids = HashSet::new();
+        for game in game_state.games().await {
+            let game_id = game.id().await;
+            // the settings name a bot as soon as one is seated, other games are skipped cheaply
+            let Some(bot_settings) = game.settings().await.bot else {
+                continue;
+            };
+            if game.result().await.is_some() || game.adjournment().await.is_adjourned {
+                continue;
+            }
+            game_ids.insert(game_id);
+            let Some(bot_number) = bot_to_move(&game).await else {
+                continue;
+            };
+            if !bots.contains_key(&game_id) {
+                let mut bot = Bot::new(bot_settings, rand::random());
+                bot.set_opening_book(opening_book.clone());
+                bot.set_tablebase(tablebase.clone());
+                bots.insert(game_id, Arc::new(Mutex::new(bot)));
+            }
+            // A locked bot is still thinking about this move.
+            let Ok(bot) = bots[&game_id].clone().try_lock_owned() else {
+                continue;
+            };
+            tokio::spawn(play_bot_move(game, bot_number, bot, hub_engine.clone()));
+        }
+        bots.retain(|game_id, _| game_ids.contains(game_id));
+    }
+}
+
+/// The seat of the bot, if the game is running and it is the bot's turn.
+async fn bot_to_move(game: &Game) -> Option<usize> {
+    let clock = game.clock().await;
+    if !clock.is_started || clock.is_paused {
+        return None;
+    }
+    let player_number = game.position().await.side_to_move.player_number();
+    game.players().await.get(player_number)
+        .filter(|player| player.public_data.is_bot && !player.public_data.is_engine)
+        .map(|_| player_number)
+}
+
+async fn play_bot_move(game: Game, bot_number: usize, mut bot: OwnedMutexGuard<Bot>, hub_engine: Option<Arc<HubEngine>>) {
+    let game_id = game.id().await;
+    let position = game.position().await;
+    let mut engine_move = None;
+    if let Some(hub_engine) = &hub_engine {
+        engine_move = match bot.book_move(&position) {
+            Some(book_move) => Some(book_move),
+            None => search_hub_move(&game, hub_engine, bot_number).await,
+        };
+    }
+    let (bot, mv) = match engine_move {
+        Some(mv) => (bot, Some(mv)),
+        None => {
+            let search = tokio::task::spawn_blocking(move || {
+                let mv = bot.choose_move(&position);
+                (bot, mv)
+            }).await;
+            match search {
+                Ok(search) => search,
+                Err(error) => {
+                    logging::error!("Bot search failed in game {}: {:?}", game_id, error);
+                    return;
+                }
+            }
+        }
+    };
+    let Some(mv) = mv else {
+        return;
+    };
+
+    let notation = format_move(&position, &mv);
+    logging::log!("Bot plays {} in game {}", notation, game_id);
+    if let Err(error) = game.play_move(bot_number, &notation).await {
+        logging::error!("Bot move rejected in game {}: {}", game_id, error);
+    }
+    // The bot stays locked until the move is on the board, so the scheduler does not
+    //  start thinking about the same position again.
+    drop(bot);
+}
+
+/// The move of the Hub engine, limited like the bot's own search on the level of the game.
+///  Unlike the bot it makes no deliberate mistakes. With a clock the engine gets the time
+///  control of the game and plans its time itself.
+async fn search_hub_move(game: &Game, hub_engine: &HubEngine, bot_number: usize) -> Option<Move> {
+    let game_id = game.id().await;
+    let position = game.position().await;
+    let limits = game.settings().await.bot.unwrap_or_default().level.search_limits();
+    let clock = game.clock().await;
+    let time_per_move = |player| clock.time_per_move_of(player).and_then(|time_per_move| time_per_move.to_std().ok());
+    let level = HubLevel {
+        depth: Some(limits.max_depth),
+        nodes: Some(limits.max_nodes),
+        move_time: None,
+        moves: time_per_move(position.side_to_move).map(|_| 1),
+        time: time_per_move(position.side_to_move),
+    };
+    let hub_clock = clock.remaining_time(Utc::now())
+        .and_then(|remaining_time| remaining_time.to_std().ok())
+        .map(|my_time| HubClock {
+            my_time,
+            opponent_time: time_per_move(position.side_to_move.opponent()).unwrap_or_default(),
+        });
+    match hub_engine.search(game_id, &position, level, hub_clock).await {
+        Ok(result) => result.best_move,
+        Err(error) => {
+            logging::warn!("Hub engine failed for seat {} in game {}, the bot searches itself: {}", bot_number, game_id, error);
+            None
+        }
+    }
+}
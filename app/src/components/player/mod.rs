@@ -1,6 +1,24 @@
 mod use_player_assingment;
+mod use_players_socket;
 mod player_information;
 mod player_assignment_server_function;
 mod player_assignment;
+mod abandonment_server_function;
+mod abandonment_claim;
+mod bot_assignment;
+#[cfg(feature = "ssr")]
+mod seat_scheduler;
+#[cfg(feature = "ssr")]
+mod bot_scheduler;
 
-pub use player_information::PlayerInformation as PlayerInformation;
\ No newline at end of file
+pub use player_information::PlayerInformation as PlayerInformation;
+pub use use_players_socket::use_players_socket as use_players_socket;
+pub use use_player_assingment::use_player_seat as use_player_seat;
+pub use use_player_assingment::seat_navigation_state as seat_navigation_state;
+pub use player_assignment::PLAYER_NAME_COOKIE_NAME as PLAYER_NAME_COOKIE_NAME;
+#[cfg(feature = "ssr")]
+pub use seat_scheduler::run_seat_scheduler as run_seat_scheduler;
+#[cfg(feature = "ssr")]
+pub use bot_scheduler::run_bot_scheduler as run_bot_scheduler;
+pub use player_assignment_server_function::PlayerAssingmentError as PlayerAssingmentError;
+pub use abandonment_server_function::AbandonmentClaimError as AbandonmentClaimError;
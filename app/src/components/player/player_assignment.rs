@@ -3,9 +3,11 @@ use leptos_use::{UseCookieOptions, SameSite};
 use codee::string::FromToStringCodec;
 
 use super::use_player_assingment::UsePlayerAssingmentResult;
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::player_color::PlayerColor;
 use crate::utils::use_cookie_signal::{use_cookie_signal, UseCookieSignalResult};
 
-const PLAYER_NAME_COOKIE_NAME: &str = "player_name";
+pub const PLAYER_NAME_COOKIE_NAME: &str = "player_name";
 
 
 #[component]
@@ -18,12 +20,13 @@ pub fn PlayerAssignment(
         impl Fn() + Clone + 'static,
     >,
 ) -> impl IntoView {
+    let locale = use_locale();
     let UseCookieSignalResult {
         signal_reader: player_name,
         signal_writer: set_player_name,
         store_value: store_player_name,
     } = use_cookie_signal::<String, FromToStringCodec>(
-        "Player".to_string(), 
+        Text::DefaultPlayerName.translate(locale.get_untracked()),
         PLAYER_NAME_COOKIE_NAME, 
         UseCookieOptions::default()
             .max_age::<i64>(Some(1000*60*60*24*365)) // 1 year
@@ -36,8 +39,9 @@ pub fn PlayerAssignment(
     view! {
         <Show when=move || player_number.get().is_none()>
             <div class="p-2 w-full flex justify-center">
-                <p>"Join game as "</p>
+                <label for="player-name">{move || Text::JoinGameAs.translate(locale.get())}</label>
                 <input
+                    id="player-name"
                     disabled={move || !player_assignment_possible()}
                     type="text"
                     class="input input-bordered max-w-xs input-xs ml-2"
@@ -58,7 +62,7 @@ pub fn PlayerAssignment(
                         }
                     }
                 >
-                    "Red Player"
+                    {move || Text::JoinAs(PlayerColor::Red).translate(locale.get())}
                 </button>
                 <button
                     disabled={move || !player_assignment_possible()}
@@ -72,10 +76,10 @@ pub fn PlayerAssignment(
                     }
                     class="btn bg-blue-800 hover:bg-blue-700 text-content btn-xs ml-2"
                 >
-                    "Blue Player"
+                    {move || Text::JoinAs(PlayerColor::Blue).translate(locale.get())}
                 </button>
                 <Show when=player_assignment.player_assignment_pending>
-                    <span class="loading loading-spinner text-primary"></span>
+                    <span class="loading loading-spinner text-primary" role="status" aria-label=move || Text::JoiningGame.translate(locale.get())></span>
                 </Show>
             </div>
         </Show>
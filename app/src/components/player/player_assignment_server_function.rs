@@ -4,7 +4,10 @@ use serde::{Deserialize, Serialize};
 use core::fmt;
 use uuid::Uuid;
 use std::str::FromStr;
-use std::time::Duration;
+
+/// The name of the external engine seat until the engine introduced itself.
+#[cfg(feature = "ssr")]
+const EXTERNAL_ENGINE_NAME: &str = "External engine";
 
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
@@ -13,6 +16,7 @@ pub enum PlayerAssingmentError {
     InvalidPlayerNumber,
     PlayerAllreadyAssigned,
     InvalidPlayerSecret,
+    NoEngineAvailable,
 }
 
 impl fmt::Display for PlayerAssingmentError {
@@ -22,6 +26,7 @@ impl fmt::Display for PlayerAssingmentError {
             PlayerAssingmentError::InvalidPlayerNumber => write!(f, "Invalid player number."),
             PlayerAssingmentError::PlayerAllreadyAssigned => write!(f, "Player allready assigned."),
             PlayerAssingmentError::InvalidPlayerSecret => write!(f, "Invalid player secret."),
+            PlayerAssingmentError::NoEngineAvailable => write!(f, "No external engine is available."),
         }
     }
 }
@@ -46,12 +51,30 @@ pub async fn assign_player_to_game(
     name: String,
     player_number: usize,    
 ) -> Result<PlayerAssignmentResult, ServerFnError<PlayerAssingmentError>> {
+    use chrono::Utc;
+
     logging::log!("Assigning player to game: {} {} {}", game_id, name, player_number);
-    tokio::time::sleep(Duration::from_secs(1)).await;
-    Ok(PlayerAssignmentResult {
-        player_number,
-        player_secret: "foobar".to_string(),
-    })
+    let game = get_game(game_id).await?;
+    let mut result = Err(PlayerAssingmentError::InvalidPlayerNumber);
+    game.with_player(|mut players| {
+        result = match players.players.get_mut(player_number) {
+            None => Err(PlayerAssingmentError::InvalidPlayerNumber),
+            Some(player) if player.public_data.is_assigned => Err(PlayerAssingmentError::PlayerAllreadyAssigned),
+            Some(player) => {
+                let player_secret = Uuid::new_v4().to_string(); // todo: generate a better secret
+                player.public_data.name = Some(name.clone());
+                player.public_data.is_assigned = true;
+                player.public_data.last_ping = Some(Utc::now());
+                player.secret = Some(player_secret.clone());
+                Ok(PlayerAssignmentResult {
+                    player_number,
+                    player_secret,
+                })
+            }
+        };
+        players
+    }).await;
+    result.map_err(ServerFnError::WrappedServerError)
 }
 
 #[server(ReassignPlayerToGame, "/api")]
@@ -60,12 +83,80 @@ pub async fn reassign_player_to_game(
     player_number: usize,    
     player_secret: String,
 ) -> Result<PlayerAssignmentResult, ServerFnError<PlayerAssingmentError>> {
-    logging::log!("Reassigning player to game: {} {} {}", game_id, player_number, player_secret);
-    tokio::time::sleep(Duration::from_secs(1)).await;
-    Ok(PlayerAssignmentResult {
-        player_number,
-        player_secret: "foobar".to_string(),
-    })
+    use chrono::Utc;
+    use crate::components::adjournment::resume_adjourned_game;
+    use crate::game::player_color::PlayerColor;
+
+    logging::log!("Reassigning player to game: {} {}", game_id, player_number);
+    let game = get_game(game_id).await?;
+    let mut result = Err(PlayerAssingmentError::InvalidPlayerNumber);
+    game.with_player(|mut players| {
+        result = match players.players.get_mut(player_number) {
+            None => Err(PlayerAssingmentError::InvalidPlayerNumber),
+            Some(player) if !player.has_secret(&player_secret) => Err(PlayerAssingmentError::InvalidPlayerSecret),
+            Some(player) => {
+                player.public_data.last_ping = Some(Utc::now());
+                Ok(PlayerAssignmentResult {
+                    player_number,
+                    player_secret: player_secret.clone(),
+                })
+            }
+        };
+        players
+    }).await;
+    if let (Ok(_), Some(player)) = (&result, PlayerColor::from_player_number(player_number)) {
+        resume_adjourned_game(&game, player).await;
+    }
+    result.map_err(ServerFnError::WrappedServerError)
+}
+
+/// Seats the computer opposite the requesting player, who has to be seated already.
+#[server(AssignBotToGame, "/api")]
+pub async fn assign_bot_to_game(
+    game_id: Uuid,
+    player_number: usize,
+    player_secret: String,
+) -> Result<(), ServerFnError<PlayerAssingmentError>> {
+    use crate::pages::game_page::PlayerInfo;
+    use crate::game::bot_settings::BOT_NAME;
+
+    logging::log!("Assigning bot to game: {} opposite {}", game_id, player_number);
+    let game = get_game(game_id).await?;
+    seat_opponent(&game, player_number, &player_secret, |bot_number| PlayerInfo::bot(bot_number, BOT_NAME)).await
+        .map_err(ServerFnError::WrappedServerError)?;
+    // The bot plays on the default level, unless one was chosen at creation.
+    game.with_settings(|mut settings| {
+        settings.bot = Some(settings.bot.unwrap_or_default());
+        settings
+    }).await;
+    Ok(())
+}
+
+/// Whether an external engine is configured, which can be played like the bot.
+#[server(IsExternalEngineAvailable, "/api")]
+pub async fn is_external_engine_available() -> Result<bool, ServerFnError> {
+    Ok(crate::components::dxp::engine_address().is_some())
+}
+
+/// Seats the external engine opposite the requesting player, who has to be seated already.
+///  The engine is connected in the background and the seat is free again, if it refuses.
+#[server(AssignEngineToGame, "/api")]
+pub async fn assign_engine_to_game(
+    game_id: Uuid,
+    player_number: usize,
+    player_secret: String,
+) -> Result<(), ServerFnError<PlayerAssingmentError>> {
+    use crate::pages::game_page::PlayerInfo;
+    use crate::components::dxp::{engine_address, play_engine_in_game};
+
+    logging::log!("Assigning external engine to game: {} opposite {}", game_id, player_number);
+    let address = engine_address().ok_or(ServerFnError::WrappedServerError(PlayerAssingmentError::NoEngineAvailable))?;
+    let game = get_game(game_id).await?;
+    let engine_number = seat_opponent(&game, player_number, &player_secret, |engine_number| {
+        PlayerInfo::engine(engine_number, EXTERNAL_ENGINE_NAME)
+    }).await.map_err(ServerFnError::WrappedServerError)?;
+    tokio::spawn(play_engine_in_game(game, engine_number, address));
+    Ok(())
 }
 
 #[server(UnassignPlayerFromGame, "/api")]
@@ -74,7 +165,66 @@ pub async fn unassign_player_from_game(
     player_number: usize,    
     player_secret: String,
 ) -> Result<(), ServerFnError<PlayerAssingmentError>> {
-    logging::log!("Unassigning player from game: {} {} {}", game_id, player_number, player_secret);
-    tokio::time::sleep(Duration::from_secs(1)).await;
-    Ok(())
+    use crate::pages::game_page::PlayerInfo;
+
+    logging::log!("Unassigning player from game: {} {}", game_id, player_number);
+    let game = get_game(game_id).await?;
+    let mut result = Err(PlayerAssingmentError::InvalidPlayerNumber);
+    game.with_player(|mut players| {
+        result = match players.players.get_mut(player_number) {
+            None => Err(PlayerAssingmentError::InvalidPlayerNumber),
+            Some(player) if !player.has_secret(&player_secret) => Err(PlayerAssingmentError::InvalidPlayerSecret),
+            Some(player) => {
+                *player = PlayerInfo::new(player_number);
+                Ok(())
+            }
+        };
+        players
+    }).await;
+    result.map_err(ServerFnError::WrappedServerError)
+}
+
+/// Gives the seat opposite the requesting player to the computer and returns its number.
+#[cfg(feature = "ssr")]
+async fn seat_opponent(
+    game: &crate::pages::game_page::Game,
+    player_number: usize,
+    player_secret: &str,
+    computer: impl FnOnce(usize) -> crate::pages::game_page::PlayerInfo,
+) -> Result<usize, PlayerAssingmentError> {
+    use crate::game::player_color::PlayerColor;
+
+    let opponent_number = PlayerColor::from_player_number(player_number)
+        .ok_or(PlayerAssingmentError::InvalidPlayerNumber)?
+        .opponent()
+        .player_number();
+    let mut result = Err(PlayerAssingmentError::InvalidPlayerNumber);
+    game.with_player(|mut players| {
+        let is_requester_seated = players.players.get(player_number)
+            .is_some_and(|player| player.has_secret(player_secret));
+        result = match players.players.get_mut(opponent_number) {
+            None => Err(PlayerAssingmentError::InvalidPlayerNumber),
+            Some(_) if !is_requester_seated => Err(PlayerAssingmentError::InvalidPlayerSecret),
+            Some(opponent) if opponent.public_data.is_assigned => Err(PlayerAssingmentError::PlayerAllreadyAssigned),
+            Some(opponent) => {
+                *opponent = computer(opponent_number);
+                Ok(opponent_number)
+            }
+        };
+        players
+    }).await;
+    result
+}
+
+#[cfg(feature = "ssr")]
+async fn get_game<E>(game_id: Uuid) -> Result<crate::pages::game_page::Game, ServerFnError<E>> {
+    use std::sync::Arc;
+    use leptos_axum::extract;
+    use axum::extract::Extension;
+    use crate::pages::game_page::GameState;
+
+    let game_state = extract::<Extension<Arc<GameState>>>().await
+        .expect("Cannot get the game-state extension.");
+    game_state.get_game(game_id).await
+        .ok_or_else(|| ServerFnError::ServerError(format!("Game {} not found.", game_id)))
 }
@@ -1,8 +1,14 @@
 use leptos::*;
+use leptos_use::{use_interval_fn, utils::Pausable};
+use chrono::Utc;
 use uuid::Uuid;
 
 use super::use_player_assingment::use_player_assingment;
 use super::player_assignment::PlayerAssignment;
+use super::abandonment_claim::AbandonmentClaim;
+use super::bot_assignment::BotAssignment;
+use crate::components::adjournment::AdjournmentControl;
+use crate::pages::game_page::{Player, PlayerInfo};
 
 
 #[component]
@@ -12,17 +18,65 @@ pub fn PlayerInformation(
     #[prop(into)]
     player_number: RwSignal<Option<usize>>,
     #[prop(into)]
+    player_secret: RwSignal<Option<String>>,
+    #[prop(into)]
+    players: Signal<Vec<Player>>,
+    #[prop(into)]
     error_message: RwSignal<Option<String>>,
 ) -> impl IntoView {
-    let player_secret = create_rw_signal::<Option<String>>(None);
     let player_assignment = use_player_assingment(
         game_id.clone(),
         player_number.clone(),
         player_secret.clone(),
         error_message.clone(),
     );
+    let reassign_player = player_assignment.reassign_player.clone();
+    let (now, set_now) = create_signal(Utc::now());
+    let Pausable { .. } = use_interval_fn(
+        move || {
+            set_now.set(Utc::now());
+        },
+        1000,
+    );
 
     view! {
+        <div class="overflow-x-auto">
+            <table class="table">
+                <tbody>
+                    <For
+                        each=move || players.get()
+                        key=|player| player.clone()
+                        let:player
+                    >
+                        <Show when=move || player.is_assigned>
+                            <PlayerInfo player=player.clone() now=Signal::derive(now) />
+                        </Show>
+                    </For>
+                </tbody>
+            </table>
+        </div>
+        <AbandonmentClaim
+            game_id=game_id
+            players=players
+            player_number=player_number
+            player_secret=player_secret
+            now=Signal::derive(now)
+            error_message=error_message
+        />
+        <BotAssignment
+            game_id=game_id
+            players=players
+            player_number=player_number
+            player_secret=player_secret
+            error_message=error_message
+        />
+        <AdjournmentControl
+            game_id=game_id
+            player_number=player_number
+            player_secret=player_secret
+            error_message=error_message
+            reassign_player=reassign_player
+        />
         <PlayerAssignment
             player_number=player_number
             player_assignment=player_assignment
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use std::time::Duration;
+use leptos::*;
+use chrono::{DateTime, Utc};
+
+use crate::pages::game_page::{Game, GameState, PlayerInfo};
+use crate::game::time_control::TimeControl;
+
+const SEAT_SCHEDULER_INTERVAL_IN_SEC: u64 = 60;
+
+
+/// Background task, which releases seats of players who did not ping for a long time,
+///  so that another person can take over the seat.
+pub async fn run_seat_scheduler(game_state: Arc<GameState>) {
+    let mut interval = tokio::time::interval(Duration::from_secs(SEAT_SCHEDULER_INTERVAL_IN_SEC));
+    loop {
+        interval.tick().await;
+        let now = Utc::now();
+        for game in game_state.games().await {
+            release_abandoned_seats(&game, now).await;
+        }
+    }
+}
+
+async fn release_abandoned_seats(game: &Game, now: DateTime<Utc>) {
+    // Seats of adjourned games are kept, because the players need them to resume the game.
+    if game.result().await.is_some() || game.adjournment().await.is_adjourned {
+        return;
+    }
+
+    // Players of correspondence games are expected to be offline, the deadline decides instead.
+    let settings = game.settings().await;
+    if let TimeControl::Correspondence { .. } = settings.time_control {
+        return;
+    }
+
+    let game_id = game.id().await;
+    let abandonment_rules = settings.abandonment;
+    let _lock = game.lock().await;
+    game.with_player(|mut players| {
+        for player in players.players.iter_mut() {
+            if player.public_data.is_assigned && !player.public_data.is_bot && abandonment_rules.is_seat_abandoned(player.public_data.last_ping, now) {
+                logging::log!("Releasing abandoned seat {} in game {}", player.public_data.player_number, game_id);
+                *player = PlayerInfo::new(player.public_data.player_number);
+            }
+        }
+        players
+    }).await;
+}
@@ -1,15 +1,19 @@
 use leptos::*;
-use leptos_router::use_location;
+use leptos_router::{use_location, State};
 use leptos_use::{use_cookie_with_options, UseCookieOptions, SameSite, UseTimeoutFnReturn, use_timeout_fn};
 use serde::{Deserialize, Serialize};
 use codee::string::JsonSerdeCodec;
 use uuid::Uuid;
+use wasm_bindgen::JsValue;
 
 use super::player_assignment_server_function::{assign_player_to_game, reassign_player_to_game, unassign_player_from_game};
+use crate::components::i18n::{use_locale, Text, Translate};
 
 
 const PLAYER_ASSIGNMENT_COOKIE_NAME: &str = "player_assignment";
-const PLAYER_ASSIGNMENT_COOKIE_LIFETIME_IN_SEC: i64 = 60*60*24*14; // 14 days
+// The cookie is written again on every (re-)assignment and every load of the game page,
+//  so it outlives even a long correspondence game as long as the player visits the game once a year.
+const PLAYER_ASSIGNMENT_COOKIE_LIFETIME_IN_SEC: i64 = 60*60*24*365; // 1 year
 const PLAYER_ASSINGMENT_TIMEOUT_IN_SEC: f64 = 10.0;
 
 
@@ -37,6 +41,7 @@ pub fn use_player_assingment(
     impl Fn() + Clone + 'static,
 > 
 {
+    let locale = use_locale();
     let (player_assingment_cookie, set_player_assignment_cookie) = use_player_assignment_cookie();
     let UseTimeoutFnReturn { 
         start: start_assignment_timeout, 
@@ -71,7 +76,8 @@ pub fn use_player_assingment(
                 }
                 Err(error) => {
                     logging::error!("Player assignment failed: {:?}", error);
-                    assignment_error.set(Some("Player assignment failed.".to_string()));
+                    let reason = error.translate(locale.get_untracked());
+                    assignment_error.set(Some(Text::PlayerAssignmentFailed(reason).translate(locale.get_untracked())));
                     player_secret.set(None);
                     player_number.set(None);
                     set_player_assignment_cookie.set(None);
@@ -103,7 +109,8 @@ pub fn use_player_assingment(
                 }
                 Err(error) => {
                     logging::error!("Player reassignment failed: {:?}", error);
-                    assignment_error.set(Some("Player reassignment failed.".to_string()));
+                    let reason = error.translate(locale.get_untracked());
+                    assignment_error.set(Some(Text::PlayerReassignmentFailed(reason).translate(locale.get_untracked())));
                     player_secret.set(None);
                     player_number.set(None);
                     set_player_assignment_cookie.set(None);
@@ -138,6 +145,16 @@ pub fn use_player_assingment(
         });
     };
 
+    // Refresh the lifetime of the cookie whenever the game page is loaded, without a secret
+    //  the reassignment below writes the cookie anew.
+    create_effect(move |_| {
+        if let Some(player_assingment_data) = player_assingment_cookie.get_untracked() {
+            if player_secret.get_untracked().is_some() {
+                set_player_assignment_cookie.set(Some(player_assingment_data));
+            }
+        }
+    });
+
     // Automatically reassign a player in case the cookie is set but the secret not
     //  this only happens when there is a browser reload.
     let inner_reassign_player_for_auto_reassign = inner_reassign_player.clone();
@@ -149,6 +166,20 @@ pub fn use_player_assingment(
         }
     });
 
+    // A seat handed over in the navigation state, e.g. the swapped seat of a return game,
+    //  is taken like one from the cookie of this page.
+    let location = use_location();
+    let inner_reassign_player_for_handed_over_seat = inner_reassign_player.clone();
+    create_effect(move |_| {
+        let handed_over_seat = location.state.get().to_js_value().as_string()
+            .and_then(|data| serde_json::from_str::<PlayerAssignmentData>(&data).ok());
+        if let Some(handed_over_seat) = handed_over_seat {
+            if player_assingment_cookie.get_untracked().is_none() && player_secret.get_untracked().is_none() {
+                inner_reassign_player_for_handed_over_seat(handed_over_seat);
+            }
+        }
+    });
+
     let reassign_player = move || { 
         if let Some(player_assingment_data) = player_assingment_cookie.get() {
             inner_reassign_player(player_assingment_data);
@@ -183,6 +214,20 @@ struct PlayerAssignmentData {
 }
 
 
+/// The seat this browser took in the game of the current page, as player number and secret.
+pub fn use_player_seat() -> Signal<Option<(usize, String)>> {
+    let (player_assignment_cookie, _) = use_player_assignment_cookie();
+    Signal::derive(move || player_assignment_cookie.get().map(|data| (data.player_number, data.player_secret)))
+}
+
+/// Navigation state, which lets the player take the seat on the next game page. The cookie
+///  of a seat is bound to the page of its game, so it cannot carry the seat over.
+pub fn seat_navigation_state(player_number: usize, player_secret: String) -> State {
+    let data = PlayerAssignmentData { player_number, player_secret };
+    State(serde_json::to_string(&data).ok().map(|data| JsValue::from_str(&data)))
+}
+
+
 fn use_player_assignment_cookie() -> (Signal<Option<PlayerAssignmentData>>, WriteSignal<Option<PlayerAssignmentData>>) {
     let location = use_location();
 
@@ -191,7 +236,7 @@ fn use_player_assignment_cookie() -> (Signal<Option<PlayerAssignmentData>>, Writ
     ) = use_cookie_with_options::<PlayerAssignmentData, JsonSerdeCodec>(
         PLAYER_ASSIGNMENT_COOKIE_NAME,
         UseCookieOptions::default()
-            .max_age::<i64>(Some(PLAYER_ASSIGNMENT_COOKIE_LIFETIME_IN_SEC * 1000)) // 14 days
+            .max_age::<i64>(Some(PLAYER_ASSIGNMENT_COOKIE_LIFETIME_IN_SEC * 1000))
             .same_site(SameSite::Lax)
             .path(location.pathname.get_untracked()),
     );
@@ -0,0 +1,118 @@
+use leptos::*;
+use leptos_use::{use_websocket_with_options, ReconnectLimit, UseWebSocketOptions, UseWebSocketReturn};
+use leptos_use::core::ConnectionReadyState;
+use codee::string::JsonSerdeCodec;
+use uuid::Uuid;
+
+use crate::game::game_result::GameResult;
+use crate::pages::game_page::{MoveRejection, Player, PlayerClientData, PlayerIdentity, PlayerServerData};
+
+const PLAYERS_SOCKET_RECONNECT_INTERVAL_IN_MS: u64 = 5000;
+
+
+pub struct UsePlayersSocketResult<PlayMoveFn>
+where
+    PlayMoveFn: Fn(String) + Clone + 'static,
+{
+    pub players: Signal<Vec<Player>>,
+    /// FEN of the position the game started from.
+    pub initial_position: Signal<Option<String>>,
+    /// The moves played so far in numeric notation.
+    pub moves: Signal<Vec<String>>,
+    pub result: Signal<Option<GameResult>>,
+    pub move_error: Signal<Option<MoveRejection>>,
+    pub play_move: PlayMoveFn,
+}
+
+
+/// Connects to the players websocket of the game, keeps the player list and the moves up to
+///  date and answers every player list with an alive message, as long as a player is assigned.
+pub fn use_players_socket(
+    game_id: Signal<Uuid>,
+    player_number: RwSignal<Option<usize>>,
+    player_secret: RwSignal<Option<String>>,
+) -> UsePlayersSocketResult<impl Fn(String) + Clone + 'static> {
+    let (players, set_players) = create_signal::<Vec<Player>>(Vec::new());
+    let (initial_position, set_initial_position) = create_signal::<Option<String>>(None);
+    let (moves, set_moves) = create_signal::<Vec<String>>(Vec::new());
+    let (result, set_result) = create_signal::<Option<GameResult>>(None);
+    let (move_error, set_move_error) = create_signal::<Option<MoveRejection>>(None);
+    let UseWebSocketReturn {
+        ready_state: players_socket_ready_state,
+        message: players_socket_message,
+        send: players_socket_send,
+        ..
+    } = use_websocket_with_options::<PlayerClientData, PlayerServerData, JsonSerdeCodec>(
+        "/players",
+        UseWebSocketOptions::default()
+            .reconnect_limit(ReconnectLimit::Infinite)
+            .on_close(|_| logging::log!("Lost connection to players websocket."))
+            .reconnect_interval(PLAYERS_SOCKET_RECONNECT_INTERVAL_IN_MS),
+    );
+
+    let players_socket_send_alive = players_socket_send.clone();
+    create_effect(move |_| {
+        players_socket_message.with(|message| {
+            match message {
+                Some(PlayerServerData::PlayerList(player_list)) => {
+                    set_players.set(player_list.clone());
+                    if let (Some(player_number), Some(secret)) = (player_number.get_untracked(), player_secret.get_untracked()) {
+                        players_socket_send_alive(&PlayerClientData::Alive(PlayerIdentity {
+                            game_id: game_id.get_untracked(),
+                            player_number,
+                            secret,
+                        }));
+                    }
+                }
+                Some(PlayerServerData::Moves { initial_position: position, moves: move_list, result: game_result }) => {
+                    if initial_position.get_untracked().as_ref() != Some(position) {
+                        set_initial_position.set(Some(position.clone()));
+                    }
+                    if &moves.get_untracked() != move_list {
+                        set_moves.set(move_list.clone());
+                        set_move_error.set(None);
+                    }
+                    if &result.get_untracked() != game_result {
+                        set_result.set(*game_result);
+                    }
+                }
+                Some(PlayerServerData::MoveRejected(error)) => {
+                    set_move_error.set(Some(error.clone()));
+                }
+                None => {}
+            }
+        });
+    });
+
+    let players_socket_send_select_game = players_socket_send.clone();
+    create_effect(move |_| {
+        players_socket_ready_state.with(|state| {
+            if state == &ConnectionReadyState::Open {
+                players_socket_send_select_game(&PlayerClientData::SelectGame(game_id.get()));
+            }
+        });
+    });
+
+    let players_socket_send_move = players_socket_send.clone();
+    let play_move = move |notation: String| {
+        if let (Some(player_number), Some(secret)) = (player_number.get_untracked(), player_secret.get_untracked()) {
+            players_socket_send_move(&PlayerClientData::PlayMove(PlayerIdentity {
+                game_id: game_id.get_untracked(),
+                player_number,
+                secret,
+            }, notation));
+        }
+        else {
+            set_move_error.set(Some(MoveRejection::NotJoined));
+        }
+    };
+
+    UsePlayersSocketResult {
+        players: players.into(),
+        initial_position: initial_position.into(),
+        moves: moves.into(),
+        result: result.into(),
+        move_error: move_error.into(),
+        play_move,
+    }
+}
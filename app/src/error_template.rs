@@ -3,6 +3,8 @@ use http::status::StatusCode;
 use leptos::*;
 use thiserror::Error;
 
+use crate::components::i18n::{use_locale, Text, Translate};
+
 #[cfg(feature = "ssr")]
 use leptos_axum::ResponseOptions;
 
@@ -43,6 +45,8 @@ pub fn ErrorTemplate(
         .filter_map(|(_k, v)| v.downcast_ref::<AppError>().cloned())
         .collect();
     println!("Errors: {errors:#?}");
+    let locale = use_locale();
+    let number_of_errors = errors.len();
 
     // Only the response code for the first error is actually sent from the server
     // this may be customized by the specific application
@@ -54,7 +58,7 @@ pub fn ErrorTemplate(
     }}
 
     view! {
-        <h1>{if errors.len() > 1 { "Errors" } else { "Error" }}</h1>
+        <h1>{move || Text::ErrorHeading { number_of_errors }.translate(locale.get())}</h1>
         <For
             // a function that returns the items we're iterating over; a signal is fine
             each=move || { errors.clone().into_iter().enumerate() }
@@ -62,11 +66,10 @@ pub fn ErrorTemplate(
             key=|(index, _error)| *index
             // renders each item to a view
             children=move |error| {
-                let error_string = error.1.to_string();
                 let error_code = error.1.status_code();
                 view! {
                     <h2>{error_code.to_string()}</h2>
-                    <p>"Error: " {error_string}</p>
+                    <p>{move || Text::Error(error.1.translate(locale.get())).translate(locale.get())}</p>
                 }
             }
         />
@@ -0,0 +1,54 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+
+
+pub const ABANDONMENT_GRACE_PERIODS_IN_SEC: [i64; 4] = [60*2, 60*5, 60*15, 60*60];
+const DEFAULT_SEAT_RELEASE_PERIOD_IN_SEC: i64 = 60*60*24; // 1 day
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct AbandonmentRules {
+    /// Time without a ping after which the opponent may claim a win or a draw.
+    pub grace_period_in_sec: i64,
+    /// Time without a ping after which a seat is released for other people.
+    pub seat_release_period_in_sec: i64,
+}
+
+impl Default for AbandonmentRules {
+    fn default() -> Self {
+        Self {
+            grace_period_in_sec: ABANDONMENT_GRACE_PERIODS_IN_SEC[0],
+            seat_release_period_in_sec: DEFAULT_SEAT_RELEASE_PERIOD_IN_SEC,
+        }
+    }
+}
+
+impl AbandonmentRules {
+    /// Whether the periods are the ones offered when creating a game.
+    pub fn is_valid(&self) -> bool {
+        ABANDONMENT_GRACE_PERIODS_IN_SEC.contains(&self.grace_period_in_sec)
+            && self.seat_release_period_in_sec == DEFAULT_SEAT_RELEASE_PERIOD_IN_SEC
+    }
+
+    pub fn is_disconnected(&self, last_ping: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+        is_older_than(last_ping, now, self.grace_period_in_sec)
+    }
+
+    pub fn is_seat_abandoned(&self, last_ping: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+        is_older_than(last_ping, now, self.seat_release_period_in_sec)
+    }
+}
+
+fn is_older_than(last_ping: Option<DateTime<Utc>>, now: DateTime<Utc>, period_in_sec: i64) -> bool {
+    match last_ping {
+        Some(last_ping) => TimeDelta::try_seconds(period_in_sec).is_some_and(|period| (now - last_ping) > period),
+        None => true,
+    }
+}
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum AbandonmentClaim {
+    Win,
+    Draw,
+}
@@ -0,0 +1,90 @@
+use core::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+use super::player_color::PlayerColor;
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+pub enum AdjournmentError {
+    UnknownError(String),
+    InvalidPlayerSecret,
+    GameNotRunning,
+    AlreadyAdjourned,
+    NotAdjourned,
+    NoOfferFromOpponent,
+}
+
+impl fmt::Display for AdjournmentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AdjournmentError::UnknownError(s) => write!(f, "{}", s),
+            AdjournmentError::InvalidPlayerSecret => write!(f, "Invalid player secret."),
+            AdjournmentError::GameNotRunning => write!(f, "Game is not running."),
+            AdjournmentError::AlreadyAdjourned => write!(f, "Game is already adjourned."),
+            AdjournmentError::NotAdjourned => write!(f, "Game is not adjourned."),
+            AdjournmentError::NoOfferFromOpponent => write!(f, "Opponent did not offer an adjournment."),
+        }
+    }
+}
+
+impl FromStr for AdjournmentError {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(AdjournmentError::UnknownError(s.to_string()))
+    }
+}
+
+
+/// Adjournment state of a game: an adjournment must be offered by one player and accepted
+///  by the other one. An adjourned game is resumed once both players re-authenticated.
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub struct Adjournment {
+    pub offered_by: Option<PlayerColor>,
+    pub is_adjourned: bool,
+    pub resumed_by: Vec<PlayerColor>,
+}
+
+impl Adjournment {
+    pub fn offer(&mut self, player: PlayerColor) -> Result<(), AdjournmentError> {
+        if self.is_adjourned {
+            return Err(AdjournmentError::AlreadyAdjourned);
+        }
+        self.offered_by = Some(player);
+        Ok(())
+    }
+
+    pub fn accept(&mut self, player: PlayerColor) -> Result<(), AdjournmentError> {
+        if self.is_adjourned {
+            return Err(AdjournmentError::AlreadyAdjourned);
+        }
+        if self.offered_by != Some(player.opponent()) {
+            return Err(AdjournmentError::NoOfferFromOpponent);
+        }
+        self.offered_by = None;
+        self.is_adjourned = true;
+        self.resumed_by.clear();
+        Ok(())
+    }
+
+    pub fn decline(&mut self) {
+        self.offered_by = None;
+    }
+
+    /// Records that a player is back, returns true if the game can be resumed now.
+    pub fn resume(&mut self, player: PlayerColor) -> Result<bool, AdjournmentError> {
+        if !self.is_adjourned {
+            return Err(AdjournmentError::NotAdjourned);
+        }
+        if !self.resumed_by.contains(&player) {
+            self.resumed_by.push(player);
+        }
+        if self.resumed_by.contains(&player.opponent()) {
+            self.is_adjourned = false;
+            self.resumed_by.clear();
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
@@ -0,0 +1,300 @@
+use core::fmt;
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+
+use super::board::Position;
+use super::engine::{Engine, Score, SearchLimits, WIN_SCORE};
+use super::moves::Move;
+use super::notation::format_move;
+use super::opening_book::OpeningBook;
+use super::player_color::PlayerColor;
+use super::tablebase::{Tablebase, TablebaseValue};
+
+/// A large advantage and a won game are judged alike, otherwise every move in a won game
+///  which does not win fastest would be a blunder.
+const MAX_JUDGED_SCORE: Score = 1000;
+/// How much a move may worsen the score, in hundredths of a man, before it is judged.
+const INACCURACY_LOSS: Score = 40;
+const MISTAKE_LOSS: Score = 100;
+const BLUNDER_LOSS: Score = 250;
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub enum MoveJudgement {
+    Inaccuracy,
+    Mistake,
+    Blunder,
+}
+
+impl MoveJudgement {
+    fn from_loss(loss: Score) -> Option<Self> {
+        match loss {
+            loss if loss >= BLUNDER_LOSS => Some(MoveJudgement::Blunder),
+            loss if loss >= MISTAKE_LOSS => Some(MoveJudgement::Mistake),
+            loss if loss >= INACCURACY_LOSS => Some(MoveJudgement::Inaccuracy),
+            _ => None,
+        }
+    }
+
+    /// The move strength suffix used in game notation.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            MoveJudgement::Inaccuracy => "?!",
+            MoveJudgement::Mistake => "?",
+            MoveJudgement::Blunder => "??",
+        }
+    }
+
+    /// The numeric annotation glyph of the move strength in PDN.
+    pub fn nag(&self) -> u8 {
+        match self {
+            MoveJudgement::Inaccuracy => 6,
+            MoveJudgement::Mistake => 2,
+            MoveJudgement::Blunder => 4,
+        }
+    }
+}
+
+impl fmt::Display for MoveJudgement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MoveJudgement::Inaccuracy => write!(f, "Inaccuracy"),
+            MoveJudgement::Mistake => write!(f, "Mistake"),
+            MoveJudgement::Blunder => write!(f, "Blunder"),
+        }
+    }
+}
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+pub struct PlyAnalysis {
+    /// The score of the position after the move, from the point of view of red.
+    pub score: Score,
+    pub judgement: Option<MoveJudgement>,
+    /// The best move in numeric notation, if the played move was judged.
+    pub better_move: Option<String>,
+}
+
+/// The engine's view of a finished game, one entry per ply.
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+pub struct GameAnalysis {
+    /// The score of the initial position, from the point of view of red.
+    pub initial_score: Score,
+    pub plies: Vec<PlyAnalysis>,
+}
+
+impl GameAnalysis {
+    /// The scores of the initial position and of the position after every ply.
+    pub fn scores(&self) -> Vec<Score> {
+        std::iter::once(self.initial_score).chain(self.plies.iter().map(|ply| ply.score)).collect()
+    }
+}
+
+
+/// Analyses games move by move. Book moves are never judged and endgames in the tablebase
+///  get their exact scores.
+pub struct Analyzer {
+    engine: Engine,
+    limits: SearchLimits,
+    opening_book: Option<Arc<OpeningBook>>,
+    tablebase: Option<Arc<Tablebase>>,
+}
+
+impl Analyzer {
+    pub fn new(limits: SearchLimits) -> Self {
+        Self {
+            engine: Engine::new(),
+            limits,
+            opening_book: None,
+            tablebase: None,
+        }
+    }
+
+    pub fn set_opening_book(&mut self, opening_book: Option<Arc<OpeningBook>>) {
+        self.opening_book = opening_book;
+    }
+
+    pub fn set_tablebase(&mut self, tablebase: Option<Arc<Tablebase>>) {
+        self.engine.set_tablebase(tablebase.clone());
+        self.tablebase = tablebase;
+    }
+
+    pub fn analyze(&mut self, initial_position: Position, moves: &[Move]) -> GameAnalysis {
+        self.engine.clear();
+        let evaluations: Vec<(Score, Option<Move>)> = positions(initial_position, moves).iter()
+            .map(|position| self.evaluate(position))
+            .collect();
+        self.judge(initial_position, moves, &evaluations)
+    }
+
+    /// Judges the moves by the score and the best move of the initial position and of the
+    ///  position after every ply, from the point of view of the side to move. The evaluations
+    ///  may come from another engine.
+    pub fn judge(&self, initial_position: Position, moves: &[Move], evaluations: &[(Score, Option<Move>)]) -> GameAnalysis {
+        let positions = positions(initial_position, moves);
+        assert_eq!(positions.len(), evaluations.len(), "Every position needs an evaluation.");
+        let evaluations: Vec<(Score, Option<Move>)> = positions.iter().zip(evaluations)
+            .map(|(position, (score, best_move))| (self.exact_score(position).unwrap_or(*score), best_move.clone()))
+            .collect();
+        let score_for_red = |position: &Position, score: Score| match position.side_to_move {
+            PlayerColor::Red => score,
+            PlayerColor::Blue => -score,
+        };
+
+        let plies = moves.iter().enumerate().map(|(ply, mv)| {
+            let position = &positions[ply];
+            let (score, best_move) = (evaluations[ply].0, &evaluations[ply].1);
+            let next_score = evaluations[ply + 1].0;
+            let is_book_move = self.opening_book.as_ref().is_some_and(|opening_book| {
+                opening_book.moves(position).iter().any(|(book_move, statistics)| book_move == mv && statistics.weight() > 0)
+            });
+            let is_forced = position.legal_moves().len() == 1;
+            let judgement = match best_move {
+                Some(best_move) if best_move != mv && !is_book_move && !is_forced => {
+                    let loss = score.clamp(-MAX_JUDGED_SCORE, MAX_JUDGED_SCORE)
+                        - (-next_score).clamp(-MAX_JUDGED_SCORE, MAX_JUDGED_SCORE);
+                    MoveJudgement::from_loss(loss)
+                }
+                _ => None,
+            };
+            PlyAnalysis {
+                score: score_for_red(&positions[ply + 1], next_score),
+                judgement,
+                better_move: judgement.and(best_move.as_ref()).map(|best_move| format_move(position, best_move)),
+            }
+        }).collect();
+
+        GameAnalysis {
+            initial_score: score_for_red(&initial_position, evaluations[0].0),
+            plies,
+        }
+    }
+
+    fn evaluate(&mut self, position: &Position) -> (Score, Option<Move>) {
+        // a search does not think about a single legal move, but its score is needed here
+        let forced_move = match position.legal_moves().len() {
+            1 => self.engine.score_moves(position, self.limits).into_iter().next(),
+            _ => None,
+        };
+        match forced_move {
+            Some((mv, score)) => (score, Some(mv)),
+            None => {
+                let result = self.engine.search(position, self.limits);
+                (result.score, result.best_move)
+            }
+        }
+    }
+
+    fn exact_score(&self, position: &Position) -> Option<Score> {
+        self.tablebase.as_ref()
+            .filter(|tablebase| position.board.pieces().count() <= tablebase.max_pieces() as usize)
+            .and_then(|tablebase| tablebase.probe(position))
+            .map(|value| match value {
+                TablebaseValue::Win(distance) => WIN_SCORE - distance as Score,
+                TablebaseValue::Loss(distance) => -WIN_SCORE + distance as Score,
+                TablebaseValue::Draw => 0,
+            })
+    }
+}
+
+/// The initial position and the position after every ply.
+pub fn positions(initial_position: Position, moves: &[Move]) -> Vec<Position> {
+    let mut positions = vec![initial_position];
+    for mv in moves {
+        positions.push(positions.last().expect("There is at least the initial position.").after(mv));
+    }
+    positions
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::game_result::{GameResult, WinReason};
+    use crate::game::pdn::{PdnGame, PdnMove, MOVE_STRENGTHS};
+
+    /// Judges the first legal move of the initial position, while the engine preferred the
+    ///  second one. The scores are from the point of view of the side to move.
+    fn judge_first_move(analyzer: &Analyzer, score: Score, next_score: Score) -> PlyAnalysis {
+        let position = Position::initial();
+        let moves = position.legal_moves();
+        let evaluations = [(score, Some(moves[1].clone())), (next_score, None)];
+        analyzer.judge(position, &moves[..1], &evaluations).plies.remove(0)
+    }
+
+    #[test]
+    fn losses_are_judged_by_thresholds() {
+        let analyzer = Analyzer::new(SearchLimits::default());
+        assert_eq!(judge_first_move(&analyzer, 0, INACCURACY_LOSS - 1).judgement, None);
+        assert_eq!(judge_first_move(&analyzer, 0, INACCURACY_LOSS).judgement, Some(MoveJudgement::Inaccuracy));
+        assert_eq!(judge_first_move(&analyzer, 0, MISTAKE_LOSS).judgement, Some(MoveJudgement::Mistake));
+        assert_eq!(judge_first_move(&analyzer, 20, BLUNDER_LOSS - 20).judgement, Some(MoveJudgement::Blunder));
+        // a won game stays won, even if the move does not win fastest
+        assert_eq!(judge_first_move(&analyzer, WIN_SCORE - 3, -2 * MAX_JUDGED_SCORE).judgement, None);
+    }
+
+    #[test]
+    fn judged_moves_name_the_better_move() {
+        let analyzer = Analyzer::new(SearchLimits::default());
+        let position = Position::initial();
+        let better_move = format_move(&position, &position.legal_moves()[1]);
+        assert_eq!(judge_first_move(&analyzer, 0, MISTAKE_LOSS).better_move, Some(better_move));
+        assert_eq!(judge_first_move(&analyzer, 0, 0).better_move, None);
+    }
+
+    #[test]
+    fn scores_are_from_the_point_of_view_of_red() {
+        let analyzer = Analyzer::new(SearchLimits::default());
+        let position = Position::initial();
+        let moves = position.legal_moves();
+        let analysis = analyzer.judge(position, &moves[..1], &[(30, Some(moves[0].clone())), (40, None)]);
+        assert_eq!(analysis.initial_score, 30);
+        assert_eq!(analysis.plies[0].score, -40);
+        assert_eq!(analysis.plies[0].judgement, None);
+        assert_eq!(analysis.scores(), vec![30, -40]);
+    }
+
+    #[test]
+    fn book_moves_are_not_judged() {
+        let mut analyzer = Analyzer::new(SearchLimits::default());
+        let position = Position::initial();
+        let mut opening_book = OpeningBook::new();
+        let winner = GameResult::Win { winner: PlayerColor::Red, reason: WinReason::Unspecified };
+        opening_book.add_game(position, &position.legal_moves()[..1], Some(winner));
+        analyzer.set_opening_book(Some(Arc::new(opening_book)));
+        assert_eq!(judge_first_move(&analyzer, 0, BLUNDER_LOSS).judgement, None);
+    }
+
+    #[test]
+    fn forced_moves_are_not_judged() {
+        let analyzer = Analyzer::new(SearchLimits::default());
+        let position = Position::from_fen("B:W15:B11").unwrap();
+        let moves = position.legal_moves();
+        assert_eq!(moves.len(), 1);
+        let other_move = Position::initial().legal_moves().remove(0);
+        let analysis = analyzer.judge(position, &moves, &[(0, Some(other_move)), (BLUNDER_LOSS, None)]);
+        assert_eq!(analysis.plies[0].judgement, None);
+    }
+
+    #[test]
+    fn judgements_match_the_pdn_move_strengths() {
+        for judgement in [MoveJudgement::Inaccuracy, MoveJudgement::Mistake, MoveJudgement::Blunder] {
+            assert!(MOVE_STRENGTHS.contains(&(judgement.symbol(), judgement.nag())), "{}", judgement);
+        }
+    }
+
+    #[test]
+    fn judged_moves_are_annotated_in_pdn() {
+        let analyzer = Analyzer::new(SearchLimits::default());
+        let analysis = GameAnalysis {
+            initial_score: 0,
+            plies: vec![judge_first_move(&analyzer, 0, BLUNDER_LOSS)],
+        };
+        let mut game = PdnGame::default();
+        game.moves.push(PdnMove::new(format_move(&Position::initial(), &Position::initial().legal_moves()[0])));
+        game.annotate(&analysis);
+        assert_eq!(game.moves[0].nags, vec![MoveJudgement::Blunder.nag()]);
+        assert_eq!(game.moves[0].variations, vec![vec![PdnMove::new(analysis.plies[0].better_move.clone().unwrap())]]);
+        assert!(game.to_string().contains(" $4 ("));
+    }
+}
@@ -0,0 +1,107 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+use super::board::Position;
+use super::notation::parse_move;
+
+
+const THREE_MOVE_BALLOTS: &str = include_str!("../../data/three_move_ballots.txt");
+const NAME_SEPARATOR: char = '|';
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+pub enum BallotError {
+    /// The ballot is not in the ballot deck or its moves cannot be played.
+    UnknownBallot(usize),
+}
+
+impl fmt::Display for BallotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BallotError::UnknownBallot(number) => write!(f, "Ballot {} is not in the ballot deck.", number),
+        }
+    }
+}
+
+
+/// A three-move opening, the game starts from the position after these moves.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+pub struct Ballot {
+    pub number: usize,
+    pub moves: String,
+    /// The name of the opening, e.g. `Ayrshire Lassie`, if the deck gives one.
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+impl Ballot {
+    pub fn position(&self) -> Option<Position> {
+        let mut position = Position::initial();
+        for text in self.moves.split_whitespace() {
+            let mv = parse_move(&position, text).ok()?;
+            position.play(&mv);
+        }
+        Some(position)
+    }
+
+    /// Whether the ballot is one of the deck, with the same number and moves.
+    pub fn is_in_deck(&self) -> bool {
+        ballot_deck().contains(self)
+    }
+}
+
+impl fmt::Display for Ballot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Ballot {}: {}", self.number, self.moves)?;
+        if let Some(name) = &self.name {
+            write!(f, " ({})", name)?;
+        }
+        Ok(())
+    }
+}
+
+
+pub fn ballot_deck() -> Vec<Ballot> {
+    THREE_MOVE_BALLOTS.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .enumerate()
+        .map(|(index, line)| {
+            let (moves, name) = match line.split_once(NAME_SEPARATOR) {
+                Some((moves, name)) => (moves.trim(), Some(name.trim().to_string())),
+                None => (line, None),
+            };
+            Ballot {
+                number: index + 1,
+                moves: moves.to_string(),
+                name,
+            }
+        })
+        .collect()
+}
+
+pub fn ballot(number: usize) -> Option<Ballot> {
+    ballot_deck().into_iter().find(|ballot| ballot.number == number)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_ballot_can_be_played() {
+        for ballot in ballot_deck() {
+            let position = ballot.position().unwrap_or_else(|| panic!("{}", ballot));
+            assert!(position.validate().is_ok(), "{}", ballot);
+        }
+    }
+
+    #[test]
+    fn names_are_read_from_the_deck() {
+        let ballot = ballot_deck().into_iter().find(|ballot| ballot.moves == "11-15 24-20 8-11").unwrap();
+        assert_eq!(ballot.name.as_deref(), Some("Ayrshire Lassie"));
+        assert_eq!(ballot.to_string(), format!("Ballot {}: 11-15 24-20 8-11 (Ayrshire Lassie)", ballot.number));
+        assert!(ballot.is_in_deck());
+    }
+}
@@ -0,0 +1,183 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+use super::player_color::PlayerColor;
+
+
+pub const BOARD_SIZE: usize = 8;
+pub const NUMBER_OF_SQUARES: usize = 32;
+const SQUARES_PER_ROW: usize = BOARD_SIZE / 2;
+
+
+/// A playable (dark) square in standard numbering from 1 to 32. Square 1 is in the
+///  first row of the red player, square 32 in the first row of the blue player.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub struct Square(u8);
+
+impl Square {
+    pub fn new(number: u8) -> Option<Self> {
+        if (1..=NUMBER_OF_SQUARES as u8).contains(&number) {
+            Some(Square(number))
+        }
+        else {
+            None
+        }
+    }
+
+    pub fn from_index(index: usize) -> Self {
+        assert!(index < NUMBER_OF_SQUARES, "Invalid square index {}.", index);
+        Square(index as u8 + 1)
+    }
+
+    /// Returns the square at the given row and column, if it is a playable square.
+    pub fn from_coordinates(row: isize, column: isize) -> Option<Self> {
+        let is_on_board = (0..BOARD_SIZE as isize).contains(&row) && (0..BOARD_SIZE as isize).contains(&column);
+        if !is_on_board || (row + column) % 2 == 0 {
+            return None;
+        }
+        Some(Square::from_index(row as usize * SQUARES_PER_ROW + column as usize / 2))
+    }
+
+    pub fn all() -> impl DoubleEndedIterator<Item = Square> {
+        (0..NUMBER_OF_SQUARES).map(Square::from_index)
+    }
+
+    pub fn number(&self) -> u8 {
+        self.0
+    }
+
+    pub fn index(&self) -> usize {
+        self.0 as usize - 1
+    }
+
+    pub fn row(&self) -> usize {
+        self.index() / SQUARES_PER_ROW
+    }
+
+    pub fn column(&self) -> usize {
+        let position_in_row = self.index() % SQUARES_PER_ROW;
+        if self.row().is_multiple_of(2) {
+            2 * position_in_row + 1
+        }
+        else {
+            2 * position_in_row
+        }
+    }
+
+    /// The diagonal neighbour in the given direction.
+    pub fn neighbour(&self, row_step: isize, column_step: isize) -> Option<Square> {
+        Square::from_coordinates(self.row() as isize + row_step, self.column() as isize + column_step)
+    }
+
+    pub fn is_promotion_square_for(&self, color: PlayerColor) -> bool {
+        match color {
+            PlayerColor::Red => self.row() == BOARD_SIZE - 1,
+            PlayerColor::Blue => self.row() == 0,
+        }
+    }
+}
+
+impl fmt::Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub enum PieceKind {
+    Man,
+    King,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub struct Piece {
+    pub color: PlayerColor,
+    pub kind: PieceKind,
+}
+
+impl Piece {
+    pub fn man(color: PlayerColor) -> Self {
+        Self { color, kind: PieceKind::Man }
+    }
+
+    pub fn king(color: PlayerColor) -> Self {
+        Self { color, kind: PieceKind::King }
+    }
+
+    pub fn is_king(&self) -> bool {
+        self.kind == PieceKind::King
+    }
+
+    /// Row steps this piece may move in: men only move forward, kings in both directions.
+    pub fn row_steps(&self) -> &'static [isize] {
+        match (self.kind, self.color) {
+            (PieceKind::King, _) => &[1, -1],
+            (PieceKind::Man, PlayerColor::Red) => &[1],
+            (PieceKind::Man, PlayerColor::Blue) => &[-1],
+        }
+    }
+}
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Board {
+    squares: [Option<Piece>; NUMBER_OF_SQUARES],
+}
+
+impl Board {
+    pub fn empty() -> Self {
+        Self {
+            squares: [None; NUMBER_OF_SQUARES],
+        }
+    }
+
+    /// The starting position: red men on the squares 1 to 12, blue men on 21 to 32.
+    pub fn initial() -> Self {
+        let mut board = Board::empty();
+        for square in Square::all() {
+            match square.number() {
+                1..=12 => board.set(square, Some(Piece::man(PlayerColor::Red))),
+                21..=32 => board.set(square, Some(Piece::man(PlayerColor::Blue))),
+                _ => {}
+            }
+        }
+        board
+    }
+
+    pub fn get(&self, square: Square) -> Option<Piece> {
+        self.squares[square.index()]
+    }
+
+    pub fn set(&mut self, square: Square, piece: Option<Piece>) {
+        self.squares[square.index()] = piece;
+    }
+
+    pub fn pieces(&self) -> impl Iterator<Item = (Square, Piece)> + '_ {
+        Square::all().filter_map(|square| self.get(square).map(|piece| (square, piece)))
+    }
+
+    pub fn pieces_of(&self, color: PlayerColor) -> impl Iterator<Item = (Square, Piece)> + '_ {
+        self.pieces().filter(move |(_, piece)| piece.color == color)
+    }
+
+    pub fn count(&self, color: PlayerColor) -> usize {
+        self.pieces_of(color).count()
+    }
+}
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Position {
+    pub board: Board,
+    pub side_to_move: PlayerColor,
+}
+
+impl Position {
+    pub fn initial() -> Self {
+        Self {
+            board: Board::initial(),
+            side_to_move: PlayerColor::Red,
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+use super::engine::{Score, SearchLimits};
+
+
+/// The name of the computer player, as it appears in game records.
+pub const BOT_NAME: &str = "Computer";
+
+
+/// How strong the computer plays. Weaker levels search less deeply, misjudge positions
+///  and now and then deliberately play a worse move.
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub enum BotLevel {
+    Beginner,
+    Casual,
+    #[default]
+    Intermediate,
+    Advanced,
+    Expert,
+}
+
+impl BotLevel {
+    pub fn all() -> Vec<BotLevel> {
+        vec![BotLevel::Beginner, BotLevel::Casual, BotLevel::Intermediate, BotLevel::Advanced, BotLevel::Expert]
+    }
+
+    pub fn search_limits(&self) -> SearchLimits {
+        let (max_depth, max_nodes) = match self {
+            BotLevel::Beginner => (2, 5_000),
+            BotLevel::Casual => (4, 20_000),
+            BotLevel::Intermediate => (6, 100_000),
+            BotLevel::Advanced => (10, 300_000),
+            BotLevel::Expert => (16, 1_000_000),
+        };
+        SearchLimits { max_depth, max_nodes }
+    }
+
+    /// The largest random error added to the evaluation of a position.
+    pub fn evaluation_noise(&self) -> Score {
+        match self {
+            BotLevel::Beginner => 60,
+            BotLevel::Casual => 30,
+            BotLevel::Intermediate => 15,
+            BotLevel::Advanced => 5,
+            BotLevel::Expert => 0,
+        }
+    }
+
+    /// The chance to deliberately play a worse move instead of the best one.
+    pub fn mistake_in_percent(&self) -> usize {
+        match self {
+            BotLevel::Beginner => 40,
+            BotLevel::Casual => 20,
+            BotLevel::Intermediate => 8,
+            BotLevel::Advanced => 2,
+            BotLevel::Expert => 0,
+        }
+    }
+
+    /// How much worse than the best move a deliberate mistake may be, in hundredths of a man.
+    pub fn max_mistake_loss(&self) -> Score {
+        match self {
+            BotLevel::Beginner => 400,
+            BotLevel::Casual => 200,
+            BotLevel::Intermediate => 100,
+            BotLevel::Advanced => 50,
+            BotLevel::Expert => 0,
+        }
+    }
+}
+
+impl fmt::Display for BotLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BotLevel::Beginner => write!(f, "Beginner"),
+            BotLevel::Casual => write!(f, "Casual"),
+            BotLevel::Intermediate => write!(f, "Intermediate"),
+            BotLevel::Advanced => write!(f, "Advanced"),
+            BotLevel::Expert => write!(f, "Expert"),
+        }
+    }
+}
+
+
+/// What the computer values in a position besides the material.
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub enum BotStyle {
+    #[default]
+    Balanced,
+    /// Pushes the men forward to crown them early.
+    Aggressive,
+    /// Keeps the back row closed and waits for the opponent.
+    Defensive,
+}
+
+impl BotStyle {
+    pub fn all() -> Vec<BotStyle> {
+        vec![BotStyle::Balanced, BotStyle::Aggressive, BotStyle::Defensive]
+    }
+}
+
+impl fmt::Display for BotStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BotStyle::Balanced => write!(f, "balanced"),
+            BotStyle::Aggressive => write!(f, "aggressive"),
+            BotStyle::Defensive => write!(f, "defensive"),
+        }
+    }
+}
+
+
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct BotSettings {
+    pub level: BotLevel,
+    pub style: BotStyle,
+}
+
+impl fmt::Display for BotSettings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Computer: {}, {}", self.level, self.style)
+    }
+}
@@ -0,0 +1,76 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::player_color::PlayerColor;
+use super::time_control::TimeControl;
+
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Clock {
+    pub time_control: TimeControl,
+    /// Percentage of the time per move for each player, indexed by the player number.
+    pub time_odds_in_percent: [u32; 2],
+    pub active_player: PlayerColor,
+    pub is_started: bool,
+    pub deadline: Option<DateTime<Utc>>,
+    pub is_paused: bool,
+    pub paused_remaining_time_in_ms: Option<i64>,
+}
+
+impl Clock {
+    pub fn new(time_control: TimeControl, time_odds_in_percent: [u32; 2]) -> Self {
+        Self {
+            time_control,
+            time_odds_in_percent,
+            active_player: PlayerColor::Red,
+            is_started: false,
+            deadline: None,
+            is_paused: false,
+            paused_remaining_time_in_ms: None,
+        }
+    }
+
+    pub fn start(&mut self, now: DateTime<Utc>) {
+        self.is_started = true;
+        self.deadline = self.time_per_move_of(self.active_player).map(|time_per_move| now + time_per_move);
+    }
+
+    /// The time the player gets for each move, after the time odds.
+    pub fn time_per_move_of(&self, player: PlayerColor) -> Option<TimeDelta> {
+        let time_odds_in_percent = self.time_odds_in_percent[player.player_number()] as i32;
+        self.time_control.time_per_move().map(|time_per_move| time_per_move * time_odds_in_percent / 100)
+    }
+
+    pub fn stop(&mut self) {
+        self.deadline = None;
+    }
+
+    /// Freezes the clock, the remaining time is kept until the clock is resumed.
+    pub fn pause(&mut self, now: DateTime<Utc>) {
+        self.paused_remaining_time_in_ms = self.remaining_time(now).map(|remaining_time| remaining_time.num_milliseconds());
+        self.deadline = None;
+        self.is_paused = true;
+    }
+
+    pub fn resume(&mut self, now: DateTime<Utc>) {
+        self.deadline = self.paused_remaining_time_in_ms.map(|remaining_time_in_ms| now + TimeDelta::milliseconds(remaining_time_in_ms));
+        self.paused_remaining_time_in_ms = None;
+        self.is_paused = false;
+    }
+
+    /// Hands the move over to the opponent, who gets the full time per move.
+    pub fn switch_player(&mut self, now: DateTime<Utc>) {
+        self.active_player = self.active_player.opponent();
+        if self.is_started {
+            self.start(now);
+        }
+    }
+
+    pub fn remaining_time(&self, now: DateTime<Utc>) -> Option<TimeDelta> {
+        self.deadline.map(|deadline| (deadline - now).max(TimeDelta::zero()))
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.deadline.is_some_and(|deadline| now >= deadline)
+    }
+}
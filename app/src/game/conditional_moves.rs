@@ -0,0 +1,159 @@
+use core::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+use super::board::Position;
+use super::moves::Move;
+use super::notation::{parse_move, NotationError};
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+pub enum ConditionalMovesError {
+    UnknownError(String),
+    InvalidPlayerSecret,
+    GameNotRunning,
+    NotOpponentsTurn,
+    OnlyForCorrespondence,
+    IncompleteLine(String),
+    ConflictingReplies(String),
+    InvalidMove(NotationError),
+}
+
+impl fmt::Display for ConditionalMovesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConditionalMovesError::UnknownError(s) => write!(f, "{}", s),
+            ConditionalMovesError::InvalidPlayerSecret => write!(f, "Invalid player secret."),
+            ConditionalMovesError::GameNotRunning => write!(f, "Game is not running."),
+            ConditionalMovesError::NotOpponentsTurn => write!(f, "Moves can only be prepared during the opponent's turn."),
+            ConditionalMovesError::OnlyForCorrespondence => write!(f, "Conditional moves are only available in correspondence games."),
+            ConditionalMovesError::IncompleteLine(line) => write!(f, "'{}' needs a reply for every move of the opponent.", line),
+            ConditionalMovesError::ConflictingReplies(notation) => write!(f, "There are different replies to '{}'.", notation),
+            ConditionalMovesError::InvalidMove(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl FromStr for ConditionalMovesError {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(ConditionalMovesError::UnknownError(s.to_string()))
+    }
+}
+
+
+/// A prepared reply: if the opponent plays the move, the reply is played at once and the
+///  continuation prepares the following moves.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub struct ConditionalBranch {
+    pub opponent_move: String,
+    pub reply: String,
+    pub continuation: Vec<ConditionalBranch>,
+}
+
+/// Moves a player prepared during the opponent's turn, all in numeric notation. A branch
+///  matching the opponent's move is preferred over the premove, which is played after
+///  any move of the opponent as long as it is legal. Everything else is dropped once the
+///  opponent moved.
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub struct ConditionalMoves {
+    pub premove: Option<String>,
+    pub branches: Vec<ConditionalBranch>,
+}
+
+impl ConditionalMoves {
+    pub fn is_empty(&self) -> bool {
+        self.premove.is_none() && self.branches.is_empty()
+    }
+
+    /// Builds the branches from lines of alternating opponent moves and replies, lines
+    ///  starting with the same moves share their branches.
+    pub fn branches_from_lines(lines: &[Vec<String>]) -> Result<Vec<ConditionalBranch>, ConditionalMovesError> {
+        let mut branches: Vec<ConditionalBranch> = Vec::new();
+        for line in lines.iter().filter(|line| !line.is_empty()) {
+            if line.len() % 2 != 0 {
+                return Err(ConditionalMovesError::IncompleteLine(line.join(" ")));
+            }
+            let mut current = &mut branches;
+            for pair in line.chunks(2) {
+                let (opponent_move, reply) = (&pair[0], &pair[1]);
+                let index = match current.iter().position(|branch| &branch.opponent_move == opponent_move) {
+                    Some(index) if &current[index].reply != reply => {
+                        return Err(ConditionalMovesError::ConflictingReplies(opponent_move.clone()));
+                    }
+                    Some(index) => index,
+                    None => {
+                        current.push(ConditionalBranch {
+                            opponent_move: opponent_move.clone(),
+                            reply: reply.clone(),
+                            continuation: Vec::new(),
+                        });
+                        current.len() - 1
+                    }
+                };
+                current = &mut current[index].continuation;
+            }
+        }
+        Ok(branches)
+    }
+
+    /// The branches as lines of alternating opponent moves and replies.
+    pub fn lines(&self) -> Vec<Vec<String>> {
+        fn add_lines(branches: &[ConditionalBranch], prefix: &mut Vec<String>, lines: &mut Vec<Vec<String>>) {
+            for branch in branches {
+                prefix.push(branch.opponent_move.clone());
+                prefix.push(branch.reply.clone());
+                if branch.continuation.is_empty() {
+                    lines.push(prefix.clone());
+                }
+                add_lines(&branch.continuation, prefix, lines);
+                prefix.truncate(prefix.len() - 2);
+            }
+        }
+
+        let mut lines = Vec::new();
+        add_lines(&self.branches, &mut Vec::new(), &mut lines);
+        lines
+    }
+
+    /// Checks all prepared moves in the position with the opponent to move.
+    pub fn validate(&self, position: &Position) -> Result<(), ConditionalMovesError> {
+        fn validate_branches(branches: &[ConditionalBranch], position: &Position) -> Result<(), ConditionalMovesError> {
+            for branch in branches {
+                let opponent_move = parse_move(position, &branch.opponent_move).map_err(ConditionalMovesError::InvalidMove)?;
+                let position = position.after(&opponent_move);
+                let reply = parse_move(&position, &branch.reply).map_err(ConditionalMovesError::InvalidMove)?;
+                validate_branches(&branch.continuation, &position.after(&reply))?;
+            }
+            Ok(())
+        }
+
+        if let Some(premove) = &self.premove {
+            let is_possible = position.legal_moves().iter()
+                .any(|opponent_move| parse_move(&position.after(opponent_move), premove).is_ok());
+            if !is_possible {
+                return Err(ConditionalMovesError::InvalidMove(NotationError::IllegalMove(premove.clone())));
+            }
+        }
+        validate_branches(&self.branches, position)
+    }
+
+    /// Chooses the reply to the opponent's move, given the position before it. Only the
+    ///  continuation of a matching branch is kept, so every call consumes prepared moves.
+    pub fn reply_to(&mut self, position: &Position, opponent_move: &Move) -> Option<Move> {
+        let branches = std::mem::take(&mut self.branches);
+        let premove = self.premove.take();
+        let position_after = position.after(opponent_move);
+
+        let matching_branch = branches.into_iter()
+            .find(|branch| parse_move(position, &branch.opponent_move).is_ok_and(|mv| &mv == opponent_move));
+        if let Some(branch) = matching_branch {
+            if let Ok(reply) = parse_move(&position_after, &branch.reply) {
+                self.branches = branch.continuation;
+                return Some(reply);
+            }
+        }
+        premove.and_then(|premove| parse_move(&position_after, &premove).ok())
+    }
+}
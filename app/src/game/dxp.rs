@@ -0,0 +1,507 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+use super::board::{Board, Piece, PieceKind, Position, Square, NUMBER_OF_SQUARES};
+use super::moves::Move;
+use super::player_color::PlayerColor;
+use super::time_control::TimeControl;
+
+pub const DXP_VERSION: u8 = 1;
+/// Player names are padded with spaces to this length.
+pub const NAME_LENGTH: usize = 32;
+pub const MAX_CHAT_LENGTH: usize = 126;
+// DXP names the colours in Dutch. Like in FEN, black moves first and is our red player.
+const RED_DXP_COLOR: char = 'Z';
+const BLUE_DXP_COLOR: char = 'W';
+const EMPTY_SQUARE: char = 'e';
+const INITIAL_POSITION: char = 'A';
+const CUSTOM_POSITION: char = 'B';
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+pub enum DxpError {
+    InvalidMessage(String),
+    UnknownMessageType(char),
+    UnsupportedVersion(u8),
+    /// The peer sent a move which is not legal in the current position.
+    IllegalMove(String),
+    GameRefused(DxpAcceptance),
+    ConnectionClosed,
+    Io(String),
+}
+
+impl fmt::Display for DxpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DxpError::InvalidMessage(message) => write!(f, "Invalid DXP message: {}", message),
+            DxpError::UnknownMessageType(header) => write!(f, "Unknown DXP message type '{}'.", header),
+            DxpError::UnsupportedVersion(version) => write!(f, "DXP version {} is not supported.", version),
+            DxpError::IllegalMove(mv) => write!(f, "Illegal move {}.", mv),
+            DxpError::GameRefused(acceptance) => write!(f, "The game was refused: {}", acceptance),
+            DxpError::ConnectionClosed => write!(f, "The connection was closed."),
+            DxpError::Io(error) => write!(f, "Connection error: {}", error),
+        }
+    }
+}
+
+
+/// The answer to a game request.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum DxpAcceptance {
+    Accepted,
+    ColorRefused,
+    TimeRefused,
+    PositionRefused,
+    Refused,
+}
+
+impl DxpAcceptance {
+    fn code(&self) -> char {
+        match self {
+            DxpAcceptance::Accepted => '0',
+            DxpAcceptance::ColorRefused => '1',
+            DxpAcceptance::TimeRefused => '2',
+            DxpAcceptance::PositionRefused => '3',
+            DxpAcceptance::Refused => '9',
+        }
+    }
+
+    fn from_code(code: char) -> Option<Self> {
+        match code {
+            '0' => Some(DxpAcceptance::Accepted),
+            '1' => Some(DxpAcceptance::ColorRefused),
+            '2' => Some(DxpAcceptance::TimeRefused),
+            '3' => Some(DxpAcceptance::PositionRefused),
+            '9' => Some(DxpAcceptance::Refused),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DxpAcceptance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DxpAcceptance::Accepted => write!(f, "accepted"),
+            DxpAcceptance::ColorRefused => write!(f, "not with this colour"),
+            DxpAcceptance::TimeRefused => write!(f, "not with this time control"),
+            DxpAcceptance::PositionRefused => write!(f, "not from this position"),
+            DxpAcceptance::Refused => write!(f, "refused"),
+        }
+    }
+}
+
+
+/// The result of a game, from the point of view of the sender of the game end message.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum DxpGameEndReason {
+    Unknown,
+    Loss,
+    Draw,
+    Win,
+}
+
+impl DxpGameEndReason {
+    fn code(&self) -> char {
+        match self {
+            DxpGameEndReason::Unknown => '0',
+            DxpGameEndReason::Loss => '1',
+            DxpGameEndReason::Draw => '2',
+            DxpGameEndReason::Win => '3',
+        }
+    }
+
+    fn from_code(code: char) -> Option<Self> {
+        match code {
+            '0' => Some(DxpGameEndReason::Unknown),
+            '1' => Some(DxpGameEndReason::Loss),
+            '2' => Some(DxpGameEndReason::Draw),
+            '3' => Some(DxpGameEndReason::Win),
+            _ => None,
+        }
+    }
+}
+
+
+/// The answer to a request to take back moves.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum DxpBackupAcceptance {
+    Accepted,
+    NotSupported,
+    Refused,
+}
+
+impl DxpBackupAcceptance {
+    fn code(&self) -> char {
+        match self {
+            DxpBackupAcceptance::Accepted => '0',
+            DxpBackupAcceptance::NotSupported => '1',
+            DxpBackupAcceptance::Refused => '2',
+        }
+    }
+
+    fn from_code(code: char) -> Option<Self> {
+        match code {
+            '0' => Some(DxpBackupAcceptance::Accepted),
+            '1' => Some(DxpBackupAcceptance::NotSupported),
+            '2' => Some(DxpBackupAcceptance::Refused),
+            _ => None,
+        }
+    }
+}
+
+
+/// A message of the DamExchange Protocol. Messages are sent as ASCII text terminated by a
+///  null byte, the first character tells the type and the fields have fixed widths. The
+///  board of a custom start position has one character per square of our board, so it is
+///  32 characters long instead of the 50 of international draughts.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+pub enum DxpMessage {
+    Chat(String),
+    GameRequest {
+        version: u8,
+        initiator_name: String,
+        /// The colour of the peer receiving the request.
+        follower_color: PlayerColor,
+        /// The thinking time for the number of moves, the time is not limited if both are 0.
+        minutes: u16,
+        moves: u16,
+        /// `None` for the standard start position.
+        position: Option<Position>,
+    },
+    GameAcceptance { follower_name: String, acceptance: DxpAcceptance },
+    Move {
+        /// The thinking time spent on the move.
+        seconds: u16,
+        from: Square,
+        to: Square,
+        /// The squares of all captured pieces, in any order.
+        captures: Vec<Square>,
+    },
+    GameEnd { reason: DxpGameEndReason, stop_session: bool },
+    /// Asks to go back to the position before the move with the number, with the side to move.
+    BackupRequest { move_number: u16, side_to_move: PlayerColor },
+    BackupAcceptance(DxpBackupAcceptance),
+}
+
+impl DxpMessage {
+    pub fn from_move(mv: &Move, seconds: u16) -> Self {
+        DxpMessage::Move {
+            seconds,
+            from: mv.from(),
+            to: mv.to(),
+            captures: mv.captures.clone(),
+        }
+    }
+
+    /// The legal move in the position matching a move message. DXP only sends the start and
+    ///  end square and the captured pieces, which identify a move.
+    pub fn to_move(&self, position: &Position) -> Result<Move, DxpError> {
+        let DxpMessage::Move { from, to, captures, .. } = self else {
+            return Err(DxpError::InvalidMessage("Not a move message.".to_string()));
+        };
+        let mut captures = captures.clone();
+        captures.sort();
+        position.find_moves(*from, *to).into_iter()
+            .find(|mv| {
+                let mut move_captures = mv.captures.clone();
+                move_captures.sort();
+                move_captures == captures
+            })
+            .ok_or_else(|| DxpError::IllegalMove(self.to_string()))
+    }
+
+    pub fn parse(message: &str) -> Result<Self, DxpError> {
+        let mut fields = Fields { text: message };
+        let header = fields.character()?;
+        let message = match header {
+            'C' => DxpMessage::Chat(fields.rest().to_string()),
+            'R' => {
+                let version = fields.number(2)? as u8;
+                if version != DXP_VERSION {
+                    return Err(DxpError::UnsupportedVersion(version));
+                }
+                let initiator_name = fields.name()?;
+                let follower_color = fields.color()?;
+                let minutes = fields.number(3)?;
+                let moves = fields.number(3)?;
+                let position = match fields.character()? {
+                    INITIAL_POSITION => None,
+                    CUSTOM_POSITION => Some(fields.position()?),
+                    other => return Err(DxpError::InvalidMessage(format!("Unknown start position '{}'.", other))),
+                };
+                DxpMessage::GameRequest { version, initiator_name, follower_color, minutes, moves, position }
+            }
+            'A' => {
+                let follower_name = fields.name()?;
+                let code = fields.character()?;
+                let acceptance = DxpAcceptance::from_code(code)
+                    .ok_or_else(|| DxpError::InvalidMessage(format!("Unknown acceptance code '{}'.", code)))?;
+                DxpMessage::GameAcceptance { follower_name, acceptance }
+            }
+            'M' => {
+                let seconds = fields.number(4)?;
+                let from = fields.square()?;
+                let to = fields.square()?;
+                let number_of_captures = fields.number(2)?;
+                let captures = (0..number_of_captures).map(|_| fields.square()).collect::<Result<Vec<_>, _>>()?;
+                DxpMessage::Move { seconds, from, to, captures }
+            }
+            'E' => {
+                let code = fields.character()?;
+                let reason = DxpGameEndReason::from_code(code)
+                    .ok_or_else(|| DxpError::InvalidMessage(format!("Unknown game end reason '{}'.", code)))?;
+                let stop_session = match fields.character()? {
+                    '0' => false,
+                    '1' => true,
+                    other => return Err(DxpError::InvalidMessage(format!("Unknown stop code '{}'.", other))),
+                };
+                DxpMessage::GameEnd { reason, stop_session }
+            }
+            'B' => {
+                let move_number = fields.number(3)?;
+                let side_to_move = fields.color()?;
+                DxpMessage::BackupRequest { move_number, side_to_move }
+            }
+            'K' => {
+                let code = fields.character()?;
+                let acceptance = DxpBackupAcceptance::from_code(code)
+                    .ok_or_else(|| DxpError::InvalidMessage(format!("Unknown backup acceptance code '{}'.", code)))?;
+                DxpMessage::BackupAcceptance(acceptance)
+            }
+            other => return Err(DxpError::UnknownMessageType(other)),
+        };
+        Ok(message)
+    }
+}
+
+impl fmt::Display for DxpMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DxpMessage::Chat(text) => {
+                let text: String = text.chars().filter(|c| c.is_ascii() && *c != '\0').take(MAX_CHAT_LENGTH).collect();
+                write!(f, "C{}", text)
+            }
+            DxpMessage::GameRequest { version, initiator_name, follower_color, minutes, moves, position } => {
+                write!(f, "R{:02}{}{}{:03}{:03}", version, format_name(initiator_name), format_color(*follower_color), minutes, moves)?;
+                match position {
+                    None => write!(f, "{}", INITIAL_POSITION),
+                    Some(position) => write!(f, "{}{}{}", CUSTOM_POSITION, format_color(position.side_to_move), format_board(&position.board)),
+                }
+            }
+            DxpMessage::GameAcceptance { follower_name, acceptance } => {
+                write!(f, "A{}{}", format_name(follower_name), acceptance.code())
+            }
+            DxpMessage::Move { seconds, from, to, captures } => {
+                write!(f, "M{:04}{:02}{:02}{:02}", seconds, from.number(), to.number(), captures.len())?;
+                captures.iter().try_for_each(|square| write!(f, "{:02}", square.number()))
+            }
+            DxpMessage::GameEnd { reason, stop_session } => {
+                write!(f, "E{}{}", reason.code(), if *stop_session { '1' } else { '0' })
+            }
+            DxpMessage::BackupRequest { move_number, side_to_move } => {
+                write!(f, "B{:03}{}", move_number, format_color(*side_to_move))
+            }
+            DxpMessage::BackupAcceptance(acceptance) => write!(f, "K{}", acceptance.code()),
+        }
+    }
+}
+
+/// The number of plies played before the position a backup request asks for, counted from
+///  the start position of the game. Moves are numbered like in game notation.
+pub fn backup_ply(first_player: PlayerColor, move_number: u16, side_to_move: PlayerColor) -> Option<usize> {
+    let full_moves = (move_number as usize).checked_sub(1)?;
+    Some(2 * full_moves + usize::from(side_to_move != first_player))
+}
+
+/// The time control matching the thinking time of a game request. The moves of correspondence
+///  games take days, which a request cannot give, so only unlimited thinking time has one.
+pub fn requested_time_control(minutes: u16, moves: u16) -> Option<TimeControl> {
+    (minutes == 0 && moves == 0).then_some(TimeControl::Unlimited)
+}
+
+fn format_name(name: &str) -> String {
+    let name: String = name.chars().filter(|c| c.is_ascii() && !c.is_ascii_control()).take(NAME_LENGTH).collect();
+    format!("{:<width$}", name, width = NAME_LENGTH)
+}
+
+fn format_color(color: PlayerColor) -> char {
+    match color {
+        PlayerColor::Red => RED_DXP_COLOR,
+        PlayerColor::Blue => BLUE_DXP_COLOR,
+    }
+}
+
+fn format_board(board: &Board) -> String {
+    Square::all().map(|square| match board.get(square) {
+        None => EMPTY_SQUARE,
+        Some(piece) => {
+            let color = format_color(piece.color);
+            if piece.is_king() { color } else { color.to_ascii_lowercase() }
+        }
+    }).collect()
+}
+
+
+/// Reads the fixed width fields of a message one after the other.
+struct Fields<'a> {
+    text: &'a str,
+}
+
+impl<'a> Fields<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a str, DxpError> {
+        if self.text.len() < length || !self.text.is_char_boundary(length) {
+            return Err(DxpError::InvalidMessage("The message is too short.".to_string()));
+        }
+        let (field, rest) = self.text.split_at(length);
+        self.text = rest;
+        Ok(field)
+    }
+
+    fn rest(&mut self) -> &'a str {
+        std::mem::take(&mut self.text)
+    }
+
+    fn character(&mut self) -> Result<char, DxpError> {
+        let mut chars = self.text.chars();
+        let c = chars.next().ok_or_else(|| DxpError::InvalidMessage("The message is too short.".to_string()))?;
+        self.text = chars.as_str();
+        Ok(c)
+    }
+
+    fn number(&mut self, digits: usize) -> Result<u16, DxpError> {
+        let field = self.take(digits)?;
+        field.trim().parse().map_err(|_| DxpError::InvalidMessage(format!("'{}' is not a number.", field)))
+    }
+
+    fn name(&mut self) -> Result<String, DxpError> {
+        Ok(self.take(NAME_LENGTH)?.trim_end().to_string())
+    }
+
+    fn color(&mut self) -> Result<PlayerColor, DxpError> {
+        match self.character()? {
+            RED_DXP_COLOR => Ok(PlayerColor::Red),
+            BLUE_DXP_COLOR => Ok(PlayerColor::Blue),
+            other => Err(DxpError::InvalidMessage(format!("Unknown colour '{}'.", other))),
+        }
+    }
+
+    fn square(&mut self) -> Result<Square, DxpError> {
+        let number = self.number(2)?;
+        u8::try_from(number).ok().and_then(Square::new)
+            .ok_or_else(|| DxpError::InvalidMessage(format!("Invalid square {}.", number)))
+    }
+
+    fn position(&mut self) -> Result<Position, DxpError> {
+        let side_to_move = self.color()?;
+        let mut board = Board::empty();
+        for (square, c) in Square::all().zip(self.take(NUMBER_OF_SQUARES)?.chars()) {
+            let piece = match c {
+                EMPTY_SQUARE => None,
+                c if c.to_ascii_uppercase() == RED_DXP_COLOR || c.to_ascii_uppercase() == BLUE_DXP_COLOR => {
+                    let color = if c.to_ascii_uppercase() == RED_DXP_COLOR { PlayerColor::Red } else { PlayerColor::Blue };
+                    let kind = if c.is_ascii_uppercase() { PieceKind::King } else { PieceKind::Man };
+                    Some(Piece { color, kind })
+                }
+                other => return Err(DxpError::InvalidMessage(format!("Unknown piece '{}'.", other))),
+            };
+            board.set(square, piece);
+        }
+        let position = Position { board, side_to_move };
+        position.validate().map_err(|error| DxpError::InvalidMessage(error.to_string()))?;
+        Ok(position)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(number: u8) -> Square {
+        Square::new(number).unwrap()
+    }
+
+    fn assert_round_trip(text: &str, message: DxpMessage) {
+        assert_eq!(DxpMessage::parse(text), Ok(message.clone()));
+        assert_eq!(message.to_string(), text);
+    }
+
+    #[test]
+    fn game_request_round_trip() {
+        assert_round_trip(
+            "R01Checkers server                 W010050A",
+            DxpMessage::GameRequest {
+                version: 1,
+                initiator_name: "Checkers server".to_string(),
+                follower_color: PlayerColor::Blue,
+                minutes: 10,
+                moves: 50,
+                position: None,
+            },
+        );
+    }
+
+    #[test]
+    fn game_request_with_position_round_trip() {
+        let position = Position::from_fen("W:WK30:B1,2").unwrap();
+        let board = "zzeeeeeeeeeeeeeeeeeeeeeeeeeeeWee";
+        assert_round_trip(
+            &format!("R01Engine                          Z000000BW{}", board),
+            DxpMessage::GameRequest {
+                version: 1,
+                initiator_name: "Engine".to_string(),
+                follower_color: PlayerColor::Red,
+                minutes: 0,
+                moves: 0,
+                position: Some(position),
+            },
+        );
+    }
+
+    #[test]
+    fn move_round_trip() {
+        assert_round_trip("M0012111500", DxpMessage::Move { seconds: 12, from: square(11), to: square(15), captures: vec![] });
+    }
+
+    #[test]
+    fn move_with_captures_round_trip() {
+        let position = Position::from_fen("B:W15,23:B11").unwrap();
+        let message = DxpMessage::parse("M00031127021523").unwrap();
+        assert_eq!(message, DxpMessage::Move { seconds: 3, from: square(11), to: square(27), captures: vec![square(15), square(23)] });
+        let mv = message.to_move(&position).unwrap();
+        assert_eq!(DxpMessage::from_move(&mv, 3).to_string(), "M00031127021523");
+    }
+
+    #[test]
+    fn game_end_round_trip() {
+        assert_round_trip("E20", DxpMessage::GameEnd { reason: DxpGameEndReason::Draw, stop_session: false });
+        assert_round_trip("E11", DxpMessage::GameEnd { reason: DxpGameEndReason::Loss, stop_session: true });
+    }
+
+    #[test]
+    fn chat_round_trip() {
+        assert_round_trip("CGood luck!", DxpMessage::Chat("Good luck!".to_string()));
+    }
+
+    #[test]
+    fn backup_round_trip() {
+        assert_round_trip("B012W", DxpMessage::BackupRequest { move_number: 12, side_to_move: PlayerColor::Blue });
+        assert_round_trip("K1", DxpMessage::BackupAcceptance(DxpBackupAcceptance::NotSupported));
+    }
+
+    #[test]
+    fn malformed_messages_are_rejected() {
+        assert_eq!(DxpMessage::parse("X"), Err(DxpError::UnknownMessageType('X')));
+        assert_eq!(DxpMessage::parse("R02"), Err(DxpError::UnsupportedVersion(2)));
+        assert!(matches!(DxpMessage::parse("M00121115"), Err(DxpError::InvalidMessage(_))));
+        assert!(matches!(DxpMessage::parse("M0012113300"), Err(DxpError::InvalidMessage(_))));
+        assert!(matches!(DxpMessage::parse("E5"), Err(DxpError::InvalidMessage(_))));
+        assert!(matches!(DxpMessage::parse(""), Err(DxpError::InvalidMessage(_))));
+    }
+
+    #[test]
+    fn only_unlimited_thinking_time_is_offered() {
+        assert_eq!(requested_time_control(0, 0), Some(TimeControl::Unlimited));
+        assert_eq!(requested_time_control(10, 50), None);
+    }
+}
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use crate::game::board::Position;
+use crate::game::bot_settings::BotSettings;
+use crate::game::moves::Move;
+use crate::game::opening_book::OpeningBook;
+use crate::game::player_color::PlayerColor;
+use crate::game::tablebase::Tablebase;
+
+use super::evaluation::evaluate;
+use super::random::Random;
+use super::search::{Engine, SearchLimits};
+
+/// Mistakes are chosen among the moves scored by a search of this depth.
+const MISTAKE_SEARCH_DEPTH: u8 = 4;
+const MISTAKE_SEARCH_NODES: u64 = 20_000;
+
+
+/// The computer player of a game, which plays on the level and in the style of its settings.
+pub struct Bot {
+    engine: Engine,
+    opening_book: Option<Arc<OpeningBook>>,
+    settings: BotSettings,
+    random: Random,
+}
+
+impl Bot {
+    /// The bot of one game. Its evaluation errors are drawn once, so the transposition
+    ///  table keeps its scores from move to move.
+    pub fn new(settings: BotSettings, seed: u64) -> Self {
+        let mut random = Random::new(seed);
+        let mut engine = Engine::with_style(settings.style);
+        engine.set_evaluation_noise(settings.level.evaluation_noise(), random.next_u64());
+        Self {
+            engine,
+            opening_book: None,
+            settings,
+            random,
+        }
+    }
+
+    /// Lets the bot play endgames with few pieces perfectly, apart from its mistakes.
+    pub fn set_tablebase(&mut self, tablebase: Option<Arc<Tablebase>>) {
+        self.engine.set_tablebase(tablebase);
+    }
+
+    /// Lets the bot vary its openings with moves from the book instead of searching.
+    pub fn set_opening_book(&mut self, opening_book: Option<Arc<OpeningBook>>) {
+        self.opening_book = opening_book;
+    }
+
+    /// The move the bot plays, `None` if it has no legal moves.
+    pub fn choose_move(&mut self, position: &Position) -> Option<Move> {
+        if let Some(mv) = self.book_move(position) {
+            return Some(mv);
+        }
+        let level = self.settings.level;
+        let best_move = self.engine.search(position, level.search_limits()).best_move?;
+
+        if self.random.below(100) < level.mistake_in_percent() {
+            if let Some(mistake) = self.choose_mistake(position, &best_move) {
+                return Some(mistake);
+            }
+        }
+        Some(best_move)
+    }
+
+    /// A move from the opening book, `None` if the position is not in the book.
+    pub fn book_move(&mut self, position: &Position) -> Option<Move> {
+        let opening_book = self.opening_book.as_ref()?;
+        opening_book.choose_move(position, &mut self.random)
+    }
+
+    /// A worse move than the best one, which still does not lose too much.
+    fn choose_mistake(&mut self, position: &Position, best_move: &Move) -> Option<Move> {
+        let limits = SearchLimits { max_depth: MISTAKE_SEARCH_DEPTH, max_nodes: MISTAKE_SEARCH_NODES };
+        let scored_moves = self.engine.score_moves(position, limits);
+        let best_score = scored_moves.iter().map(|(_, score)| *score).max()?;
+        let candidates: Vec<Move> = scored_moves.into_iter()
+            .filter(|(mv, score)| mv != best_move && best_score - score <= self.settings.level.max_mistake_loss())
+            .map(|(mv, _)| mv)
+            .collect();
+        if candidates.is_empty() {
+            return None;
+        }
+        Some(candidates[self.random.below(candidates.len())].clone())
+    }
+}
+
+/// Whether the bot agrees to a draw offered in the position, which it does unless it is
+///  ahead. The static evaluation decides, so the offer is answered at once and the server
+///  can check the answer of an uploaded game.
+pub fn accepts_draw(position: &Position, bot_color: PlayerColor) -> bool {
+    let score = evaluate(position);
+    let bot_score = if position.side_to_move == bot_color { score } else { -score };
+    bot_score <= 0
+}
@@ -0,0 +1,63 @@
+use crate::game::board::{Board, PieceKind, Position, BOARD_SIZE};
+use crate::game::bot_settings::BotStyle;
+use crate::game::player_color::PlayerColor;
+
+
+/// Scores are in hundredths of a man, seen from the side to move.
+pub type Score = i32;
+
+/// The score of a won position, reduced by the plies it takes to win, so that faster
+///  wins are preferred.
+pub const WIN_SCORE: Score = 100_000;
+
+/// The value of the pieces and of their placement, in hundredths of a man.
+struct Weights {
+    man: Score,
+    king: Score,
+    /// Bonus per row a man has advanced towards the crowning row.
+    advancement: Score,
+    /// Bonus for men still on the own back row, which keep the opponent from crowning.
+    back_row: Score,
+    /// Bonus for kings in the centre, where they control the most squares.
+    centre_king: Score,
+}
+
+const fn weights(style: BotStyle) -> Weights {
+    match style {
+        BotStyle::Balanced => Weights { man: 100, king: 160, advancement: 3, back_row: 8, centre_king: 10 },
+        BotStyle::Aggressive => Weights { man: 100, king: 170, advancement: 6, back_row: 2, centre_king: 15 },
+        BotStyle::Defensive => Weights { man: 100, king: 150, advancement: 1, back_row: 16, centre_king: 5 },
+    }
+}
+
+
+/// A static evaluation of the position from the point of view of the side to move.
+pub fn evaluate(position: &Position) -> Score {
+    evaluate_in_style(position, BotStyle::default())
+}
+
+/// Like `evaluate`, but weighing the placement of the pieces as the playing style prefers.
+pub fn evaluate_in_style(position: &Position, style: BotStyle) -> Score {
+    let weights = weights(style);
+    let side = position.side_to_move;
+    evaluate_side(&position.board, side, &weights) - evaluate_side(&position.board, side.opponent(), &weights)
+}
+
+fn evaluate_side(board: &Board, color: PlayerColor, weights: &Weights) -> Score {
+    board.pieces_of(color).map(|(square, piece)| {
+        let rows_advanced = match color {
+            PlayerColor::Red => square.row(),
+            PlayerColor::Blue => BOARD_SIZE - 1 - square.row(),
+        } as Score;
+        match piece.kind {
+            PieceKind::Man if rows_advanced == 0 => weights.man + weights.back_row,
+            PieceKind::Man => weights.man + weights.advancement * rows_advanced,
+            PieceKind::King if is_centre(square.row()) && is_centre(square.column()) => weights.king + weights.centre_king,
+            PieceKind::King => weights.king,
+        }
+    }).sum()
+}
+
+fn is_centre(coordinate: usize) -> bool {
+    (2..BOARD_SIZE - 2).contains(&coordinate)
+}
@@ -0,0 +1,17 @@
+mod evaluation;
+mod random;
+mod transposition_table;
+mod search;
+mod bot;
+
+pub use transposition_table::hash as hash;
+pub use evaluation::evaluate as evaluate;
+pub use evaluation::evaluate_in_style as evaluate_in_style;
+pub use evaluation::Score as Score;
+pub use evaluation::WIN_SCORE as WIN_SCORE;
+pub use random::Random as Random;
+pub use search::Engine as Engine;
+pub use search::SearchLimits as SearchLimits;
+pub use search::SearchResult as SearchResult;
+pub use bot::Bot as Bot;
+pub use bot::accepts_draw as accepts_draw;
@@ -0,0 +1,353 @@
+use std::sync::Arc;
+
+use crate::game::board::{Position, NUMBER_OF_SQUARES};
+use crate::game::moves::Move;
+
+use crate::game::bot_settings::BotStyle;
+use crate::game::tablebase::{Tablebase, TablebaseValue};
+
+use super::evaluation::{evaluate_in_style, Score, WIN_SCORE};
+use super::random::splitmix64;
+use super::transposition_table::{hash, Bound, TranspositionTable};
+
+const TRANSPOSITION_TABLE_SIZE: usize = 1 << 16;
+/// Forced captures are searched beyond the nominal depth, but never deeper than this.
+const MAX_PLY: usize = 64;
+const NODES_BETWEEN_STOP_CHECKS: u64 = 1024;
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SearchLimits {
+    pub max_depth: u8,
+    /// The search stops after visiting this many positions and returns the best move of
+    ///  the last completed iteration.
+    pub max_nodes: u64,
+}
+
+impl Default for SearchLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 12,
+            max_nodes: 1_000_000,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SearchResult {
+    /// `None` if the side to move has no legal moves.
+    pub best_move: Option<Move>,
+    /// The score of the best move from the point of view of the side to move.
+    pub score: Score,
+    /// The depth of the last completed iteration.
+    pub depth: u8,
+    pub nodes: u64,
+}
+
+
+/// An alpha-beta search with iterative deepening. Moves are ordered by the best move of the
+///  previous iteration from the transposition table, killer moves and the history heuristic.
+///  The engine keeps its tables between searches, so consecutive moves of a game profit
+///  from earlier work.
+pub struct Engine {
+    table: TranspositionTable,
+    killer_moves: Vec<[Option<Move>; 2]>,
+    /// How often a quiet move from one square to another caused a cutoff, weighted by depth.
+    history: Vec<[u32; NUMBER_OF_SQUARES]>,
+    style: BotStyle,
+    /// The largest error added to every evaluation, so that weaker levels misjudge positions.
+    evaluation_noise: Score,
+    noise_seed: u64,
+    /// Exact values for endgames with few pieces, which replace the search.
+    tablebase: Option<Arc<Tablebase>>,
+    nodes: u64,
+    max_nodes: u64,
+    is_stopped: bool,
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        Engine::new()
+    }
+}
+
+impl Engine {
+    pub fn new() -> Self {
+        Self {
+            table: TranspositionTable::new(TRANSPOSITION_TABLE_SIZE),
+            killer_moves: vec![[None, None]; MAX_PLY + 1],
+            history: vec![[0; NUMBER_OF_SQUARES]; NUMBER_OF_SQUARES],
+            style: BotStyle::default(),
+            evaluation_noise: 0,
+            noise_seed: 0,
+            tablebase: None,
+            nodes: 0,
+            max_nodes: 0,
+            is_stopped: false,
+        }
+    }
+
+    pub fn with_style(style: BotStyle) -> Self {
+        Self {
+            style,
+            ..Engine::new()
+        }
+    }
+
+    /// Adds a random error of up to `noise` to every evaluation. The error of a position
+    ///  only depends on the seed, so it stays the same during one search.
+    pub fn set_evaluation_noise(&mut self, noise: Score, seed: u64) {
+        if (noise, seed) != (self.evaluation_noise, self.noise_seed) {
+            // the stored scores were computed with the old errors
+            self.table.clear();
+        }
+        self.evaluation_noise = noise;
+        self.noise_seed = seed;
+    }
+
+    pub fn set_tablebase(&mut self, tablebase: Option<Arc<Tablebase>>) {
+        self.tablebase = tablebase;
+        // the stored scores of endgames were only estimated
+        self.table.clear();
+    }
+
+    /// Forgets everything learned in earlier searches, e.g. when a new game starts.
+    pub fn clear(&mut self) {
+        self.table.clear();
+        self.killer_moves.iter_mut().for_each(|killers| *killers = [None, None]);
+        self.history.iter_mut().for_each(|row| *row = [0; NUMBER_OF_SQUARES]);
+    }
+
+    pub fn search(&mut self, position: &Position, limits: SearchLimits) -> SearchResult {
+        self.start_search(limits.max_nodes);
+
+        let mut moves = position.legal_moves();
+        let mut result = SearchResult {
+            best_move: moves.first().cloned(),
+            score: if moves.is_empty() { -WIN_SCORE } else { self.evaluate(position) },
+            depth: 0,
+            nodes: 0,
+        };
+        // there is nothing to think about with a single legal move
+        if moves.len() <= 1 {
+            return result;
+        }
+
+        for depth in 1..=limits.max_depth.max(1) {
+            // the best move so far is searched first, so a stopped iteration still has a
+            //  result at least as good as the previous one
+            if let Some(best_move) = &result.best_move {
+                if let Some(index) = moves.iter().position(|mv| mv == best_move) {
+                    let best_move = moves.remove(index);
+                    moves.insert(0, best_move);
+                }
+            }
+
+            let mut alpha = -WIN_SCORE - 1;
+            let mut best_move = None;
+            for mv in &moves {
+                let score = -self.alpha_beta(&position.after(mv), depth - 1, 1, -WIN_SCORE - 1, -alpha);
+                if self.is_stopped {
+                    break;
+                }
+                if score > alpha {
+                    alpha = score;
+                    best_move = Some(mv.clone());
+                }
+            }
+            if self.is_stopped {
+                break;
+            }
+
+            result = SearchResult { best_move, score: alpha, depth, nodes: self.nodes };
+            // a forced win or loss does not change with a deeper search
+            if alpha.abs() > WIN_SCORE - MAX_PLY as Score {
+                break;
+            }
+        }
+        result.nodes = self.nodes;
+        result
+    }
+
+    /// Scores every legal move with a search of the given depth. Unlike `search`, which only
+    ///  knows that the other moves are worse than the best one, this tells how much worse
+    ///  they are.
+    pub fn score_moves(&mut self, position: &Position, limits: SearchLimits) -> Vec<(Move, Score)> {
+        self.start_search(limits.max_nodes);
+        let depth = limits.max_depth.max(1);
+        let mut scored_moves = Vec::new();
+        for mv in position.legal_moves() {
+            let score = -self.alpha_beta(&position.after(&mv), depth - 1, 1, -WIN_SCORE - 1, WIN_SCORE + 1);
+            if self.is_stopped {
+                break;
+            }
+            scored_moves.push((mv, score));
+        }
+        scored_moves
+    }
+
+    fn start_search(&mut self, max_nodes: u64) {
+        self.nodes = 0;
+        self.max_nodes = max_nodes;
+        self.is_stopped = false;
+        self.killer_moves.iter_mut().for_each(|killers| *killers = [None, None]);
+    }
+
+    fn evaluate(&self, position: &Position) -> Score {
+        let score = evaluate_in_style(position, self.style);
+        if self.evaluation_noise == 0 {
+            return score;
+        }
+        let noise_range = 2 * self.evaluation_noise as u64 + 1;
+        let noise = (splitmix64(hash(position) ^ self.noise_seed) % noise_range) as Score - self.evaluation_noise;
+        score + noise
+    }
+
+    /// The exact score of an endgame in the tablebase, which counts wins in plies from the
+    ///  root like the search does.
+    fn probe_tablebase(&self, position: &Position, ply: usize) -> Option<Score> {
+        let tablebase = self.tablebase.as_ref()?;
+        if position.board.pieces().count() > tablebase.max_pieces() as usize {
+            return None;
+        }
+        Some(match tablebase.probe(position)? {
+            TablebaseValue::Win(distance) => WIN_SCORE - ply as Score - distance as Score,
+            TablebaseValue::Loss(distance) => -WIN_SCORE + ply as Score + distance as Score,
+            TablebaseValue::Draw => 0,
+        })
+    }
+
+    fn alpha_beta(&mut self, position: &Position, depth: u8, ply: usize, mut alpha: Score, beta: Score) -> Score {
+        self.nodes += 1;
+        if self.nodes.is_multiple_of(NODES_BETWEEN_STOP_CHECKS) && self.nodes >= self.max_nodes {
+            self.is_stopped = true;
+        }
+        if self.is_stopped {
+            return 0;
+        }
+
+        let mut moves = position.legal_moves();
+        if moves.is_empty() {
+            return -WIN_SCORE + ply as Score;
+        }
+        if let Some(score) = self.probe_tablebase(position, ply) {
+            return score;
+        }
+        // pending captures are played out, so the evaluation never misses a recapture
+        let is_quiet = !moves[0].is_capture();
+        if (depth == 0 && is_quiet) || ply >= MAX_PLY {
+            return self.evaluate(position);
+        }
+
+        let key = hash(position);
+        let mut table_move = None;
+        if let Some(entry) = self.table.probe(key) {
+            if entry.depth >= depth {
+                let score = entry.score(ply);
+                let is_usable = match entry.bound {
+                    Bound::Exact => true,
+                    Bound::Lower => score >= beta,
+                    Bound::Upper => score <= alpha,
+                };
+                if is_usable {
+                    return score;
+                }
+            }
+            table_move = entry.best_move.clone();
+        }
+
+        self.order_moves(&mut moves, table_move.as_ref(), ply);
+        let original_alpha = alpha;
+        let mut best_score = -WIN_SCORE - 1;
+        let mut best_move = None;
+        for mv in moves {
+            let score = -self.alpha_beta(&position.after(&mv), depth.saturating_sub(1), ply + 1, -beta, -alpha);
+            if self.is_stopped {
+                return 0;
+            }
+            if score > best_score {
+                best_score = score;
+                best_move = Some(mv);
+            }
+            if best_score > alpha {
+                alpha = best_score;
+            }
+            if alpha >= beta {
+                if let Some(mv) = best_move.as_ref().filter(|mv| !mv.is_capture()) {
+                    self.remember_cutoff(mv, depth, ply);
+                }
+                break;
+            }
+        }
+
+        let bound = if best_score <= original_alpha {
+            Bound::Upper
+        }
+        else if best_score >= beta {
+            Bound::Lower
+        }
+        else {
+            Bound::Exact
+        };
+        self.table.store(key, depth, best_score, ply, bound, best_move);
+        best_score
+    }
+
+    fn order_moves(&self, moves: &mut [Move], table_move: Option<&Move>, ply: usize) {
+        let killers = &self.killer_moves[ply];
+        moves.sort_by_cached_key(|mv| {
+            let priority = if Some(mv) == table_move {
+                u32::MAX
+            }
+            else if mv.is_capture() {
+                u32::MAX - 1 - (NUMBER_OF_SQUARES - mv.captures.len()) as u32
+            }
+            else if killers.iter().any(|killer| killer.as_ref() == Some(mv)) {
+                u32::MAX - 1 - NUMBER_OF_SQUARES as u32
+            }
+            else {
+                self.history[mv.from().index()][mv.to().index()].min(u32::MAX - 2 - NUMBER_OF_SQUARES as u32)
+            };
+            std::cmp::Reverse(priority)
+        });
+    }
+
+    fn remember_cutoff(&mut self, mv: &Move, depth: u8, ply: usize) {
+        let killers = &mut self.killer_moves[ply];
+        if killers[0].as_ref() != Some(mv) {
+            killers[1] = killers[0].take();
+            killers[0] = Some(mv.clone());
+        }
+        let history = &mut self.history[mv.from().index()][mv.to().index()];
+        *history = history.saturating_add(depth as u32 * depth as u32);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn perft(position: &Position, depth: u8) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        position.legal_moves().iter()
+            .map(|mv| perft(&position.after(mv), depth - 1))
+            .sum()
+    }
+
+    #[test]
+    fn perft_from_initial_position() {
+        let position = Position::initial();
+        let counts: Vec<u64> = (1..=5).map(|depth| perft(&position, depth)).collect();
+        assert_eq!(counts, vec![7, 49, 302, 1469, 7361]);
+    }
+
+    #[test]
+    fn search_returns_legal_move() {
+        let position = Position::initial();
+        let result = Engine::new().search(&position, SearchLimits { max_depth: 4, ..SearchLimits::default() });
+        assert!(result.best_move.is_some_and(|mv| position.is_legal(&mv)));
+    }
+}
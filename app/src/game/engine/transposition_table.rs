@@ -0,0 +1,123 @@
+use crate::game::board::{PieceKind, Position, NUMBER_OF_SQUARES};
+use crate::game::moves::Move;
+use crate::game::player_color::PlayerColor;
+
+use super::evaluation::{Score, WIN_SCORE};
+use super::random::splitmix64;
+
+/// Scores beyond this bound are wins or losses, which depend on the ply they are found at.
+const WIN_BOUND: Score = WIN_SCORE - 1000;
+
+const NUMBER_OF_PIECE_TYPES: usize = 4;
+const ZOBRIST_SEED: u64 = 0x2545_F491_4F6C_DD1D;
+const ZOBRIST_KEYS: [[u64; NUMBER_OF_SQUARES]; NUMBER_OF_PIECE_TYPES] = zobrist_keys();
+const SIDE_TO_MOVE_KEY: u64 = splitmix64(ZOBRIST_SEED ^ 0xB1AC_0FF5);
+
+
+/// Fixed pseudo random keys, so the hashes are the same on the server and in the browser.
+const fn zobrist_keys() -> [[u64; NUMBER_OF_SQUARES]; NUMBER_OF_PIECE_TYPES] {
+    let mut keys = [[0; NUMBER_OF_SQUARES]; NUMBER_OF_PIECE_TYPES];
+    let mut state = ZOBRIST_SEED;
+    let mut piece_type = 0;
+    while piece_type < NUMBER_OF_PIECE_TYPES {
+        let mut square = 0;
+        while square < NUMBER_OF_SQUARES {
+            state = splitmix64(state);
+            keys[piece_type][square] = state;
+            square += 1;
+        }
+        piece_type += 1;
+    }
+    keys
+}
+
+/// The Zobrist hash of the position.
+pub fn hash(position: &Position) -> u64 {
+    let mut hash = match position.side_to_move {
+        PlayerColor::Red => 0,
+        PlayerColor::Blue => SIDE_TO_MOVE_KEY,
+    };
+    for (square, piece) in position.board.pieces() {
+        let piece_type = 2 * piece.color.player_number() + match piece.kind {
+            PieceKind::Man => 0,
+            PieceKind::King => 1,
+        };
+        hash ^= ZOBRIST_KEYS[piece_type][square.index()];
+    }
+    hash
+}
+
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Bound {
+    Exact,
+    /// The score is at least the stored one, the search failed high.
+    Lower,
+    /// The score is at most the stored one, the search failed low.
+    Upper,
+}
+
+#[derive(Debug, Clone)]
+pub struct Entry {
+    key: u64,
+    pub depth: u8,
+    score: Score,
+    pub bound: Bound,
+    pub best_move: Option<Move>,
+}
+
+impl Entry {
+    /// The stored score, with wins and losses counted from the given ply again.
+    pub fn score(&self, ply: usize) -> Score {
+        match self.score {
+            score if score > WIN_BOUND => score - ply as Score,
+            score if score < -WIN_BOUND => score + ply as Score,
+            score => score,
+        }
+    }
+}
+
+
+/// Remembers searched positions, so transpositions are not searched twice and the best move
+///  of an earlier iteration is tried first. Entries are replaced when their slot is taken.
+pub struct TranspositionTable {
+    entries: Vec<Option<Entry>>,
+}
+
+impl TranspositionTable {
+    /// Creates a table with at least the given number of entries, rounded up to a power of two.
+    pub fn new(size: usize) -> Self {
+        Self {
+            entries: vec![None; size.next_power_of_two()],
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.iter_mut().for_each(|entry| *entry = None);
+    }
+
+    pub fn probe(&self, key: u64) -> Option<&Entry> {
+        self.entries[self.slot(key)].as_ref().filter(|entry| entry.key == key)
+    }
+
+    pub fn store(&mut self, key: u64, depth: u8, score: Score, ply: usize, bound: Bound, best_move: Option<Move>) {
+        let slot = self.slot(key);
+        // keep deeper results of the same position, they are more valuable
+        if let Some(entry) = &self.entries[slot] {
+            if entry.key == key && entry.depth > depth {
+                return;
+            }
+        }
+        // wins and losses are stored relative to this position instead of the root
+        let score = match score {
+            score if score > WIN_BOUND => score + ply as Score,
+            score if score < -WIN_BOUND => score - ply as Score,
+            score => score,
+        };
+        self.entries[slot] = Some(Entry { key, depth, score, bound, best_move });
+    }
+
+    fn slot(&self, key: u64) -> usize {
+        (key as usize) & (self.entries.len() - 1)
+    }
+}
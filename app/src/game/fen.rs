@@ -0,0 +1,209 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+use super::board::{Board, Piece, PieceKind, Position, Square};
+use super::player_color::PlayerColor;
+
+
+pub const MAX_PIECES_PER_PLAYER: usize = 12;
+// FEN uses the colours of the printed diagrams: black moves first and is our red player
+const RED_FEN_COLOR: char = 'B';
+const BLUE_FEN_COLOR: char = 'W';
+const KING_PREFIX: char = 'K';
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+pub enum FenError {
+    InvalidFormat(String),
+    InvalidSquare(String),
+    DuplicateSquare(u8),
+    TooManyPieces(PlayerColor),
+    NoPieces(PlayerColor),
+    ManOnPromotionRow(u8),
+    NoLegalMoves(PlayerColor),
+}
+
+impl fmt::Display for FenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FenError::InvalidFormat(s) => write!(f, "Invalid FEN: {}", s),
+            FenError::InvalidSquare(s) => write!(f, "Invalid square '{}'.", s),
+            FenError::DuplicateSquare(square) => write!(f, "Square {} is occupied twice.", square),
+            FenError::TooManyPieces(player) => write!(f, "{} has more than {} pieces.", player, MAX_PIECES_PER_PLAYER),
+            FenError::NoPieces(player) => write!(f, "{} has no pieces.", player),
+            FenError::ManOnPromotionRow(square) => write!(f, "The man on square {} should be a king.", square),
+            FenError::NoLegalMoves(player) => write!(f, "{} has no legal moves.", player),
+        }
+    }
+}
+
+
+impl Position {
+    /// Formats the position as FEN, e.g. `B:W21,22,K30:B1,2,K5`. Men are listed before
+    ///  kings and squares in ascending order, so equal positions give equal strings.
+    pub fn to_fen(&self) -> String {
+        let pieces = |color: PlayerColor| {
+            let men = self.board.pieces_of(color)
+                .filter(|(_, piece)| !piece.is_king())
+                .map(|(square, _)| square.to_string());
+            let kings = self.board.pieces_of(color)
+                .filter(|(_, piece)| piece.is_king())
+                .map(|(square, _)| format!("{}{}", KING_PREFIX, square));
+            men.chain(kings).collect::<Vec<_>>().join(",")
+        };
+        format!(
+            "{}:{}{}:{}{}",
+            fen_color(self.side_to_move),
+            BLUE_FEN_COLOR, pieces(PlayerColor::Blue),
+            RED_FEN_COLOR, pieces(PlayerColor::Red),
+        )
+    }
+
+    /// Parses a FEN string, square ranges like `B1-12` are accepted as well. Positions without
+    ///  legal moves are accepted, as games end in them, `validate` rejects them as setups.
+    pub fn from_fen(fen: &str) -> Result<Position, FenError> {
+        let fen = fen.trim().trim_end_matches('.');
+        let fields: Vec<&str> = fen.split(':').map(str::trim).collect();
+        let [side_to_move, first_pieces, second_pieces] = fields.as_slice() else {
+            return Err(FenError::InvalidFormat("Expected three fields separated by ':'.".to_string()));
+        };
+
+        let side_to_move = parse_fen_color(side_to_move)
+            .ok_or_else(|| FenError::InvalidFormat(format!("Unknown side to move '{}'.", side_to_move)))?;
+        let mut board = Board::empty();
+        let mut colors = Vec::new();
+        for field in [first_pieces, second_pieces] {
+            let mut chars = field.chars();
+            let color = chars.next().and_then(|c| parse_fen_color(&c.to_string()))
+                .ok_or_else(|| FenError::InvalidFormat(format!("Unknown piece colour in '{}'.", field)))?;
+            if colors.contains(&color) {
+                return Err(FenError::InvalidFormat(format!("Pieces of {} are listed twice.", color)));
+            }
+            colors.push(color);
+            parse_pieces(chars.as_str(), color, &mut board)?;
+        }
+
+        let position = Position { board, side_to_move };
+        position.validate_pieces()?;
+        Ok(position)
+    }
+
+    /// Checks that a game can start from the position.
+    pub fn validate(&self) -> Result<(), FenError> {
+        self.validate_pieces()?;
+        if self.legal_moves().is_empty() {
+            return Err(FenError::NoLegalMoves(self.side_to_move));
+        }
+        Ok(())
+    }
+
+    /// Checks that the position could be reached in a game.
+    fn validate_pieces(&self) -> Result<(), FenError> {
+        for color in [PlayerColor::Red, PlayerColor::Blue] {
+            let count = self.board.count(color);
+            if count == 0 {
+                return Err(FenError::NoPieces(color));
+            }
+            if count > MAX_PIECES_PER_PLAYER {
+                return Err(FenError::TooManyPieces(color));
+            }
+        }
+        match self.board.pieces().find(|(square, piece)| !piece.is_king() && square.is_promotion_square_for(piece.color)) {
+            Some((square, _)) => Err(FenError::ManOnPromotionRow(square.number())),
+            None => Ok(()),
+        }
+    }
+}
+
+fn fen_color(color: PlayerColor) -> char {
+    match color {
+        PlayerColor::Red => RED_FEN_COLOR,
+        PlayerColor::Blue => BLUE_FEN_COLOR,
+    }
+}
+
+fn parse_fen_color(text: &str) -> Option<PlayerColor> {
+    match text {
+        "B" => Some(PlayerColor::Red),
+        "W" => Some(PlayerColor::Blue),
+        _ => None,
+    }
+}
+
+fn parse_pieces(text: &str, color: PlayerColor, board: &mut Board) -> Result<(), FenError> {
+    for item in text.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+        let (kind, squares) = match item.strip_prefix(KING_PREFIX) {
+            Some(squares) => (PieceKind::King, squares),
+            None => (PieceKind::Man, item),
+        };
+        let (first, last) = squares.split_once('-').unwrap_or((squares, squares));
+        let parse_square = |text: &str| {
+            text.parse::<u8>().ok().and_then(Square::new)
+                .ok_or_else(|| FenError::InvalidSquare(item.to_string()))
+        };
+        let (first, last) = (parse_square(first)?, parse_square(last)?);
+        if first > last {
+            return Err(FenError::InvalidSquare(item.to_string()));
+        }
+        for number in first.number()..=last.number() {
+            let square = Square::new(number).expect("Square is inside a valid range.");
+            if board.get(square).is_some() {
+                return Err(FenError::DuplicateSquare(number));
+            }
+            board.set(square, Some(Piece { color, kind }));
+        }
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initial_position_round_trips() {
+        let position = Position::initial();
+        assert_eq!(Position::from_fen(&position.to_fen()), Ok(position));
+    }
+
+    #[test]
+    fn position_with_kings_round_trips() {
+        let fen = "W:W21,22,K5:B1,2,K30";
+        let position = Position::from_fen(fen).unwrap();
+        assert_eq!(position.to_fen(), fen);
+        assert_eq!(position.side_to_move, PlayerColor::Blue);
+    }
+
+    #[test]
+    fn square_ranges_and_order_are_accepted() {
+        let position = Position::from_fen("B:B1-12:W21-32.").unwrap();
+        assert_eq!(position, Position::initial());
+        assert_eq!(Position::from_fen("B:W32,K9,21:B2,1"), Position::from_fen("B:WK9,21,32:B1,2"));
+    }
+
+    #[test]
+    fn malformed_fen_is_rejected() {
+        for fen in ["", ":", "B", "B:W21", "B:W21:B1:W22", "X:W21:B1", "B:X21:B1", "B:W21:W1", "B:W:B", "B:W21-:B1", "B:W-21:B1", "B:W21-1:B1", "B:Wä:B1", "B:WK:B1"] {
+            assert!(Position::from_fen(fen).is_err(), "{}", fen);
+        }
+    }
+
+    #[test]
+    fn impossible_positions_are_rejected() {
+        assert_eq!(Position::from_fen("B:W0:B1"), Err(FenError::InvalidSquare("0".to_string())));
+        assert_eq!(Position::from_fen("B:W33:B1"), Err(FenError::InvalidSquare("33".to_string())));
+        assert_eq!(Position::from_fen("B:W21:B21"), Err(FenError::DuplicateSquare(21)));
+        assert_eq!(Position::from_fen("B:W1-13:B30"), Err(FenError::TooManyPieces(PlayerColor::Blue)));
+        assert_eq!(Position::from_fen("B:W21:B"), Err(FenError::NoPieces(PlayerColor::Red)));
+        assert_eq!(Position::from_fen("B:W1:B21"), Err(FenError::ManOnPromotionRow(1)));
+    }
+
+    #[test]
+    fn setups_without_legal_moves_are_rejected() {
+        // the red man on 1 is blocked by the men on 5 and 6, which cannot be jumped
+        let position = Position::from_fen("B:W5,6,10:B1").unwrap();
+        assert_eq!(position.validate(), Err(FenError::NoLegalMoves(PlayerColor::Red)));
+        assert!(Position::from_fen("W:W5,6,10:B1").unwrap().validate().is_ok());
+    }
+}
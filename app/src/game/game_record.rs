@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::board::Position;
+use super::game_result::GameResult;
+use super::game_settings::GameSettings;
+use super::moves::Move;
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+pub struct RecordedMove {
+    pub mv: Move,
+    pub comment: Option<String>,
+}
+
+impl RecordedMove {
+    pub fn new(mv: Move) -> Self {
+        Self {
+            mv,
+            comment: None,
+        }
+    }
+}
+
+
+/// Everything needed to archive or replay a game.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub struct GameRecord {
+    pub game_id: Uuid,
+    pub settings: GameSettings,
+    pub created_at: DateTime<Utc>,
+    /// Names of the players, indexed by the player number.
+    pub player_names: Vec<Option<String>>,
+    pub initial_position: Position,
+    pub moves: Vec<RecordedMove>,
+    pub result: Option<GameResult>,
+}
+
+impl GameRecord {
+    /// The position after all recorded moves.
+    pub fn position(&self) -> Position {
+        self.moves.iter().fold(self.initial_position, |position, recorded_move| position.after(&recorded_move.mv))
+    }
+}
@@ -0,0 +1,52 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+use super::player_color::PlayerColor;
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum WinReason {
+    Timeout,
+    Abandonment,
+    /// The opponent has no pieces or cannot move any more.
+    NoMovesLeft,
+    /// The endgame tablebase proves that the winner cannot be stopped.
+    Adjudication,
+    /// The opponent gave up, e.g. an external engine ending the game with a loss.
+    Resignation,
+    /// The result is known, but not how it was reached, e.g. for imported games.
+    Unspecified,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum DrawReason {
+    Abandonment,
+    /// The endgame tablebase proves that neither player can win.
+    Adjudication,
+    /// Both players agreed to the draw, e.g. the bot accepting an offer in an offline game.
+    Agreement,
+    Unspecified,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum GameResult {
+    Win { winner: PlayerColor, reason: WinReason },
+    Draw { reason: DrawReason },
+}
+
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameResult::Win { winner, reason: WinReason::Timeout } => write!(f, "{} wins on time", winner),
+            GameResult::Win { winner, reason: WinReason::Abandonment } => write!(f, "{} wins by abandonment", winner),
+            GameResult::Win { winner, reason: WinReason::NoMovesLeft } => write!(f, "{} wins, the opponent cannot move", winner),
+            GameResult::Win { winner, reason: WinReason::Adjudication } => write!(f, "{} wins by adjudication", winner),
+            GameResult::Win { winner, reason: WinReason::Resignation } => write!(f, "{} wins by resignation", winner),
+            GameResult::Win { winner, reason: WinReason::Unspecified } => write!(f, "{} wins", winner),
+            GameResult::Draw { reason: DrawReason::Abandonment } => write!(f, "Draw by abandonment"),
+            GameResult::Draw { reason: DrawReason::Adjudication } => write!(f, "Draw by adjudication"),
+            GameResult::Draw { reason: DrawReason::Agreement } => write!(f, "Draw by agreement"),
+            GameResult::Draw { reason: DrawReason::Unspecified } => write!(f, "Draw"),
+        }
+    }
+}
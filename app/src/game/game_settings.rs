@@ -0,0 +1,104 @@
+use core::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::time_control::TimeControl;
+use super::abandonment::AbandonmentRules;
+use super::ballot::BallotError;
+use super::board::Position;
+use super::fen::FenError;
+use super::handicap::{Handicap, MAX_HANDICAP_PIECES, TIME_ODDS_IN_PERCENT};
+use super::opening::Opening;
+use super::bot_settings::BotSettings;
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+pub enum GameSettingsError {
+    UnknownError(String),
+    InvalidTimeControl,
+    InvalidTimeOdds,
+    InvalidHandicap,
+    InvalidAbandonmentRules,
+    UnknownBallot(usize),
+    InvalidPosition(FenError),
+}
+
+impl fmt::Display for GameSettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameSettingsError::UnknownError(s) => write!(f, "{}", s),
+            GameSettingsError::InvalidTimeControl => write!(f, "Invalid time control."),
+            GameSettingsError::InvalidTimeOdds => write!(f, "Invalid time odds."),
+            GameSettingsError::InvalidHandicap => write!(f, "At most {} men can be removed or crowned.", MAX_HANDICAP_PIECES),
+            GameSettingsError::InvalidAbandonmentRules => write!(f, "Invalid abandonment periods."),
+            GameSettingsError::UnknownBallot(number) => write!(f, "{}", BallotError::UnknownBallot(*number)),
+            GameSettingsError::InvalidPosition(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl FromStr for GameSettingsError {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(GameSettingsError::UnknownError(s.to_string()))
+    }
+}
+
+impl From<BallotError> for GameSettingsError {
+    fn from(error: BallotError) -> Self {
+        match error {
+            BallotError::UnknownBallot(number) => GameSettingsError::UnknownBallot(number),
+        }
+    }
+}
+
+
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub struct GameSettings {
+    pub time_control: TimeControl,
+    pub abandonment: AbandonmentRules,
+    pub handicap: Handicap,
+    pub opening: Opening,
+    /// Level and style of the computer opponent, `None` if none was chosen at creation.
+    #[serde(default)]
+    pub bot: Option<BotSettings>,
+    /// The game this game is the return game of, played with the same ballot and reversed colours.
+    pub return_game_of: Option<Uuid>,
+}
+
+impl GameSettings {
+    pub fn initial_position(&self) -> Result<Position, BallotError> {
+        let mut position = match &self.opening {
+            Opening::Ballot(ballot) => ballot.position().ok_or(BallotError::UnknownBallot(ballot.number))?,
+            Opening::Position(position) => *position,
+            _ => Position::initial(),
+        };
+        self.handicap.apply_to_position(&mut position);
+        Ok(position)
+    }
+
+    /// Checks that the settings are among the ones offered when creating a game, as they
+    ///  come from the client and the clock computes its deadlines from them.
+    pub fn validate(&self) -> Result<(), GameSettingsError> {
+        if !TimeControl::all().contains(&self.time_control) {
+            return Err(GameSettingsError::InvalidTimeControl);
+        }
+        if !TIME_ODDS_IN_PERCENT.contains(&self.handicap.time_odds_in_percent) {
+            return Err(GameSettingsError::InvalidTimeOdds);
+        }
+        if self.handicap.removed_men > MAX_HANDICAP_PIECES || self.handicap.extra_kings > MAX_HANDICAP_PIECES {
+            return Err(GameSettingsError::InvalidHandicap);
+        }
+        if !self.abandonment.is_valid() {
+            return Err(GameSettingsError::InvalidAbandonmentRules);
+        }
+        if let Opening::Ballot(ballot) = &self.opening {
+            if !ballot.is_in_deck() {
+                return Err(GameSettingsError::UnknownBallot(ballot.number));
+            }
+        }
+        self.initial_position()?.validate().map_err(GameSettingsError::InvalidPosition)
+    }
+}
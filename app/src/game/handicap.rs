@@ -0,0 +1,87 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+use super::board::{Piece, PieceKind, Position, Square};
+use super::player_color::PlayerColor;
+
+
+pub const MAX_HANDICAP_PIECES: usize = 4;
+pub const TIME_ODDS_IN_PERCENT: [u32; 4] = [100, 75, 50, 25];
+
+
+/// Handicap for teaching games. The stronger player gives away men from the own back rank,
+///  men on the back rank of the weaker player are crowned and the stronger player may get
+///  only a fraction of the time per move.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Handicap {
+    pub stronger_player: PlayerColor,
+    pub removed_men: usize,
+    pub extra_kings: usize,
+    pub time_odds_in_percent: u32,
+}
+
+impl Default for Handicap {
+    fn default() -> Self {
+        Self {
+            stronger_player: PlayerColor::Red,
+            removed_men: 0,
+            extra_kings: 0,
+            time_odds_in_percent: 100,
+        }
+    }
+}
+
+impl Handicap {
+    pub fn is_none(&self) -> bool {
+        self.removed_men == 0 && self.extra_kings == 0 && self.time_odds_in_percent == 100
+    }
+
+    pub fn apply_to_position(&self, position: &mut Position) {
+        let weaker_player = self.stronger_player.opponent();
+        back_rank_squares(self.stronger_player)
+            .filter(|square| position.board.get(*square) == Some(Piece::man(self.stronger_player)))
+            .take(self.removed_men.min(MAX_HANDICAP_PIECES))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .for_each(|square| position.board.set(square, None));
+        back_rank_squares(weaker_player)
+            .filter(|square| position.board.get(*square) == Some(Piece::man(weaker_player)))
+            .take(self.extra_kings.min(MAX_HANDICAP_PIECES))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .for_each(|square| position.board.set(square, Some(Piece { color: weaker_player, kind: PieceKind::King })));
+    }
+
+    /// Factor for the time per move of each player, indexed by the player number.
+    pub fn time_odds(&self) -> [u32; 2] {
+        let mut time_odds = [100; 2];
+        time_odds[self.stronger_player.player_number()] = self.time_odds_in_percent;
+        time_odds
+    }
+}
+
+impl fmt::Display for Handicap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_none() {
+            return write!(f, "No handicap");
+        }
+        let mut odds = Vec::new();
+        if self.removed_men > 0 {
+            odds.push(format!("{} {}", self.removed_men, if self.removed_men == 1 { "man" } else { "men" }));
+        }
+        if self.extra_kings > 0 {
+            odds.push(format!("{} {}", self.extra_kings, if self.extra_kings == 1 { "king" } else { "kings" }));
+        }
+        if self.time_odds_in_percent != 100 {
+            odds.push(format!("{}% time", self.time_odds_in_percent));
+        }
+        write!(f, "{} gives odds: {}", self.stronger_player, odds.join(", "))
+    }
+}
+
+fn back_rank_squares(color: PlayerColor) -> Box<dyn Iterator<Item = Square>> {
+    match color {
+        PlayerColor::Red => Box::new(Square::all()),
+        PlayerColor::Blue => Box::new(Square::all().rev()),
+    }
+}
@@ -0,0 +1,395 @@
+use core::fmt;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+
+use super::board::{Position, Square};
+use super::engine::{Score, WIN_SCORE};
+use super::moves::Move;
+use super::player_color::PlayerColor;
+
+// Hub names the colours like FEN: black moves first and is our red player.
+const RED_HUB_COLOR: char = 'B';
+const BLUE_HUB_COLOR: char = 'W';
+const EMPTY_SQUARE: char = 'e';
+const QUIET_MOVE_SEPARATOR: char = '-';
+const CAPTURE_SEPARATOR: char = 'x';
+/// Engines playing several variants are told with this parameter to play English checkers.
+pub const VARIANT_PARAM: &str = "variant";
+pub const HUB_VARIANT: &str = "english";
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+pub enum HubError {
+    InvalidMessage(String),
+    /// The engine answered with a move which is not legal in the position.
+    IllegalMove(String),
+    /// The engine reported an error.
+    EngineError(String),
+    /// The engine did not answer in time.
+    Timeout,
+    EngineStopped,
+    Io(String),
+}
+
+impl fmt::Display for HubError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HubError::InvalidMessage(message) => write!(f, "Invalid Hub message: {}", message),
+            HubError::IllegalMove(mv) => write!(f, "Illegal move {}.", mv),
+            HubError::EngineError(message) => write!(f, "The engine reported an error: {}", message),
+            HubError::Timeout => write!(f, "The engine did not answer in time."),
+            HubError::EngineStopped => write!(f, "The engine stopped."),
+            HubError::Io(error) => write!(f, "Engine process error: {}", error),
+        }
+    }
+}
+
+
+/// The limits of a search, the engine stops at the first one reached. With a time control
+///  of `time` for a number of `moves` the engine plans its time itself, from the clock it
+///  gets before each search.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct HubLevel {
+    pub depth: Option<u8>,
+    pub nodes: Option<u64>,
+    pub move_time: Option<Duration>,
+    pub moves: Option<u32>,
+    pub time: Option<Duration>,
+}
+
+
+/// The remaining time of the side to move and of its opponent.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct HubClock {
+    pub my_time: Duration,
+    pub opponent_time: Duration,
+}
+
+
+/// A line the server sends to a Hub engine.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum HubCommand {
+    /// Asks for the engine's name and parameters, answered with `wait`.
+    Hub,
+    /// Lets the engine load its data, answered with `ready`.
+    Init,
+    SetParam { name: String, value: String },
+    /// Clears what the engine learned in the previous game.
+    NewGame,
+    Position(Position),
+    Level(HubLevel),
+    Time(HubClock),
+    /// Starts a search of the position, answered with `done`.
+    Go,
+    Stop,
+    /// Answered with `pong` once the engine handled all commands before.
+    Ping,
+    Quit,
+}
+
+impl fmt::Display for HubCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HubCommand::Hub => write!(f, "hub"),
+            HubCommand::Init => write!(f, "init"),
+            HubCommand::SetParam { name, value } => write!(f, "set-param name={} value={}", quote(name), quote(value)),
+            HubCommand::NewGame => write!(f, "new-game"),
+            HubCommand::Position(position) => write!(f, "pos pos={}", format_hub_position(position)),
+            HubCommand::Level(level) => {
+                write!(f, "level")?;
+                if let Some(depth) = level.depth {
+                    write!(f, " depth={}", depth)?;
+                }
+                if let Some(nodes) = level.nodes {
+                    write!(f, " nodes={}", nodes)?;
+                }
+                if let Some(move_time) = level.move_time {
+                    write!(f, " move-time={:.3}", move_time.as_secs_f64())?;
+                }
+                if let Some(moves) = level.moves {
+                    write!(f, " moves={}", moves)?;
+                }
+                if let Some(time) = level.time {
+                    write!(f, " time={:.3}", time.as_secs_f64())?;
+                }
+                // without any limit the engine searches until it is stopped
+                if *level == HubLevel::default() {
+                    write!(f, " infinite")?;
+                }
+                Ok(())
+            }
+            HubCommand::Time(clock) => {
+                write!(f, "time my={:.3} opp={:.3}", clock.my_time.as_secs_f64(), clock.opponent_time.as_secs_f64())
+            }
+            HubCommand::Go => write!(f, "go think"),
+            HubCommand::Stop => write!(f, "stop"),
+            HubCommand::Ping => write!(f, "ping"),
+            HubCommand::Quit => write!(f, "quit"),
+        }
+    }
+}
+
+
+/// The progress of a search, the fields the engine did not send are `None`.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone)]
+pub struct HubSearchInfo {
+    pub depth: Option<u8>,
+    /// From the point of view of the side to move.
+    pub score: Option<Score>,
+    pub nodes: Option<u64>,
+    /// The expected continuation in Hub move notation.
+    pub principal_variation: Vec<String>,
+}
+
+
+/// A line a Hub engine sends to the server.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum HubResponse {
+    Id { name: Option<String>, version: Option<String> },
+    Param { name: String },
+    Wait,
+    Ready,
+    Pong,
+    Info(HubSearchInfo),
+    /// The end of a search with the best move in Hub move notation, `None` without legal moves.
+    Done { best_move: Option<String> },
+    Error(String),
+    /// Lines of newer protocol versions, which can be skipped.
+    Unknown(String),
+}
+
+impl HubResponse {
+    pub fn parse(line: &str) -> Result<Self, HubError> {
+        let mut tokens = tokenize(line)?.into_iter();
+        let Some((command, _)) = tokens.next() else {
+            return Err(HubError::InvalidMessage("The line is empty.".to_string()));
+        };
+        let arguments: Vec<(String, Option<String>)> = tokens.collect();
+        let argument = |name: &str| arguments.iter()
+            .find(|(key, _)| key == name)
+            .and_then(|(_, value)| value.clone());
+        let number = |name: &str| argument(name).and_then(|value| value.parse::<f64>().ok());
+
+        let response = match command.as_str() {
+            "id" => HubResponse::Id { name: argument("name"), version: argument("version") },
+            "param" => HubResponse::Param { name: argument("name").unwrap_or_default() },
+            "wait" => HubResponse::Wait,
+            "ready" => HubResponse::Ready,
+            "pong" => HubResponse::Pong,
+            "info" => HubResponse::Info(HubSearchInfo {
+                depth: number("depth").map(|depth| depth.clamp(0.0, u8::MAX as f64) as u8),
+                // scores are sent in men
+                score: number("score").map(|score| ((score * 100.0).round() as Score).clamp(-WIN_SCORE, WIN_SCORE)),
+                nodes: number("nodes").map(|nodes| nodes.max(0.0) as u64),
+                principal_variation: argument("pv").map(|pv| pv.split_whitespace().map(str::to_string).collect()).unwrap_or_default(),
+            }),
+            "done" => HubResponse::Done { best_move: argument("move").filter(|mv| !mv.is_empty()) },
+            "error" => HubResponse::Error(argument("message").unwrap_or_else(|| line.to_string())),
+            _ => HubResponse::Unknown(line.to_string()),
+        };
+        Ok(response)
+    }
+}
+
+/// Splits a line into words and `key=value` pairs, values with spaces are quoted.
+fn tokenize(line: &str) -> Result<Vec<(String, Option<String>)>, HubError> {
+    let mut tokens = Vec::new();
+    let mut chars = line.trim().chars().peekable();
+    while let Some(&first) = chars.peek() {
+        if first.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let mut key = String::new();
+        while let Some(c) = chars.next_if(|c| *c != '=' && !c.is_whitespace()) {
+            key.push(c);
+        }
+        if chars.next_if_eq(&'=').is_none() {
+            tokens.push((key, None));
+            continue;
+        }
+        let mut value = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some(c) => value.push(c),
+                    None => return Err(HubError::InvalidMessage(format!("Unterminated quote in '{}'.", line))),
+                }
+            }
+        }
+        else {
+            while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+                value.push(c);
+            }
+        }
+        tokens.push((key, Some(value)));
+    }
+    Ok(tokens)
+}
+
+fn quote(value: &str) -> String {
+    if value.is_empty() || value.contains(char::is_whitespace) {
+        format!("\"{}\"", value.replace('"', ""))
+    }
+    else {
+        value.to_string()
+    }
+}
+
+/// The side to move followed by one character per square: `e` for empty squares, `b` and
+///  `w` for men, `B` and `W` for kings. Our board has 32 squares instead of the 50 of
+///  international draughts.
+pub fn format_hub_position(position: &Position) -> String {
+    let squares: String = Square::all().map(|square| match position.board.get(square) {
+        None => EMPTY_SQUARE,
+        Some(piece) => {
+            let color = hub_color(piece.color);
+            if piece.is_king() { color } else { color.to_ascii_lowercase() }
+        }
+    }).collect();
+    format!("{}{}", hub_color(position.side_to_move), squares)
+}
+
+fn hub_color(color: PlayerColor) -> char {
+    match color {
+        PlayerColor::Red => RED_HUB_COLOR,
+        PlayerColor::Blue => BLUE_HUB_COLOR,
+    }
+}
+
+/// Quiet moves are written `from-to`, captures `fromxto` followed by the captured squares,
+///  e.g. `14x23x18`. Unlike in game notation the landing squares in between are left out.
+pub fn format_hub_move(mv: &Move) -> String {
+    if !mv.is_capture() {
+        return format!("{}{}{}", mv.from(), QUIET_MOVE_SEPARATOR, mv.to());
+    }
+    let mut captures = mv.captures.clone();
+    captures.sort();
+    std::iter::once(mv.from()).chain(std::iter::once(mv.to())).chain(captures)
+        .map(|square| square.to_string())
+        .collect::<Vec<_>>()
+        .join(&CAPTURE_SEPARATOR.to_string())
+}
+
+/// The legal move in the position written in Hub move notation.
+pub fn parse_hub_move(position: &Position, notation: &str) -> Result<Move, HubError> {
+    let illegal_move = || HubError::IllegalMove(notation.to_string());
+    let squares = notation.trim()
+        .split([QUIET_MOVE_SEPARATOR, CAPTURE_SEPARATOR])
+        .map(|square| square.parse::<u8>().ok().and_then(Square::new))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(illegal_move)?;
+    let [from, to, captures @ ..] = squares.as_slice() else {
+        return Err(illegal_move());
+    };
+    let mut captures = captures.to_vec();
+    captures.sort();
+    let is_capture = notation.contains(CAPTURE_SEPARATOR);
+    let candidates: Vec<Move> = position.find_moves(*from, *to).into_iter()
+        .filter(|mv| mv.is_capture() == is_capture)
+        .filter(|mv| {
+            let mut move_captures = mv.captures.clone();
+            move_captures.sort();
+            captures.is_empty() || move_captures == captures
+        })
+        .collect();
+    candidates.into_iter().next().ok_or_else(illegal_move)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commands_are_formatted() {
+        assert_eq!(HubCommand::Hub.to_string(), "hub");
+        assert_eq!(HubCommand::Init.to_string(), "init");
+        assert_eq!(
+            HubCommand::SetParam { name: VARIANT_PARAM.to_string(), value: HUB_VARIANT.to_string() }.to_string(),
+            "set-param name=variant value=english",
+        );
+        assert_eq!(
+            HubCommand::SetParam { name: "book".to_string(), value: "my book".to_string() }.to_string(),
+            "set-param name=book value=\"my book\"",
+        );
+        assert_eq!(HubCommand::NewGame.to_string(), "new-game");
+        assert_eq!(HubCommand::Go.to_string(), "go think");
+        assert_eq!(HubCommand::Ping.to_string(), "ping");
+        assert_eq!(HubCommand::Quit.to_string(), "quit");
+    }
+
+    #[test]
+    fn position_is_formatted() {
+        let position = Position::from_fen("W:WK30:B1,2").unwrap();
+        // the men on 1 and 2, the king on 30
+        assert_eq!(HubCommand::Position(position).to_string(), format!("pos pos=Wbb{}Wee", "e".repeat(27)));
+    }
+
+    #[test]
+    fn level_and_time_are_formatted() {
+        let level = HubLevel {
+            depth: Some(12),
+            nodes: Some(100000),
+            move_time: None,
+            moves: Some(1),
+            time: Some(Duration::from_millis(2500)),
+        };
+        assert_eq!(HubCommand::Level(level).to_string(), "level depth=12 nodes=100000 moves=1 time=2.500");
+        assert_eq!(HubCommand::Level(HubLevel::default()).to_string(), "level infinite");
+        let clock = HubClock { my_time: Duration::from_secs(60), opponent_time: Duration::from_millis(59250) };
+        assert_eq!(HubCommand::Time(clock).to_string(), "time my=60.000 opp=59.250");
+    }
+
+    #[test]
+    fn responses_are_parsed() {
+        assert_eq!(
+            HubResponse::parse("id name=Scan version=\"3.1 beta\""),
+            Ok(HubResponse::Id { name: Some("Scan".to_string()), version: Some("3.1 beta".to_string()) }),
+        );
+        assert_eq!(HubResponse::parse("param name=variant value=normal type=enum"), Ok(HubResponse::Param { name: "variant".to_string() }));
+        assert_eq!(HubResponse::parse("wait"), Ok(HubResponse::Wait));
+        assert_eq!(HubResponse::parse("  ready  "), Ok(HubResponse::Ready));
+        assert_eq!(HubResponse::parse("pong"), Ok(HubResponse::Pong));
+        assert_eq!(HubResponse::parse("done move=11-15 ponder=22-18"), Ok(HubResponse::Done { best_move: Some("11-15".to_string()) }));
+        assert_eq!(HubResponse::parse("done move=\"\""), Ok(HubResponse::Done { best_move: None }));
+        assert_eq!(HubResponse::parse("done"), Ok(HubResponse::Done { best_move: None }));
+        assert_eq!(HubResponse::parse("error message=\"unknown variant\""), Ok(HubResponse::Error("unknown variant".to_string())));
+        assert_eq!(HubResponse::parse("error"), Ok(HubResponse::Error("error".to_string())));
+        assert_eq!(HubResponse::parse("thinking hard"), Ok(HubResponse::Unknown("thinking hard".to_string())));
+    }
+
+    #[test]
+    fn search_info_is_parsed() {
+        assert_eq!(
+            HubResponse::parse("info depth=9 score=-0.25 nodes=12345 pv=\"11-15 22-18\""),
+            Ok(HubResponse::Info(HubSearchInfo {
+                depth: Some(9),
+                score: Some(-25),
+                nodes: Some(12345),
+                principal_variation: vec!["11-15".to_string(), "22-18".to_string()],
+            })),
+        );
+        assert_eq!(HubResponse::parse("info depth=x"), Ok(HubResponse::Info(HubSearchInfo::default())));
+    }
+
+    #[test]
+    fn malformed_responses_are_rejected() {
+        assert!(matches!(HubResponse::parse(""), Err(HubError::InvalidMessage(_))));
+        assert!(matches!(HubResponse::parse("   "), Err(HubError::InvalidMessage(_))));
+        assert!(matches!(HubResponse::parse("id name=\"Scan"), Err(HubError::InvalidMessage(_))));
+    }
+
+    #[test]
+    fn moves_round_trip() {
+        let position = Position::from_fen("B:W15,23:B11").unwrap();
+        let mv = position.legal_moves().remove(0);
+        assert_eq!(format_hub_move(&mv), "11x27x15x23");
+        assert_eq!(parse_hub_move(&position, "11x27x23x15"), Ok(mv.clone()));
+        assert_eq!(parse_hub_move(&position, "11x27"), Ok(mv));
+        assert_eq!(parse_hub_move(&position, "11-27"), Err(HubError::IllegalMove("11-27".to_string())));
+        assert_eq!(parse_hub_move(&Position::initial(), "11-15").map(|mv| format_hub_move(&mv)), Ok("11-15".to_string()));
+        assert!(parse_hub_move(&Position::initial(), "11-x").is_err());
+    }
+}
@@ -0,0 +1,25 @@
+pub mod player_color;
+pub mod time_control;
+pub mod clock;
+pub mod game_result;
+pub mod game_settings;
+pub mod abandonment;
+pub mod adjournment;
+pub mod board;
+pub mod handicap;
+pub mod game_record;
+pub mod moves;
+pub mod ballot;
+pub mod opening;
+pub mod notation;
+pub mod pdn;
+pub mod fen;
+pub mod conditional_moves;
+pub mod engine;
+pub mod bot_settings;
+pub mod tablebase;
+pub mod opening_book;
+pub mod analysis;
+pub mod variation_tree;
+pub mod dxp;
+pub mod hub;
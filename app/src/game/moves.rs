@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+use super::board::{Board, Piece, PieceKind, Position, Square};
+
+
+/// A move in English checkers. Captures are mandatory, men move and capture only forward,
+///  kings one square in every direction. A man reaching the last row is crowned and
+///  the move ends there.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+pub struct Move {
+    /// The start square followed by every square the piece lands on.
+    pub path: Vec<Square>,
+    /// The squares of all jumped pieces, in the order they are jumped.
+    pub captures: Vec<Square>,
+}
+
+impl Move {
+    pub fn from(&self) -> Square {
+        *self.path.first().expect("A move has at least a start square.")
+    }
+
+    pub fn to(&self) -> Square {
+        *self.path.last().expect("A move has at least a start square.")
+    }
+
+    pub fn is_capture(&self) -> bool {
+        !self.captures.is_empty()
+    }
+}
+
+
+impl Position {
+    pub fn legal_moves(&self) -> Vec<Move> {
+        let captures = self.captures();
+        if !captures.is_empty() {
+            return captures;
+        }
+
+        let mut moves = Vec::new();
+        for (from, piece) in self.board.pieces_of(self.side_to_move) {
+            for &row_step in piece.row_steps() {
+                for column_step in [-1, 1] {
+                    if let Some(to) = from.neighbour(row_step, column_step) {
+                        if self.board.get(to).is_none() {
+                            moves.push(Move { path: vec![from, to], captures: Vec::new() });
+                        }
+                    }
+                }
+            }
+        }
+        moves
+    }
+
+    pub fn captures(&self) -> Vec<Move> {
+        let mut moves = Vec::new();
+        for (from, piece) in self.board.pieces_of(self.side_to_move) {
+            // the moving piece leaves its square, so a jump may end on the start square again
+            let mut board = self.board;
+            board.set(from, None);
+            add_captures(&board, piece, &mut vec![from], &mut Vec::new(), &mut moves);
+        }
+        moves
+    }
+
+    pub fn is_legal(&self, mv: &Move) -> bool {
+        self.legal_moves().contains(mv)
+    }
+
+    /// Plays the move without checking it, use `is_legal` for untrusted moves.
+    pub fn play(&mut self, mv: &Move) {
+        let piece = self.board.get(mv.from()).expect("The moving piece must exist.");
+        self.board.set(mv.from(), None);
+        for captured in &mv.captures {
+            self.board.set(*captured, None);
+        }
+        let piece = if mv.to().is_promotion_square_for(piece.color) {
+            Piece { color: piece.color, kind: PieceKind::King }
+        }
+        else {
+            piece
+        };
+        self.board.set(mv.to(), Some(piece));
+        self.side_to_move = self.side_to_move.opponent();
+    }
+
+    pub fn after(&self, mv: &Move) -> Position {
+        let mut position = *self;
+        position.play(mv);
+        position
+    }
+
+    /// Finds the legal moves from one square to another, multiple moves are possible
+    ///  when different capture paths share start and end square.
+    pub fn find_moves(&self, from: Square, to: Square) -> Vec<Move> {
+        self.legal_moves().into_iter()
+            .filter(|mv| mv.from() == from && mv.to() == to)
+            .collect()
+    }
+}
+
+fn add_captures(board: &Board, piece: Piece, path: &mut Vec<Square>, captures: &mut Vec<Square>, moves: &mut Vec<Move>) {
+    let from = *path.last().expect("The capture path has at least a start square.");
+    let mut can_continue = false;
+    for &row_step in piece.row_steps() {
+        for column_step in [-1, 1] {
+            let (Some(over), Some(to)) = (from.neighbour(row_step, column_step), from.neighbour(2 * row_step, 2 * column_step)) else {
+                continue;
+            };
+            let is_opponent = board.get(over).is_some_and(|other| other.color != piece.color);
+            if !is_opponent || captures.contains(&over) || board.get(to).is_some() {
+                continue;
+            }
+
+            can_continue = true;
+            path.push(to);
+            captures.push(over);
+            if !piece.is_king() && to.is_promotion_square_for(piece.color) {
+                moves.push(Move { path: path.clone(), captures: captures.clone() });
+            }
+            else {
+                add_captures(board, piece, path, captures, moves);
+            }
+            path.pop();
+            captures.pop();
+        }
+    }
+
+    if !can_continue && !captures.is_empty() {
+        moves.push(Move { path: path.clone(), captures: captures.clone() });
+    }
+}
@@ -0,0 +1,179 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+use super::board::{Position, Square};
+use super::moves::Move;
+
+
+const MOVE_SEPARATOR: char = '-';
+const CAPTURE_SEPARATOR: char = 'x';
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+pub enum NotationError {
+    InvalidSyntax(String),
+    IllegalMove(String),
+    AmbiguousMove { notation: String, candidates: Vec<String> },
+}
+
+impl fmt::Display for NotationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NotationError::InvalidSyntax(notation) => write!(f, "'{}' is not a move in numeric notation.", notation),
+            NotationError::IllegalMove(notation) => write!(f, "'{}' is not a legal move.", notation),
+            NotationError::AmbiguousMove { notation, candidates } => {
+                write!(f, "'{}' is ambiguous, use one of {}.", notation, candidates.join(", "))
+            }
+        }
+    }
+}
+
+
+/// Formats a move in numeric notation: `11-15` for a move, `22x15` for a capture. If
+///  another capture shares start and end square, every landing square is given: `15x22x29`.
+pub fn format_move(position: &Position, mv: &Move) -> String {
+    let is_ambiguous = position.find_moves(mv.from(), mv.to()).len() > 1;
+    if is_ambiguous {
+        format_move_path(mv)
+    }
+    else {
+        format_squares(&[mv.from(), mv.to()], mv.is_capture())
+    }
+}
+
+/// Formats a move with every landing square, which is never ambiguous.
+pub fn format_move_path(mv: &Move) -> String {
+    format_squares(&mv.path, mv.is_capture())
+}
+
+/// Parses a move in numeric notation. The short form with start and end square only is
+///  accepted as long as no other legal move shares these squares. Either separator is taken
+///  for moves and captures alike, as captures are compulsory and never share their squares
+///  with a move.
+pub fn parse_move(position: &Position, notation: &str) -> Result<Move, NotationError> {
+    let notation = notation.trim();
+    let squares = notation.split([MOVE_SEPARATOR, CAPTURE_SEPARATOR])
+        .map(|square| square.parse::<u8>().ok().and_then(Square::new))
+        .collect::<Option<Vec<_>>>()
+        .filter(|squares| squares.len() >= 2)
+        .ok_or_else(|| NotationError::InvalidSyntax(notation.to_string()))?;
+
+    let candidates: Vec<Move> = position.legal_moves().into_iter()
+        .filter(|mv| {
+            if squares.len() == 2 {
+                mv.from() == squares[0] && mv.to() == squares[1]
+            }
+            else {
+                mv.path == squares
+            }
+        })
+        .collect();
+
+    match candidates.as_slice() {
+        [] => Err(NotationError::IllegalMove(notation.to_string())),
+        [mv] => Ok(mv.clone()),
+        _ => Err(NotationError::AmbiguousMove {
+            notation: notation.to_string(),
+            candidates: candidates.iter().map(format_move_path).collect(),
+        }),
+    }
+}
+
+/// Formats all moves of a game, starting from the given position.
+pub fn format_moves(initial_position: &Position, moves: &[Move]) -> Vec<String> {
+    let mut position = *initial_position;
+    moves.iter()
+        .map(|mv| {
+            let notation = format_move(&position, mv);
+            position.play(mv);
+            notation
+        })
+        .collect()
+}
+
+/// Parses all moves of a game, starting from the given position.
+pub fn parse_moves(initial_position: &Position, notations: &[String]) -> Result<Vec<Move>, NotationError> {
+    let mut position = *initial_position;
+    notations.iter()
+        .map(|notation| {
+            let mv = parse_move(&position, notation)?;
+            position.play(&mv);
+            Ok(mv)
+        })
+        .collect()
+}
+
+fn format_squares(squares: &[Square], is_capture: bool) -> String {
+    let separator = if is_capture { CAPTURE_SEPARATOR } else { MOVE_SEPARATOR };
+    squares.iter()
+        .map(|square| square.to_string())
+        .collect::<Vec<_>>()
+        .join(&separator.to_string())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The moves of a game, always playing the last legal move, which mixes moves,
+    ///  captures and crownings.
+    fn sample_game(plies: usize) -> Vec<Move> {
+        let mut position = Position::initial();
+        let mut moves = Vec::new();
+        for _ in 0..plies {
+            let Some(mv) = position.legal_moves().pop() else {
+                break;
+            };
+            position.play(&mv);
+            moves.push(mv);
+        }
+        moves
+    }
+
+    #[test]
+    fn every_legal_move_round_trips() {
+        let mut position = Position::initial();
+        for mv in sample_game(40) {
+            for legal_move in position.legal_moves() {
+                let notation = format_move(&position, &legal_move);
+                assert_eq!(parse_move(&position, &notation), Ok(legal_move.clone()), "{}", notation);
+                assert_eq!(parse_move(&position, &format_move_path(&legal_move)), Ok(legal_move));
+            }
+            position.play(&mv);
+        }
+    }
+
+    #[test]
+    fn game_round_trips() {
+        let moves = sample_game(60);
+        assert!(moves.iter().any(Move::is_capture));
+        let notations = format_moves(&Position::initial(), &moves);
+        assert_eq!(parse_moves(&Position::initial(), &notations), Ok(moves));
+    }
+
+    #[test]
+    fn either_separator_is_accepted() {
+        let position = Position::initial();
+        assert_eq!(parse_move(&position, "11x15"), parse_move(&position, "11-15"));
+        assert!(parse_move(&position, " 11-15 ").is_ok());
+    }
+
+    #[test]
+    fn malformed_moves_are_rejected() {
+        let position = Position::initial();
+        for notation in ["", "-", "11", "11-", "-15", "11--15", "a-b", "0-15", "11-33", "300-15", "11-15-", "１1-15", "11 15"] {
+            assert_eq!(parse_move(&position, notation), Err(NotationError::InvalidSyntax(notation.trim().to_string())), "{}", notation);
+        }
+    }
+
+    #[test]
+    fn illegal_moves_are_rejected() {
+        let position = Position::initial();
+        for notation in ["11-19", "22-18", "11-15-19", "1-5"] {
+            assert_eq!(parse_move(&position, notation), Err(NotationError::IllegalMove(notation.to_string())), "{}", notation);
+        }
+        let notations = vec!["11-15".to_string(), "11-15".to_string()];
+        assert_eq!(parse_moves(&position, &notations), Err(NotationError::IllegalMove("11-15".to_string())));
+    }
+}
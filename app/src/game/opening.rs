@@ -0,0 +1,28 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+use super::ballot::Ballot;
+use super::board::Position;
+
+
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
+pub enum Opening {
+    #[default]
+    Standard,
+    /// A ballot which is drawn by the server when the game is created.
+    RandomBallot,
+    Ballot(Ballot),
+    /// An arbitrary start position, e.g. set up from a FEN string.
+    Position(Position),
+}
+
+impl fmt::Display for Opening {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Opening::Standard => write!(f, "Standard opening"),
+            Opening::RandomBallot => write!(f, "Random ballot"),
+            Opening::Ballot(ballot) => write!(f, "{}", ballot),
+            Opening::Position(position) => write!(f, "Position {}", position.to_fen()),
+        }
+    }
+}
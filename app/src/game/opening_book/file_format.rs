@@ -0,0 +1,164 @@
+use core::fmt;
+use std::collections::HashMap;
+
+use crate::game::board::{Square, NUMBER_OF_SQUARES};
+use super::{BookMove, OpeningBook};
+
+const MAGIC: &[u8; 4] = b"CKOB";
+const VERSION: u8 = 1;
+
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum OpeningBookError {
+    NotAnOpeningBook,
+    UnsupportedVersion(u8),
+    Truncated,
+    InvalidSquare(u8),
+}
+
+impl fmt::Display for OpeningBookError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OpeningBookError::NotAnOpeningBook => write!(f, "The file is not an opening book."),
+            OpeningBookError::UnsupportedVersion(version) => write!(f, "Opening book version {} is not supported.", version),
+            OpeningBookError::Truncated => write!(f, "The opening book file is truncated."),
+            OpeningBookError::InvalidSquare(square) => write!(f, "The opening book contains the invalid square {}.", square),
+        }
+    }
+}
+
+
+/// The file starts with the magic bytes, the version and the number of positions as little
+///  endian `u32`. Each position follows with its hash as little endian `u64` and the number
+///  of its moves as one byte. Each move is the length of its path, one byte per square of
+///  the path and its wins, draws and losses as little endian `u32`.
+impl OpeningBook {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut keys: Vec<&u64> = self.positions.keys().collect();
+        keys.sort();
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&(keys.len() as u32).to_le_bytes());
+        for key in keys {
+            // a position with more moves than fit into a byte does not occur in checkers
+            let book_moves = &self.positions[key][..self.positions[key].len().min(u8::MAX as usize)];
+            bytes.extend_from_slice(&key.to_le_bytes());
+            bytes.push(book_moves.len() as u8);
+            for book_move in book_moves {
+                bytes.push(book_move.path.len() as u8);
+                bytes.extend(book_move.path.iter().map(|square| square.index() as u8));
+                for count in [book_move.wins, book_move.draws, book_move.losses] {
+                    bytes.extend_from_slice(&count.to_le_bytes());
+                }
+            }
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, OpeningBookError> {
+        let mut reader = Reader { bytes };
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(OpeningBookError::NotAnOpeningBook);
+        }
+        let version = reader.take(1)?[0];
+        if version != VERSION {
+            return Err(OpeningBookError::UnsupportedVersion(version));
+        }
+        let number_of_positions = reader.read_u32()?;
+
+        let mut positions = HashMap::new();
+        for _ in 0..number_of_positions {
+            let key = u64::from_le_bytes(reader.take(8)?.try_into().expect("Eight bytes were taken."));
+            let number_of_moves = reader.take(1)?[0];
+            let mut book_moves = Vec::with_capacity(number_of_moves as usize);
+            for _ in 0..number_of_moves {
+                let path_length = reader.take(1)?[0] as usize;
+                let path = reader.take(path_length)?.iter()
+                    .map(|&square| match square as usize {
+                        index if index < NUMBER_OF_SQUARES => Ok(Square::from_index(index)),
+                        _ => Err(OpeningBookError::InvalidSquare(square)),
+                    })
+                    .collect::<Result<Vec<Square>, OpeningBookError>>()?;
+                book_moves.push(BookMove {
+                    path,
+                    wins: reader.read_u32()?,
+                    draws: reader.read_u32()?,
+                    losses: reader.read_u32()?,
+                });
+            }
+            positions.insert(key, book_moves);
+        }
+        Ok(OpeningBook { positions })
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], OpeningBookError> {
+        if self.bytes.len() < length {
+            return Err(OpeningBookError::Truncated);
+        }
+        let (taken, rest) = self.bytes.split_at(length);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, OpeningBookError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().expect("Four bytes were taken.")))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::board::Position;
+    use crate::game::game_result::{DrawReason, GameResult, WinReason};
+    use crate::game::player_color::PlayerColor;
+
+    fn sample_book() -> OpeningBook {
+        let mut opening_book = OpeningBook::new();
+        let position = Position::initial();
+        for (index, mv) in position.legal_moves().into_iter().enumerate() {
+            let reply = position.after(&mv).legal_moves().remove(0);
+            let result = match index % 3 {
+                0 => GameResult::Win { winner: PlayerColor::Red, reason: WinReason::Unspecified },
+                1 => GameResult::Win { winner: PlayerColor::Blue, reason: WinReason::Unspecified },
+                _ => GameResult::Draw { reason: DrawReason::Unspecified },
+            };
+            opening_book.add_game(position, &[mv, reply], Some(result));
+        }
+        opening_book
+    }
+
+    #[test]
+    fn saved_opening_book_loads_again() {
+        let opening_book = sample_book();
+        let bytes = opening_book.to_bytes();
+        let loaded = OpeningBook::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded.number_of_positions(), opening_book.number_of_positions());
+        assert_eq!(loaded.to_bytes(), bytes);
+        let position = Position::initial();
+        assert_eq!(loaded.moves(&position), opening_book.moves(&position));
+        assert_eq!(loaded.moves(&position).len(), 7);
+    }
+
+    #[test]
+    fn broken_files_are_rejected() {
+        let bytes = sample_book().to_bytes();
+        assert_eq!(OpeningBook::from_bytes(b"CKTB").unwrap_err(), OpeningBookError::NotAnOpeningBook);
+        assert_eq!(OpeningBook::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err(), OpeningBookError::Truncated);
+        let mut other_version = bytes.clone();
+        other_version[MAGIC.len()] = VERSION + 1;
+        assert_eq!(OpeningBook::from_bytes(&other_version).unwrap_err(), OpeningBookError::UnsupportedVersion(VERSION + 1));
+        // the first square of the first move follows the header, the hash and the move counts
+        let mut invalid_square = bytes.clone();
+        invalid_square[MAGIC.len() + 1 + 4 + 8 + 1 + 1] = NUMBER_OF_SQUARES as u8;
+        assert_eq!(OpeningBook::from_bytes(&invalid_square).unwrap_err(), OpeningBookError::InvalidSquare(NUMBER_OF_SQUARES as u8));
+    }
+}
@@ -0,0 +1,160 @@
+mod file_format;
+
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::game::board::{Position, Square};
+use crate::game::engine::{hash, Random};
+use crate::game::game_record::GameRecord;
+use crate::game::game_result::GameResult;
+use crate::game::moves::Move;
+use crate::game::pdn::{PdnError, PdnGame};
+
+pub use file_format::OpeningBookError as OpeningBookError;
+
+/// Only the first moves of a game are worth remembering, later positions hardly repeat.
+pub const MAX_BOOK_PLY: usize = 20;
+
+
+/// How a move played in a book position turned out for the player who played it.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct BookMove {
+    /// The start square followed by every square the piece lands on.
+    pub path: Vec<Square>,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+}
+
+impl BookMove {
+    pub fn games(&self) -> u32 {
+        self.wins + self.draws + self.losses
+    }
+
+    /// How often the bot plays the move compared to the other book moves. A move which
+    ///  only ever lost is never played.
+    pub fn weight(&self) -> u32 {
+        2 * self.wins + self.draws
+    }
+}
+
+
+/// The moves played in the openings of finished games, found by the hash of the position.
+#[derive(Debug, Default, Clone)]
+pub struct OpeningBook {
+    positions: HashMap<u64, Vec<BookMove>>,
+}
+
+impl OpeningBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn number_of_positions(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Adds the opening of a game. Games without a result teach nothing about the moves.
+    pub fn add_game(&mut self, initial_position: Position, moves: &[Move], result: Option<GameResult>) {
+        let Some(result) = result else {
+            return;
+        };
+        let mut position = initial_position;
+        for mv in moves.iter().take(MAX_BOOK_PLY) {
+            let book_moves = self.positions.entry(hash(&position)).or_default();
+            let book_move = match book_moves.iter().position(|book_move| book_move.path == mv.path) {
+                Some(index) => &mut book_moves[index],
+                None => {
+                    book_moves.push(BookMove { path: mv.path.clone(), wins: 0, draws: 0, losses: 0 });
+                    book_moves.last_mut().expect("A book move was just added.")
+                }
+            };
+            match result {
+                GameResult::Win { winner, .. } if winner == position.side_to_move => book_move.wins += 1,
+                GameResult::Win { .. } => book_move.losses += 1,
+                GameResult::Draw { .. } => book_move.draws += 1,
+            }
+            position.play(mv);
+        }
+    }
+
+    /// Adds a game played on the server, e.g. downloaded from `/games/:id/record`.
+    pub fn add_record(&mut self, record: &GameRecord) {
+        let moves: Vec<Move> = record.moves.iter().map(|recorded_move| recorded_move.mv.clone()).collect();
+        self.add_game(record.initial_position, &moves, record.result);
+    }
+
+    pub fn add_pdn_game(&mut self, game: &PdnGame) -> Result<(), PdnError> {
+        self.add_record(&game.to_record(Uuid::nil())?);
+        Ok(())
+    }
+
+    /// The legal moves of the position found in the book, with their statistics.
+    pub fn moves(&self, position: &Position) -> Vec<(Move, BookMove)> {
+        let Some(book_moves) = self.positions.get(&hash(position)) else {
+            return Vec::new();
+        };
+        // the path also protects against positions which share a hash
+        let legal_moves = position.legal_moves();
+        book_moves.iter()
+            .filter_map(|book_move| {
+                legal_moves.iter()
+                    .find(|mv| mv.path == book_move.path)
+                    .map(|mv| (mv.clone(), book_move.clone()))
+            })
+            .collect()
+    }
+
+    /// A random book move, chosen more often the better it scored. `None` if the book does
+    ///  not know the position or every known move lost.
+    pub fn choose_move(&self, position: &Position, random: &mut Random) -> Option<Move> {
+        let moves = self.moves(position);
+        let total_weight: u32 = moves.iter().map(|(_, book_move)| book_move.weight()).sum();
+        if total_weight == 0 {
+            return None;
+        }
+        let mut remaining = random.below(total_weight as usize) as u32;
+        for (mv, book_move) in moves {
+            if remaining < book_move.weight() {
+                return Some(mv);
+            }
+            remaining -= book_move.weight();
+        }
+        None
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::game_record::RecordedMove;
+    use crate::game::game_result::WinReason;
+    use crate::game::game_settings::GameSettings;
+    use crate::game::player_color::PlayerColor;
+
+    #[test]
+    fn records_count_for_the_player_who_moved() {
+        let position = Position::initial();
+        let mv = position.legal_moves().remove(0);
+        let reply = position.after(&mv).legal_moves().remove(0);
+        let record = GameRecord {
+            game_id: Uuid::nil(),
+            settings: GameSettings::default(),
+            created_at: chrono::DateTime::UNIX_EPOCH,
+            player_names: vec![None, None],
+            initial_position: position,
+            moves: vec![RecordedMove::new(mv.clone()), RecordedMove::new(reply.clone())],
+            result: Some(GameResult::Win { winner: PlayerColor::Red, reason: WinReason::Unspecified }),
+        };
+        let mut opening_book = OpeningBook::new();
+        opening_book.add_record(&record);
+        opening_book.add_record(&GameRecord { result: None, ..record.clone() });
+
+        assert_eq!(opening_book.number_of_positions(), 2);
+        let (book_move, statistics) = opening_book.moves(&position).remove(0);
+        assert_eq!((book_move, statistics.wins, statistics.losses), (mv.clone(), 1, 0));
+        let (book_move, statistics) = opening_book.moves(&position.after(&mv)).remove(0);
+        assert_eq!((book_move, statistics.wins, statistics.losses), (reply, 0, 1));
+    }
+}
@@ -0,0 +1,682 @@
+use core::fmt;
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::analysis::GameAnalysis;
+use super::board::Position;
+use super::game_record::{GameRecord, RecordedMove};
+use super::game_result::{DrawReason, GameResult, WinReason};
+use super::game_settings::{GameSettings, GameSettingsError};
+use super::notation::{format_moves, parse_move};
+use super::opening::Opening;
+use super::player_color::PlayerColor;
+
+
+const ENGLISH_CHECKERS_GAME_TYPE: &str = "21";
+const UNKNOWN_TAG_VALUE: &str = "?";
+const MAX_LINE_LENGTH: usize = 80;
+const RED_WINS: [&str; 2] = ["1-0", "2-0"];
+const BLUE_WINS: [&str; 2] = ["0-1", "0-2"];
+const DRAWS: [&str; 2] = ["1/2-1/2", "1-1"];
+const UNKNOWN_RESULTS: [&str; 2] = ["*", "0-0"];
+/// The move strength suffixes and their numeric annotation glyphs.
+pub const MOVE_STRENGTHS: [(&str, u8); 6] = [("!", 1), ("?", 2), ("!!", 3), ("??", 4), ("!?", 5), ("?!", 6)];
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+pub enum PdnError {
+    UnknownError(String),
+    Parse { line: usize, column: usize, message: String },
+    /// The settings derived from the tags of the game starting at the line are invalid.
+    InvalidSettings { line: usize, error: GameSettingsError },
+    TooLong { max_length: usize },
+    TooManyGames { max_games: usize },
+    TooManyPlies { line: usize, max_plies: usize },
+}
+
+impl fmt::Display for PdnError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PdnError::UnknownError(s) => write!(f, "{}", s),
+            PdnError::Parse { line, column, message } => write!(f, "Line {}, column {}: {}", line, column, message),
+            PdnError::InvalidSettings { line, error } => write!(f, "Line {}: {}", line, error),
+            PdnError::TooLong { max_length } => write!(f, "The PDN is longer than {} characters.", max_length),
+            PdnError::TooManyGames { max_games } => write!(f, "The PDN contains more than {} games.", max_games),
+            PdnError::TooManyPlies { line, max_plies } => {
+                write!(f, "Line {}: The game is longer than {} plies.", line, max_plies)
+            }
+        }
+    }
+}
+
+impl FromStr for PdnError {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(PdnError::UnknownError(s.to_string()))
+    }
+}
+
+
+/// A game in Portable Draughts Notation. Red is the player moving first, which is
+///  called black in PDN.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct PdnGame {
+    pub tags: Vec<(String, String)>,
+    /// Comments in front of the first move.
+    pub comments: Vec<String>,
+    pub moves: Vec<PdnMove>,
+    pub result: Option<String>,
+    /// The line the game starts at in the parsed file.
+    pub line: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PdnMove {
+    pub notation: String,
+    /// Numeric annotation glyphs, move strength suffixes like `!?` are stored as NAG as well.
+    pub nags: Vec<u8>,
+    pub comments: Vec<String>,
+    /// Alternatives to this move, each one starting with a move replacing it.
+    pub variations: Vec<Vec<PdnMove>>,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl PdnMove {
+    pub fn new(notation: String) -> Self {
+        Self {
+            notation,
+            nags: Vec::new(),
+            comments: Vec::new(),
+            variations: Vec::new(),
+            line: 0,
+            column: 0,
+        }
+    }
+}
+
+
+impl PdnGame {
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags.iter()
+            .find(|(tag_name, _)| tag_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn from_record(record: &GameRecord) -> Self {
+        let player_name = |player: PlayerColor| {
+            record.player_names.get(player.player_number()).cloned().flatten()
+                .unwrap_or_else(|| UNKNOWN_TAG_VALUE.to_string())
+        };
+        let result = match record.result {
+            Some(GameResult::Win { winner: PlayerColor::Red, .. }) => RED_WINS[0],
+            Some(GameResult::Win { winner: PlayerColor::Blue, .. }) => BLUE_WINS[0],
+            Some(GameResult::Draw { .. }) => DRAWS[0],
+            None => UNKNOWN_RESULTS[0],
+        };
+
+        let mut tags = vec![
+            ("Event".to_string(), "Casual game".to_string()),
+            ("Date".to_string(), record.created_at.format("%Y.%m.%d").to_string()),
+            ("Black".to_string(), player_name(PlayerColor::Red)),
+            ("White".to_string(), player_name(PlayerColor::Blue)),
+            ("Result".to_string(), result.to_string()),
+            ("GameType".to_string(), ENGLISH_CHECKERS_GAME_TYPE.to_string()),
+            ("TimeControl".to_string(), record.settings.time_control.to_string()),
+        ];
+        if let Opening::Ballot(ballot) = &record.settings.opening {
+            tags.push(("Opening".to_string(), ballot.name.clone().unwrap_or_else(|| ballot.moves.clone())));
+        }
+        if !record.settings.handicap.is_none() {
+            tags.push(("Handicap".to_string(), record.settings.handicap.to_string()));
+        }
+        if record.initial_position != Position::initial() {
+            tags.push(("FEN".to_string(), record.initial_position.to_fen()));
+        }
+
+        let moves = record.moves.iter().map(|recorded_move| recorded_move.mv.clone()).collect::<Vec<_>>();
+        let moves = format_moves(&record.initial_position, &moves).into_iter()
+            .zip(record.moves.iter())
+            .map(|(notation, recorded_move)| {
+                let mut pdn_move = PdnMove::new(notation);
+                pdn_move.comments = recorded_move.comment.iter().cloned().collect();
+                pdn_move
+            })
+            .collect();
+
+        Self {
+            tags,
+            comments: Vec::new(),
+            moves,
+            result: Some(result.to_string()),
+            line: 0,
+        }
+    }
+
+    /// Replays the main line of the game, variations are not part of a game record.
+    pub fn to_record(&self, game_id: Uuid) -> Result<GameRecord, PdnError> {
+        let initial_position = match self.tag("FEN") {
+            Some(fen) => Position::from_fen(fen).map_err(|error| PdnError::Parse {
+                line: self.line,
+                column: 1,
+                message: format!("Invalid FEN tag: {}", error),
+            })?,
+            None => Position::initial(),
+        };
+        let mut settings = GameSettings::default();
+        if initial_position != Position::initial() {
+            settings.opening = Opening::Position(initial_position);
+        }
+        settings.validate().map_err(|error| PdnError::InvalidSettings { line: self.line, error })?;
+        let mut position = initial_position;
+        let mut moves = Vec::new();
+        for pdn_move in &self.moves {
+            let mv = parse_move(&position, &pdn_move.notation).map_err(|error| PdnError::Parse {
+                line: pdn_move.line,
+                column: pdn_move.column,
+                message: error.to_string(),
+            })?;
+            position.play(&mv);
+            moves.push(RecordedMove {
+                mv,
+                comment: (!pdn_move.comments.is_empty()).then(|| pdn_move.comments.join(" ")),
+            });
+        }
+
+        let created_at = self.tag("Date")
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y.%m.%d").ok())
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|date| date.and_utc())
+            .unwrap_or_else(Utc::now);
+        let player_name = |tag: &str| self.tag(tag).filter(|name| *name != UNKNOWN_TAG_VALUE).map(str::to_string);
+
+        Ok(GameRecord {
+            game_id,
+            settings,
+            created_at,
+            player_names: vec![player_name("Black"), player_name("White")],
+            initial_position,
+            moves,
+            result: self.result.as_deref().and_then(parse_result),
+        })
+    }
+
+    /// Marks the moves judged by the analysis with their move strength and adds the better
+    ///  move as a variation.
+    pub fn annotate(&mut self, analysis: &GameAnalysis) {
+        for (pdn_move, ply) in self.moves.iter_mut().zip(analysis.plies.iter()) {
+            let Some(judgement) = ply.judgement else {
+                continue;
+            };
+            pdn_move.nags.push(judgement.nag());
+            if let Some(better_move) = &ply.better_move {
+                pdn_move.variations.push(vec![PdnMove::new(better_move.clone())]);
+            }
+        }
+    }
+}
+
+impl fmt::Display for PdnGame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (name, value) in &self.tags {
+            writeln!(f, "[{} \"{}\"]", name, value.replace('\\', "\\\\").replace('"', "\\\""))?;
+        }
+        writeln!(f)?;
+
+        let mut tokens: Vec<String> = self.comments.iter().map(|comment| format_comment(comment)).collect();
+        let first_side = self.tag("FEN")
+            .and_then(|fen| Position::from_fen(fen).ok())
+            .map(|position| position.side_to_move)
+            .unwrap_or(PlayerColor::Red);
+        movetext_tokens(&self.moves, 1, first_side, &mut tokens);
+        tokens.push(self.result.clone().unwrap_or_else(|| UNKNOWN_RESULTS[0].to_string()));
+
+        let mut line = String::new();
+        for token in tokens {
+            if !line.is_empty() && line.len() + token.len() + 1 > MAX_LINE_LENGTH {
+                writeln!(f, "{}", line)?;
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&token);
+        }
+        writeln!(f, "{}", line)
+    }
+}
+
+pub fn export_pdn(record: &GameRecord) -> String {
+    PdnGame::from_record(record).to_string()
+}
+
+/// Like `export_pdn`, with the judgements and better moves of the analysis.
+pub fn export_annotated_pdn(record: &GameRecord, analysis: &GameAnalysis) -> String {
+    let mut game = PdnGame::from_record(record);
+    game.annotate(analysis);
+    game.to_string()
+}
+
+/// Parses all games of a PDN file.
+pub fn parse_pdn(text: &str) -> Result<Vec<PdnGame>, PdnError> {
+    let mut parser = PdnParser::new(text);
+    let mut games = Vec::new();
+    loop {
+        parser.skip_whitespace();
+        if parser.peek().is_none() {
+            break;
+        }
+        games.push(parser.parse_game()?);
+    }
+    Ok(games)
+}
+
+fn parse_result(result: &str) -> Option<GameResult> {
+    if RED_WINS.contains(&result) {
+        Some(GameResult::Win { winner: PlayerColor::Red, reason: WinReason::Unspecified })
+    }
+    else if BLUE_WINS.contains(&result) {
+        Some(GameResult::Win { winner: PlayerColor::Blue, reason: WinReason::Unspecified })
+    }
+    else if DRAWS.contains(&result) {
+        Some(GameResult::Draw { reason: DrawReason::Unspecified })
+    }
+    else {
+        None
+    }
+}
+
+fn is_result(word: &str) -> bool {
+    RED_WINS.contains(&word) || BLUE_WINS.contains(&word) || DRAWS.contains(&word) || UNKNOWN_RESULTS.contains(&word)
+}
+
+/// The move strength suffix of the annotation glyph, or the glyph as `$n`.
+pub fn format_nag(nag: u8) -> String {
+    match MOVE_STRENGTHS.iter().find(|(_, strength_nag)| *strength_nag == nag) {
+        Some((symbol, _)) => symbol.to_string(),
+        None => format!("${}", nag),
+    }
+}
+
+fn format_comment(comment: &str) -> String {
+    format!("{{{}}}", comment.replace('}', ")"))
+}
+
+fn movetext_tokens(moves: &[PdnMove], mut move_number: usize, mut side: PlayerColor, tokens: &mut Vec<String>) {
+    let mut needs_move_number = true;
+    for pdn_move in moves {
+        if side == PlayerColor::Red {
+            tokens.push(format!("{}.", move_number));
+        }
+        else if needs_move_number {
+            tokens.push(format!("{}...", move_number));
+        }
+        tokens.push(pdn_move.notation.clone());
+        needs_move_number = false;
+
+        for nag in &pdn_move.nags {
+            tokens.push(format!("${}", nag));
+        }
+        for comment in &pdn_move.comments {
+            tokens.push(format_comment(comment));
+            needs_move_number = true;
+        }
+        for variation in &pdn_move.variations {
+            let mut variation_tokens = Vec::new();
+            movetext_tokens(variation, move_number, side, &mut variation_tokens);
+            if let Some(first) = variation_tokens.first_mut() {
+                first.insert(0, '(');
+            }
+            if let Some(last) = variation_tokens.last_mut() {
+                last.push(')');
+            }
+            tokens.extend(variation_tokens);
+            needs_move_number = true;
+        }
+
+        if side == PlayerColor::Blue {
+            move_number += 1;
+        }
+        side = side.opponent();
+    }
+}
+
+
+/// The comments before the first move, the moves and the result of a game or variation.
+type Movetext = (Vec<String>, Vec<PdnMove>, Option<String>);
+
+struct PdnParser<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> PdnParser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            chars: text.chars().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        }
+        else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn error<T>(&self, line: usize, column: usize, message: impl Into<String>) -> Result<T, PdnError> {
+        Err(PdnError::Parse { line, column, message: message.into() })
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == '%' && self.column == 1 {
+                // escape mechanism: the whole line is ignored
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.next();
+                }
+            }
+            else if c.is_whitespace() {
+                self.next();
+            }
+            else {
+                break;
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), PdnError> {
+        let (line, column) = (self.line, self.column);
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => self.error(line, column, format!("Expected '{}', found '{}'.", expected, c)),
+            None => self.error(line, column, format!("Expected '{}', found end of file.", expected)),
+        }
+    }
+
+    fn parse_game(&mut self) -> Result<PdnGame, PdnError> {
+        self.skip_whitespace();
+        let mut game = PdnGame {
+            line: self.line,
+            ..PdnGame::default()
+        };
+        while self.peek() == Some('[') {
+            game.tags.push(self.parse_tag()?);
+            self.skip_whitespace();
+        }
+        let (comments, moves, result) = self.parse_movetext(false)?;
+        game.comments = comments;
+        game.moves = moves;
+        game.result = result;
+        Ok(game)
+    }
+
+    fn parse_tag(&mut self) -> Result<(String, String), PdnError> {
+        self.expect('[')?;
+        self.skip_whitespace();
+        let (line, column) = (self.line, self.column);
+        let mut name = String::new();
+        while let Some(c) = self.peek().filter(|c| c.is_ascii_alphanumeric() || *c == '_') {
+            name.push(c);
+            self.next();
+        }
+        if name.is_empty() {
+            return self.error(line, column, "Expected a tag name.");
+        }
+
+        self.skip_whitespace();
+        let (line, column) = (self.line, self.column);
+        self.expect('"')?;
+        let mut value = String::new();
+        loop {
+            match self.next() {
+                Some('\\') => match self.next() {
+                    Some(c) => value.push(c),
+                    None => return self.error(line, column, "Unterminated tag value."),
+                },
+                Some('"') => break,
+                Some(c) => value.push(c),
+                None => return self.error(line, column, "Unterminated tag value."),
+            }
+        }
+        self.skip_whitespace();
+        self.expect(']')?;
+        Ok((name, value))
+    }
+
+    fn parse_comment(&mut self) -> Result<String, PdnError> {
+        let (line, column) = (self.line, self.column);
+        self.expect('{')?;
+        let mut comment = String::new();
+        loop {
+            match self.next() {
+                Some('}') => return Ok(comment.trim().to_string()),
+                Some(c) => comment.push(c),
+                None => return self.error(line, column, "Unterminated comment."),
+            }
+        }
+    }
+
+    /// Parses moves until the end of the game or, for variations, until the closing parenthesis.
+    fn parse_movetext(&mut self, is_variation: bool) -> Result<Movetext, PdnError> {
+        let mut comments = Vec::new();
+        let mut moves: Vec<PdnMove> = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let (line, column) = (self.line, self.column);
+            match self.peek() {
+                None if is_variation => return self.error(line, column, "Unterminated variation."),
+                None => return Ok((comments, moves, None)),
+                Some('[') if is_variation => return self.error(line, column, "Unterminated variation."),
+                Some('[') => return Ok((comments, moves, None)),
+                Some(')') if is_variation => {
+                    self.next();
+                    return Ok((comments, moves, None));
+                }
+                Some(')') => return self.error(line, column, "Unexpected ')' outside of a variation."),
+                Some('(') => {
+                    self.next();
+                    let (variation_comments, mut variation, _) = self.parse_movetext(true)?;
+                    let Some(last_move) = moves.last_mut() else {
+                        return self.error(line, column, "Variation in front of the first move.");
+                    };
+                    if let Some(first_move) = variation.first_mut() {
+                        first_move.comments.splice(0..0, variation_comments);
+                    }
+                    last_move.variations.push(variation);
+                }
+                Some('{') => {
+                    let comment = self.parse_comment()?;
+                    match moves.last_mut() {
+                        Some(last_move) => last_move.comments.push(comment),
+                        None => comments.push(comment),
+                    }
+                }
+                Some('$') => {
+                    self.next();
+                    let nag = self.parse_word();
+                    let Ok(nag) = nag.parse::<u8>() else {
+                        return self.error(line, column, format!("Invalid annotation glyph '${}'.", nag));
+                    };
+                    let Some(last_move) = moves.last_mut() else {
+                        return self.error(line, column, "Annotation glyph in front of the first move.");
+                    };
+                    last_move.nags.push(nag);
+                }
+                Some(c) if c.is_ascii_alphanumeric() || c == '*' => {
+                    let word = self.parse_word();
+                    if is_result(&word) {
+                        if is_variation {
+                            return self.error(line, column, "Unexpected result inside a variation.");
+                        }
+                        return Ok((comments, moves, Some(word)));
+                    }
+                    if let Some(pdn_move) = self.parse_move(&word, line, column)? {
+                        moves.push(pdn_move);
+                    }
+                }
+                Some(c) => return self.error(line, column, format!("Unexpected character '{}'.", c)),
+            }
+        }
+    }
+
+    fn parse_word(&mut self) -> String {
+        let mut word = String::new();
+        while let Some(c) = self.peek().filter(|c| c.is_ascii_alphanumeric() || "-x:/.!?*".contains(*c)) {
+            word.push(c);
+            self.next();
+        }
+        word
+    }
+
+    /// Parses a move with an optional move number in front, returns nothing for a plain move number.
+    fn parse_move(&self, word: &str, line: usize, column: usize) -> Result<Option<PdnMove>, PdnError> {
+        let text = match word.rfind('.') {
+            Some(index) if word[..index].trim_end_matches('.').chars().all(|c| c.is_ascii_digit()) => &word[index + 1..],
+            Some(_) => return self.error(line, column, format!("Invalid move number '{}'.", word)),
+            None => word,
+        };
+        if text.is_empty() {
+            return Ok(None);
+        }
+        let column = column + (word.len() - text.len());
+
+        let notation = text.trim_end_matches(['!', '?']);
+        let strength = &text[notation.len()..];
+        let mut nags = Vec::new();
+        if !strength.is_empty() {
+            match MOVE_STRENGTHS.iter().find(|(symbol, _)| *symbol == strength) {
+                Some((_, nag)) => nags.push(*nag),
+                None => return self.error(line, column, format!("Invalid move annotation '{}'.", strength)),
+            }
+        }
+
+        let squares: Vec<&str> = notation.split(['-', 'x', ':']).collect();
+        let is_valid = squares.len() >= 2 && squares.iter().all(|square| {
+            square.parse::<u8>().is_ok_and(|square| (1..=32).contains(&square))
+        });
+        if !is_valid {
+            return self.error(line, column, format!("Invalid move '{}'.", notation));
+        }
+
+        Ok(Some(PdnMove {
+            notation: notation.to_string(),
+            nags,
+            comments: Vec::new(),
+            variations: Vec::new(),
+            line,
+            column,
+        }))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_record() -> GameRecord {
+        let mut position = Position::initial();
+        let mut moves = Vec::new();
+        for ply in 0..30 {
+            let mv = position.legal_moves().pop().unwrap();
+            position.play(&mv);
+            let mut recorded_move = RecordedMove::new(mv);
+            if ply % 7 == 3 {
+                recorded_move.comment = Some(format!("Comment {}", ply));
+            }
+            moves.push(recorded_move);
+        }
+        GameRecord {
+            game_id: Uuid::nil(),
+            settings: GameSettings::default(),
+            created_at: NaiveDate::from_ymd_opt(2024, 5, 17).unwrap().and_hms_opt(0, 0, 0).unwrap().and_utc(),
+            player_names: vec![Some("Marion \"Red\" Tinsley".to_string()), None],
+            initial_position: Position::initial(),
+            moves,
+            result: Some(GameResult::Win { winner: PlayerColor::Blue, reason: WinReason::Unspecified }),
+        }
+    }
+
+    #[test]
+    fn record_round_trips() {
+        let record = sample_record();
+        let games = parse_pdn(&export_pdn(&record)).unwrap();
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].to_record(record.game_id), Ok(record));
+    }
+
+    #[test]
+    fn record_from_position_round_trips() {
+        let mut record = sample_record();
+        record.initial_position = Position::from_fen("W:W18,K30:B14,K1").unwrap();
+        record.settings.opening = Opening::Position(record.initial_position);
+        let mv = record.initial_position.legal_moves().pop().unwrap();
+        record.moves = vec![RecordedMove::new(mv)];
+        record.result = Some(GameResult::Draw { reason: DrawReason::Unspecified });
+        let pdn = export_pdn(&record);
+        assert!(pdn.contains("1..."));
+        assert_eq!(parse_pdn(&pdn).unwrap()[0].to_record(record.game_id), Ok(record));
+    }
+
+    #[test]
+    fn annotated_game_round_trips() {
+        let text = "[Event \"Test\"]\n\n{Before} 1. 11-15 $1 {Good} (1. 9-13 {Also fine} 2. 22-18 $2) 1... 23-19 2. 8-11 *\n";
+        let games = parse_pdn(&format!("{}\n{}", text, text)).unwrap();
+        assert_eq!(games.len(), 2);
+        let game = &games[0];
+        assert_eq!(game.comments, vec!["Before".to_string()]);
+        assert_eq!(game.moves.len(), 3);
+        assert_eq!(game.moves[0].nags, vec![1]);
+        assert_eq!(game.moves[0].variations[0][0].comments, vec!["Also fine".to_string()]);
+        assert_eq!(game.moves[0].variations[0][1].nags, vec![2]);
+        assert_eq!(game.result.as_deref(), Some("*"));
+        assert_eq!(game.to_string(), parse_pdn(&game.to_string()).unwrap()[0].to_string());
+    }
+
+    #[test]
+    fn malformed_pdn_is_rejected() {
+        let texts = [
+            "[Event \"Test",
+            "[ \"Test\"]",
+            "[Event Test]",
+            "[Event \"Test\"",
+            "1. 11-15 {Unterminated",
+            "1. 11-15 (2. 22-18",
+            "1. 11-15 )",
+            "(1. 11-15) 1. 9-13",
+            "$1 1. 11-15",
+            "1. 11-15 $x",
+            "1. 11-15 $300",
+            "1. 11-15 (22-18 1-0)",
+            "1. x *",
+        ];
+        for text in texts {
+            assert!(matches!(parse_pdn(text), Err(PdnError::Parse { .. })), "{}", text);
+        }
+    }
+
+    #[test]
+    fn invalid_games_are_rejected() {
+        for text in ["1. 11-19 *", "1. 11-15 11-15 *", "[FEN \"B:W1:B\"]\n1. 11-15 *"] {
+            let games = parse_pdn(text).unwrap();
+            assert!(matches!(games[0].to_record(Uuid::nil()), Err(PdnError::Parse { .. })), "{}", text);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub enum PlayerColor {
+    Red,
+    Blue,
+}
+
+impl PlayerColor {
+    pub fn from_player_number(player_number: usize) -> Option<Self> {
+        match player_number {
+            0 => Some(PlayerColor::Red),
+            1 => Some(PlayerColor::Blue),
+            _ => None,
+        }
+    }
+
+    pub fn player_number(&self) -> usize {
+        match self {
+            PlayerColor::Red => 0,
+            PlayerColor::Blue => 1,
+        }
+    }
+
+    pub fn opponent(&self) -> Self {
+        match self {
+            PlayerColor::Red => PlayerColor::Blue,
+            PlayerColor::Blue => PlayerColor::Red,
+        }
+    }
+}
+
+impl fmt::Display for PlayerColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PlayerColor::Red => write!(f, "Red"),
+            PlayerColor::Blue => write!(f, "Blue"),
+        }
+    }
+}
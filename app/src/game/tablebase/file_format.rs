@@ -0,0 +1,127 @@
+use core::fmt;
+use std::collections::HashMap;
+
+use super::index::Material;
+use super::Tablebase;
+
+const MAGIC: &[u8; 4] = b"CKTB";
+const VERSION: u8 = 1;
+
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum TablebaseError {
+    NotATablebase,
+    UnsupportedVersion(u8),
+    Truncated,
+    /// A table does not have the size its material needs.
+    InvalidTableSize,
+}
+
+impl fmt::Display for TablebaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TablebaseError::NotATablebase => write!(f, "The file is not a tablebase."),
+            TablebaseError::UnsupportedVersion(version) => write!(f, "Tablebase version {} is not supported.", version),
+            TablebaseError::Truncated => write!(f, "The tablebase file is truncated."),
+            TablebaseError::InvalidTableSize => write!(f, "The tablebase file contains a table of the wrong size."),
+        }
+    }
+}
+
+
+/// The file starts with the magic bytes, the version, the maximum number of pieces and the
+///  number of tables as little endian `u16`. Each table follows with its material as four
+///  bytes (attacker men and kings, defender men and kings), its length as little endian
+///  `u32` and one byte per position.
+impl Tablebase {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut materials: Vec<&Material> = self.tables.keys().collect();
+        materials.sort();
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.push(self.max_pieces);
+        bytes.extend_from_slice(&(materials.len() as u16).to_le_bytes());
+        for material in materials {
+            let table = &self.tables[material];
+            bytes.extend_from_slice(&[material.attacker_men, material.attacker_kings, material.defender_men, material.defender_kings]);
+            bytes.extend_from_slice(&(table.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(table);
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TablebaseError> {
+        let mut reader = Reader { bytes };
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(TablebaseError::NotATablebase);
+        }
+        let version = reader.take(1)?[0];
+        if version != VERSION {
+            return Err(TablebaseError::UnsupportedVersion(version));
+        }
+        let max_pieces = reader.take(1)?[0];
+        let number_of_tables = u16::from_le_bytes(reader.take(2)?.try_into().expect("Two bytes were taken."));
+
+        let mut tables = HashMap::new();
+        for _ in 0..number_of_tables {
+            let material = reader.take(4)?;
+            let material = Material {
+                attacker_men: material[0],
+                attacker_kings: material[1],
+                defender_men: material[2],
+                defender_kings: material[3],
+            };
+            let length = u32::from_le_bytes(reader.take(4)?.try_into().expect("Four bytes were taken.")) as usize;
+            if material.pieces() > max_pieces || length != material.size() {
+                return Err(TablebaseError::InvalidTableSize);
+            }
+            tables.insert(material, reader.take(length)?.to_vec());
+        }
+        Ok(Tablebase { max_pieces, tables })
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], TablebaseError> {
+        if self.bytes.len() < length {
+            return Err(TablebaseError::Truncated);
+        }
+        let (taken, rest) = self.bytes.split_at(length);
+        self.bytes = rest;
+        Ok(taken)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::board::Position;
+
+    #[test]
+    fn saved_tablebase_loads_again() {
+        let tablebase = Tablebase::generate(2, |_| {});
+        let bytes = tablebase.to_bytes();
+        let loaded = Tablebase::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded.max_pieces(), 2);
+        assert_eq!(loaded.to_bytes(), bytes);
+        let position = Position::from_fen("B:W30:BK1").unwrap();
+        assert_eq!(loaded.probe(&position), tablebase.probe(&position));
+    }
+
+    #[test]
+    fn broken_files_are_rejected() {
+        let bytes = Tablebase::generate(2, |_| {}).to_bytes();
+        assert_eq!(Tablebase::from_bytes(b"CKOB").unwrap_err(), TablebaseError::NotATablebase);
+        assert_eq!(Tablebase::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err(), TablebaseError::Truncated);
+        let mut other_version = bytes.clone();
+        other_version[MAGIC.len()] = VERSION + 1;
+        assert_eq!(Tablebase::from_bytes(&other_version).unwrap_err(), TablebaseError::UnsupportedVersion(VERSION + 1));
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::{BTreeMap, HashMap};
+
+use super::index::Material;
+use super::{Tablebase, TablebaseValue, DRAW_ENTRY, INVALID_ENTRY, MAX_DISTANCE};
+
+
+impl Tablebase {
+    /// Solves every position with at most `max_pieces` pieces by retrograde analysis. Captures
+    ///  and promotions lead to positions with fewer pieces or men, so the materials are solved
+    ///  in groups of the same number of pieces and men, from the fewest upwards. `on_solved`
+    ///  is called after every group, e.g. to report the progress.
+    pub fn generate(max_pieces: u8, mut on_solved: impl FnMut(&[Material])) -> Self {
+        let mut tablebase = Tablebase { max_pieces, tables: HashMap::new() };
+        let mut groups: BTreeMap<(u8, u8), Vec<Material>> = BTreeMap::new();
+        for material in Material::all(max_pieces) {
+            groups.entry((material.pieces(), material.men())).or_default().push(material);
+        }
+
+        let mut max_distance = 0;
+        for group in groups.values() {
+            max_distance = max_distance.max(tablebase.solve(group, max_distance));
+            on_solved(group);
+        }
+        tablebase
+    }
+
+    /// Solves a group of materials, whose positions only lead to each other or to already
+    ///  solved materials. Returns the longest distance found.
+    fn solve(&mut self, group: &[Material], solved_max_distance: u8) -> u8 {
+        let mut pending = Vec::new();
+        for material in group {
+            let mut table = vec![INVALID_ENTRY; material.size()];
+            for (index, entry) in table.iter_mut().enumerate() {
+                let Some(position) = material.position(index) else {
+                    continue;
+                };
+                if position.legal_moves().is_empty() {
+                    *entry = TablebaseValue::Loss(0).to_entry();
+                }
+                else {
+                    // unresolved positions are draws unless a later pass resolves them
+                    *entry = DRAW_ENTRY;
+                    pending.push((*material, index));
+                }
+            }
+            self.tables.insert(*material, table);
+        }
+
+        // In pass `distance` a position is won, if a move leads to a loss in `distance - 1`
+        //  plies, and lost, if all moves lead to wins and the longest one takes exactly
+        //  `distance - 1` plies. Values found in a pass are only used in the next one, so
+        //  every distance is the shortest win or the longest defence.
+        let mut max_distance = solved_max_distance;
+        let mut distance = 1;
+        while !pending.is_empty() && distance <= MAX_DISTANCE {
+            let mut resolved = Vec::new();
+            pending.retain(|&(material, index)| {
+                let position = material.position(index).expect("Only valid positions are pending.");
+                let mut are_all_wins = true;
+                let mut longest_win = 0;
+                for mv in position.legal_moves() {
+                    match self.probe(&position.after(&mv)) {
+                        Some(TablebaseValue::Loss(loss_distance)) if loss_distance == distance - 1 => {
+                            resolved.push((material, index, TablebaseValue::Win(distance)));
+                            return false;
+                        }
+                        Some(TablebaseValue::Win(win_distance)) => longest_win = longest_win.max(win_distance),
+                        _ => are_all_wins = false,
+                    }
+                }
+                if are_all_wins && longest_win == distance - 1 {
+                    resolved.push((material, index, TablebaseValue::Loss(distance)));
+                    return false;
+                }
+                true
+            });
+
+            // nothing can be resolved any more, once no shorter distance is left to build on
+            if resolved.is_empty() && distance > max_distance {
+                break;
+            }
+            for (material, index, value) in resolved {
+                self.tables.get_mut(&material).expect("The table of the group exists.")[index] = value.to_entry();
+                max_distance = max_distance.max(distance);
+            }
+            distance += 1;
+        }
+        max_distance
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::board::Position;
+    use crate::game::game_result::{GameResult, WinReason};
+    use crate::game::player_color::PlayerColor;
+
+    #[test]
+    fn two_kings_win_against_one_king() {
+        // the endgames with three kings only lead to each other and to two pieces
+        let mut tablebase = Tablebase::generate(2, |_| {});
+        tablebase.max_pieces = 3;
+        let two_kings = Material { attacker_men: 0, attacker_kings: 2, defender_men: 0, defender_kings: 1 };
+        tablebase.solve(&[two_kings, two_kings.mirrored()], 0);
+
+        let red_to_move = Position::from_fen("B:WK32:BK1,K5").unwrap();
+        assert!(matches!(tablebase.probe(&red_to_move), Some(TablebaseValue::Win(_))));
+        assert_eq!(
+            tablebase.adjudicate(&red_to_move),
+            Some(GameResult::Win { winner: PlayerColor::Red, reason: WinReason::Adjudication }),
+        );
+        let blue_to_move = Position::from_fen("W:WK32:BK1,K5").unwrap();
+        assert!(matches!(tablebase.probe(&blue_to_move), Some(TablebaseValue::Loss(_))));
+    }
+}
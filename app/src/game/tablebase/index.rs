@@ -0,0 +1,184 @@
+use crate::game::board::{Board, Piece, PieceKind, Position, Square, NUMBER_OF_SQUARES};
+use crate::game::player_color::PlayerColor;
+
+const BINOMIALS: [[u64; NUMBER_OF_SQUARES + 1]; NUMBER_OF_SQUARES + 1] = binomials();
+
+
+const fn binomials() -> [[u64; NUMBER_OF_SQUARES + 1]; NUMBER_OF_SQUARES + 1] {
+    let mut binomials = [[0; NUMBER_OF_SQUARES + 1]; NUMBER_OF_SQUARES + 1];
+    let mut n = 0;
+    while n <= NUMBER_OF_SQUARES {
+        binomials[n][0] = 1;
+        let mut k = 1;
+        while k <= n {
+            binomials[n][k] = binomials[n - 1][k - 1] + binomials[n - 1][k];
+            k += 1;
+        }
+        n += 1;
+    }
+    binomials
+}
+
+
+/// The pieces on the board, seen from the side to move, which is called the attacker.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub struct Material {
+    pub attacker_men: u8,
+    pub attacker_kings: u8,
+    pub defender_men: u8,
+    pub defender_kings: u8,
+}
+
+impl Material {
+    pub fn of(position: &Position) -> Self {
+        let count = |color: PlayerColor, kind: PieceKind| {
+            position.board.pieces_of(color).filter(|(_, piece)| piece.kind == kind).count() as u8
+        };
+        let attacker = position.side_to_move;
+        let defender = attacker.opponent();
+        Self {
+            attacker_men: count(attacker, PieceKind::Man),
+            attacker_kings: count(attacker, PieceKind::King),
+            defender_men: count(defender, PieceKind::Man),
+            defender_kings: count(defender, PieceKind::King),
+        }
+    }
+
+    /// Every material with pieces for both sides and at most `max_pieces` pieces on the board.
+    pub fn all(max_pieces: u8) -> Vec<Material> {
+        let mut materials = Vec::new();
+        for attacker_men in 0..=max_pieces {
+            for attacker_kings in 0..=max_pieces - attacker_men {
+                for defender_men in 0..=max_pieces - attacker_men - attacker_kings {
+                    for defender_kings in 0..=max_pieces - attacker_men - attacker_kings - defender_men {
+                        let material = Material { attacker_men, attacker_kings, defender_men, defender_kings };
+                        if material.attacker_pieces() > 0 && material.defender_pieces() > 0 {
+                            materials.push(material);
+                        }
+                    }
+                }
+            }
+        }
+        materials
+    }
+
+    pub fn attacker_pieces(&self) -> u8 {
+        self.attacker_men + self.attacker_kings
+    }
+
+    pub fn defender_pieces(&self) -> u8 {
+        self.defender_men + self.defender_kings
+    }
+
+    pub fn pieces(&self) -> u8 {
+        self.attacker_pieces() + self.defender_pieces()
+    }
+
+    pub fn men(&self) -> u8 {
+        self.attacker_men + self.defender_men
+    }
+
+    /// The material after the move, when the other side moves.
+    pub fn mirrored(&self) -> Self {
+        Self {
+            attacker_men: self.defender_men,
+            attacker_kings: self.defender_kings,
+            defender_men: self.attacker_men,
+            defender_kings: self.attacker_kings,
+        }
+    }
+
+    /// The number of indices, including those of impossible placements like two pieces on
+    ///  the same square, which are simply never used.
+    pub fn size(&self) -> usize {
+        self.groups().iter().map(|(count, _)| BINOMIALS[NUMBER_OF_SQUARES][*count as usize] as usize).product()
+    }
+
+    /// The pieces in index order: attacker men, attacker kings, defender men, defender kings.
+    fn groups(&self) -> [(u8, Piece); 4] {
+        [
+            (self.attacker_men, Piece::man(PlayerColor::Red)),
+            (self.attacker_kings, Piece::king(PlayerColor::Red)),
+            (self.defender_men, Piece::man(PlayerColor::Blue)),
+            (self.defender_kings, Piece::king(PlayerColor::Blue)),
+        ]
+    }
+
+    /// The index of a position with this material and red to move.
+    pub fn index(&self, position: &Position) -> usize {
+        let mut index = 0;
+        for (count, piece) in self.groups() {
+            let squares = position.board.pieces().filter(|(_, other)| *other == piece).map(|(square, _)| square.index());
+            let rank: u64 = squares.enumerate().map(|(i, square)| BINOMIALS[square][i + 1]).sum();
+            index = index * BINOMIALS[NUMBER_OF_SQUARES][count as usize] as usize + rank as usize;
+        }
+        index
+    }
+
+    /// The position with red to move of an index, `None` for impossible placements.
+    pub fn position(&self, index: usize) -> Option<Position> {
+        let mut board = Board::empty();
+        let mut remaining = index;
+        for (count, piece) in self.groups().into_iter().rev() {
+            let group_size = BINOMIALS[NUMBER_OF_SQUARES][count as usize] as usize;
+            let mut rank = (remaining % group_size) as u64;
+            remaining /= group_size;
+            for i in (1..=count as usize).rev() {
+                let square = (0..NUMBER_OF_SQUARES).rev()
+                    .find(|square| BINOMIALS[*square][i] <= rank)
+                    .expect("There is a square for every rank.");
+                rank -= BINOMIALS[square][i];
+                let square = Square::from_index(square);
+                let is_man_on_promotion_row = !piece.is_king() && square.is_promotion_square_for(piece.color);
+                if board.get(square).is_some() || is_man_on_promotion_row {
+                    return None;
+                }
+                board.set(square, Some(piece));
+            }
+        }
+        Some(Position { board, side_to_move: PlayerColor::Red })
+    }
+}
+
+
+/// The position turned by 180 degrees with the colours swapped, so that red is to move.
+pub fn normalize(position: &Position) -> Position {
+    if position.side_to_move == PlayerColor::Red {
+        return *position;
+    }
+    let mut board = Board::empty();
+    for (square, piece) in position.board.pieces() {
+        let rotated_square = Square::from_index(NUMBER_OF_SQUARES - 1 - square.index());
+        board.set(rotated_square, Some(Piece { color: piece.color.opponent(), kind: piece.kind }));
+    }
+    Position { board, side_to_move: PlayerColor::Red }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_and_position_are_inverse() {
+        let material = Material { attacker_men: 1, attacker_kings: 1, defender_men: 0, defender_kings: 1 };
+        let mut number_of_positions = 0;
+        for index in 0..material.size() {
+            if let Some(position) = material.position(index) {
+                assert_eq!(Material::of(&position), material);
+                assert_eq!(material.index(&position), index);
+                number_of_positions += 1;
+            }
+        }
+        // the man cannot stand on the promotion row
+        assert_eq!(number_of_positions, 28 * 31 * 30);
+    }
+
+    #[test]
+    fn normalize_turns_the_board_for_blue() {
+        let position = Position::from_fen("W:W18,K3:B10").unwrap();
+        let normalized = normalize(&position);
+        assert_eq!(normalized.to_fen(), "B:W23:B15,K30");
+        assert_eq!(normalize(&normalized), normalized);
+    }
+}
@@ -0,0 +1,115 @@
+mod index;
+mod generation;
+mod file_format;
+
+use core::fmt;
+use std::collections::HashMap;
+
+use crate::game::board::Position;
+use crate::game::game_result::{DrawReason, GameResult, WinReason};
+use index::normalize;
+
+pub use index::Material as Material;
+pub use file_format::TablebaseError as TablebaseError;
+
+/// The longest distance to the end of the game which fits into an entry.
+pub const MAX_DISTANCE: u8 = 126;
+
+const INVALID_ENTRY: u8 = 0;
+const DRAW_ENTRY: u8 = 1;
+
+
+/// The exact value of a position for the side to move, with the distance in plies until
+///  the winner takes the last piece or blocks the opponent.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum TablebaseValue {
+    Win(u8),
+    Loss(u8),
+    Draw,
+}
+
+impl TablebaseValue {
+    fn from_entry(entry: u8) -> Option<Self> {
+        match entry {
+            INVALID_ENTRY => None,
+            DRAW_ENTRY => Some(TablebaseValue::Draw),
+            entry if entry % 2 == 0 => Some(TablebaseValue::Loss((entry - 2) / 2)),
+            entry => Some(TablebaseValue::Win((entry - 3) / 2)),
+        }
+    }
+
+    fn to_entry(self) -> u8 {
+        match self {
+            TablebaseValue::Draw => DRAW_ENTRY,
+            TablebaseValue::Loss(distance) => 2 + 2 * distance.min(MAX_DISTANCE),
+            TablebaseValue::Win(distance) => 3 + 2 * distance.min(MAX_DISTANCE),
+        }
+    }
+}
+
+impl fmt::Display for TablebaseValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TablebaseValue::Win(distance) => write!(f, "Win in {} plies", distance),
+            TablebaseValue::Loss(distance) => write!(f, "Loss in {} plies", distance),
+            TablebaseValue::Draw => write!(f, "Draw"),
+        }
+    }
+}
+
+
+/// Perfect play for every position with at most `max_pieces` pieces, found by retrograde
+///  analysis. Each position has one byte, positions are normalized to red to move.
+#[derive(Debug, Default, Clone)]
+pub struct Tablebase {
+    max_pieces: u8,
+    tables: HashMap<Material, Vec<u8>>,
+}
+
+impl Tablebase {
+    pub fn max_pieces(&self) -> u8 {
+        self.max_pieces
+    }
+
+    /// The value of the position, `None` if it has too many pieces for the tablebase.
+    pub fn probe(&self, position: &Position) -> Option<TablebaseValue> {
+        let position = normalize(position);
+        let material = Material::of(&position);
+        if material.attacker_pieces() == 0 {
+            return Some(TablebaseValue::Loss(0));
+        }
+        let table = self.tables.get(&material)?;
+        table.get(material.index(&position)).copied().and_then(TablebaseValue::from_entry)
+    }
+
+    /// The result of the game with perfect play from the position on, `None` if the
+    ///  tablebase does not know the position.
+    pub fn adjudicate(&self, position: &Position) -> Option<GameResult> {
+        Some(match self.probe(position)? {
+            TablebaseValue::Win(_) => GameResult::Win { winner: position.side_to_move, reason: WinReason::Adjudication },
+            TablebaseValue::Loss(_) => GameResult::Win { winner: position.side_to_move.opponent(), reason: WinReason::Adjudication },
+            TablebaseValue::Draw => GameResult::Draw { reason: DrawReason::Adjudication },
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_king_against_one_king_is_a_draw() {
+        let tablebase = Tablebase::generate(2, |_| {});
+        let position = Position::from_fen("B:WK32:BK1").unwrap();
+        assert_eq!(tablebase.probe(&position), Some(TablebaseValue::Draw));
+        assert_eq!(tablebase.adjudicate(&position), Some(GameResult::Draw { reason: DrawReason::Adjudication }));
+    }
+
+    #[test]
+    fn positions_with_more_pieces_are_unknown() {
+        let tablebase = Tablebase::generate(2, |_| {});
+        assert_eq!(tablebase.probe(&Position::from_fen("B:WK32:BK1,K5").unwrap()), None);
+        assert_eq!(tablebase.adjudicate(&Position::initial()), None);
+    }
+}
@@ -0,0 +1,42 @@
+use core::fmt;
+use chrono::TimeDelta;
+use serde::{Deserialize, Serialize};
+
+
+pub const CORRESPONDENCE_DAYS_PER_MOVE: [u32; 3] = [1, 3, 7];
+
+
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum TimeControl {
+    #[default]
+    Unlimited,
+    Correspondence { days_per_move: u32 },
+}
+
+impl TimeControl {
+    /// All time controls which can be selected when creating a new game.
+    pub fn all() -> Vec<TimeControl> {
+        std::iter::once(TimeControl::Unlimited)
+            .chain(CORRESPONDENCE_DAYS_PER_MOVE.iter().map(|days_per_move| {
+                TimeControl::Correspondence { days_per_move: *days_per_move }
+            }))
+            .collect()
+    }
+
+    pub fn time_per_move(&self) -> Option<TimeDelta> {
+        match self {
+            TimeControl::Unlimited => None,
+            TimeControl::Correspondence { days_per_move } => Some(TimeDelta::days(*days_per_move as i64)),
+        }
+    }
+}
+
+impl fmt::Display for TimeControl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimeControl::Unlimited => write!(f, "Unlimited"),
+            TimeControl::Correspondence { days_per_move: 1 } => write!(f, "1 day per move"),
+            TimeControl::Correspondence { days_per_move } => write!(f, "{} days per move", days_per_move),
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use serde::{Deserialize, Serialize};
+
+use super::board::Position;
+use super::moves::Move;
+use super::notation::{format_move, parse_move};
+use super::pdn::{PdnError, PdnGame, PdnMove};
+
+pub type NodeId = usize;
+
+const ENGLISH_CHECKERS_GAME_TYPE: &str = "21";
+const UNKNOWN_RESULT: &str = "*";
+
+
+/// A position of the tree, reached by the move from the position of its parent.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub struct VariationNode {
+    pub parent: Option<NodeId>,
+    /// `None` for the root, which holds the initial position.
+    pub mv: Option<Move>,
+    pub position: Position,
+    /// The moves played from this position, the first one continues the main line.
+    pub children: Vec<NodeId>,
+    pub comment: String,
+    /// Numeric annotation glyphs of the move.
+    pub nags: Vec<u8>,
+}
+
+
+/// The lines explored on the analysis board. Deleted nodes leave a gap, so the ids of the
+///  other nodes stay valid.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub struct VariationTree {
+    nodes: Vec<Option<VariationNode>>,
+}
+
+impl Default for VariationTree {
+    fn default() -> Self {
+        VariationTree::new(Position::initial())
+    }
+}
+
+impl VariationTree {
+    pub const ROOT: NodeId = 0;
+
+    pub fn new(initial_position: Position) -> Self {
+        Self {
+            nodes: vec![Some(VariationNode {
+                parent: None,
+                mv: None,
+                position: initial_position,
+                children: Vec::new(),
+                comment: String::new(),
+                nags: Vec::new(),
+            })],
+        }
+    }
+
+    /// A tree with the moves of a game as main line.
+    pub fn from_moves(initial_position: Position, moves: &[Move]) -> Self {
+        let mut tree = VariationTree::new(initial_position);
+        moves.iter().fold(VariationTree::ROOT, |node, mv| tree.play(node, mv.clone()));
+        tree
+    }
+
+    pub fn node(&self, id: NodeId) -> Option<&VariationNode> {
+        self.nodes.get(id).and_then(Option::as_ref)
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> Option<&mut VariationNode> {
+        self.nodes.get_mut(id).and_then(Option::as_mut)
+    }
+
+    pub fn initial_position(&self) -> Position {
+        self.node(VariationTree::ROOT).expect("The root is never deleted.").position
+    }
+
+    /// Plays the move in the position of the node and returns the node reached. A move
+    ///  played before is not added again. The move must be legal.
+    pub fn play(&mut self, parent: NodeId, mv: Move) -> NodeId {
+        let Some(parent_node) = self.node(parent) else {
+            return parent;
+        };
+        if let Some(&existing) = parent_node.children.iter().find(|&&child| self.node(child).and_then(|node| node.mv.as_ref()) == Some(&mv)) {
+            return existing;
+        }
+        let position = parent_node.position.after(&mv);
+        let id = self.nodes.len();
+        self.nodes.push(Some(VariationNode {
+            parent: Some(parent),
+            mv: Some(mv),
+            position,
+            children: Vec::new(),
+            comment: String::new(),
+            nags: Vec::new(),
+        }));
+        self.node_mut(parent).expect("The parent exists.").children.push(id);
+        id
+    }
+
+    /// Removes the node with all lines following it. The root cannot be deleted.
+    pub fn delete(&mut self, id: NodeId) {
+        let Some(parent) = self.node(id).and_then(|node| node.parent) else {
+            return;
+        };
+        self.node_mut(parent).expect("The parent exists.").children.retain(|&child| child != id);
+        let mut deleted = vec![id];
+        while let Some(id) = deleted.pop() {
+            if let Some(node) = self.nodes.get_mut(id).and_then(Option::take) {
+                deleted.extend(node.children);
+            }
+        }
+    }
+
+    /// Makes the line of the node the main continuation of its parent.
+    pub fn promote(&mut self, id: NodeId) {
+        let Some(parent) = self.node(id).and_then(|node| node.parent) else {
+            return;
+        };
+        let children = &mut self.node_mut(parent).expect("The parent exists.").children;
+        children.retain(|&child| child != id);
+        children.insert(0, id);
+    }
+
+    pub fn set_comment(&mut self, id: NodeId, comment: String) {
+        if let Some(node) = self.node_mut(id) {
+            node.comment = comment;
+        }
+    }
+
+    /// Adds the annotation glyph to the move or removes it, if the move already has it.
+    pub fn toggle_nag(&mut self, id: NodeId, nag: u8) {
+        let Some(node) = self.node_mut(id).filter(|node| node.mv.is_some()) else {
+            return;
+        };
+        match node.nags.iter().position(|&existing| existing == nag) {
+            Some(index) => {
+                node.nags.remove(index);
+            }
+            None => node.nags.push(nag),
+        }
+    }
+
+    /// The nodes from the root to the node, both included.
+    pub fn line(&self, id: NodeId) -> Vec<NodeId> {
+        let mut line = Vec::new();
+        let mut current = self.node(id).map(|_| id);
+        while let Some(id) = current {
+            line.push(id);
+            current = self.node(id).and_then(|node| node.parent);
+        }
+        line.reverse();
+        line
+    }
+
+    /// The main continuation from the node to the end of its line.
+    pub fn main_line(&self, id: NodeId) -> Vec<NodeId> {
+        let mut line = Vec::new();
+        let mut current = self.node(id).and_then(|node| node.children.first().copied());
+        while let Some(id) = current {
+            line.push(id);
+            current = self.node(id).and_then(|node| node.children.first().copied());
+        }
+        line
+    }
+
+    /// The index among its siblings of every node from the root to the node, which
+    ///  identifies the node independent of the ids, e.g. in a link.
+    pub fn path(&self, id: NodeId) -> Vec<usize> {
+        self.line(id).windows(2)
+            .filter_map(|pair| {
+                let parent = self.node(pair[0])?;
+                parent.children.iter().position(|&child| child == pair[1])
+            })
+            .collect()
+    }
+
+    pub fn node_at_path(&self, path: &[usize]) -> Option<NodeId> {
+        path.iter().try_fold(VariationTree::ROOT, |id, &index| self.node(id)?.children.get(index).copied())
+    }
+
+    /// The tree as PDN game, the variations are alternatives to the main move they follow.
+    pub fn to_pdn(&self) -> PdnGame {
+        let root = self.node(VariationTree::ROOT).expect("The root is never deleted.");
+        let mut tags = vec![
+            ("Result".to_string(), UNKNOWN_RESULT.to_string()),
+            ("GameType".to_string(), ENGLISH_CHECKERS_GAME_TYPE.to_string()),
+        ];
+        if root.position != Position::initial() {
+            tags.push(("FEN".to_string(), root.position.to_fen()));
+        }
+        PdnGame {
+            tags,
+            comments: (!root.comment.is_empty()).then(|| root.comment.clone()).into_iter().collect(),
+            moves: root.children.first().map(|&first| self.pdn_moves(first)).unwrap_or_default(),
+            result: Some(UNKNOWN_RESULT.to_string()),
+            line: 0,
+        }
+    }
+
+    /// The line starting with the node, each main move followed by its alternatives.
+    fn pdn_moves(&self, first: NodeId) -> Vec<PdnMove> {
+        let mut moves = Vec::new();
+        let mut current = Some(first);
+        while let Some(id) = current {
+            let node = self.node(id).expect("Children of existing nodes exist.");
+            let parent = self.node(node.parent.expect("Only the root has no parent.")).expect("The parent exists.");
+            let mv = node.mv.as_ref().expect("Only the root has no move.");
+            let mut pdn_move = PdnMove::new(format_move(&parent.position, mv));
+            pdn_move.nags = node.nags.clone();
+            pdn_move.comments = (!node.comment.is_empty()).then(|| node.comment.clone()).into_iter().collect();
+            if parent.children.first() == Some(&id) {
+                pdn_move.variations = parent.children[1..].iter().map(|&sibling| self.pdn_moves(sibling)).collect();
+            }
+            moves.push(pdn_move);
+            current = node.children.first().copied();
+        }
+        moves
+    }
+
+    pub fn from_pdn(game: &PdnGame) -> Result<Self, PdnError> {
+        let initial_position = match game.tag("FEN") {
+            Some(fen) => Position::from_fen(fen).map_err(|error| PdnError::Parse {
+                line: game.line,
+                column: 1,
+                message: format!("Invalid FEN tag: {}", error),
+            })?,
+            None => Position::initial(),
+        };
+        let mut tree = VariationTree::new(initial_position);
+        tree.set_comment(VariationTree::ROOT, game.comments.join(" "));
+        tree.add_pdn_moves(VariationTree::ROOT, &game.moves)?;
+        Ok(tree)
+    }
+
+    fn add_pdn_moves(&mut self, parent: NodeId, moves: &[PdnMove]) -> Result<(), PdnError> {
+        let mut parent = parent;
+        for pdn_move in moves {
+            let position = self.node(parent).expect("The parent exists.").position;
+            let mv = parse_move(&position, &pdn_move.notation).map_err(|error| PdnError::Parse {
+                line: pdn_move.line,
+                column: pdn_move.column,
+                message: error.to_string(),
+            })?;
+            let id = self.play(parent, mv);
+            self.set_comment(id, pdn_move.comments.join(" "));
+            for &nag in &pdn_move.nags {
+                self.toggle_nag(id, nag);
+            }
+            // the main move is added first, so it stays the main continuation
+            for variation in &pdn_move.variations {
+                self.add_pdn_moves(parent, variation)?;
+            }
+            parent = id;
+        }
+        Ok(())
+    }
+}
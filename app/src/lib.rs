@@ -5,19 +5,23 @@ use leptos_meta::*;
 
 pub mod pages;
 pub mod components;
+pub mod game;
 mod error_template;
 mod utils;
 
 use pages::app_router::AppRouter;
+use components::i18n::provide_locale;
 
 #[component]
 pub fn App() -> impl IntoView {
     // Provides context that manages stylesheets, titles, meta tags, etc.
     provide_meta_context();
+    let locale = provide_locale().locale;
 
     //leptos_server_signal::provide_websocket_with_retry("/ssws", 5000).unwrap();
 
     view! {
+        <Html lang=move || locale.get().code()/>
         <Stylesheet id="leptos" href="/pkg/checker.css"/>
         <Title text="Play Checker"/>
         <AppRouter/>
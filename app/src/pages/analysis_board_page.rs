@@ -0,0 +1,100 @@
+use leptos::*;
+use leptos_router::{use_navigate, use_query_map, NavigateOptions};
+
+use crate::components::analysis_board::{
+    analysis_board_url, parse_node_path, AnnotationEditor, LiveEvaluation, VariationTreeView, NODE_PARAMETER, PDN_PARAMETER,
+};
+use crate::components::board::GameBoard;
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::board::Position;
+use crate::game::moves::Move;
+use crate::game::pdn::{parse_pdn, PdnError};
+use crate::game::player_color::PlayerColor;
+use crate::game::variation_tree::VariationTree;
+use crate::pages::game_page::CopyToClipboardButton;
+
+
+/// A free board to explore lines from any position. The whole tree lives in the link, so
+///  sharing the link shares the analysis.
+#[component]
+pub fn AnalysisBoardPage() -> impl IntoView {
+    let locale = use_locale();
+    let query = use_query_map();
+    let (initial_tree, initial_node, link_error) = query.with_untracked(|query| {
+        let Some(pdn) = query.get(PDN_PARAMETER) else {
+            return (VariationTree::default(), VariationTree::ROOT, None);
+        };
+        let tree = parse_pdn(pdn).and_then(|games| match games.first() {
+            Some(game) => VariationTree::from_pdn(game),
+            None => Err(PdnError::UnknownError("The link contains no moves.".to_string())),
+        });
+        match tree {
+            Ok(tree) => {
+                let node = query.get(NODE_PARAMETER)
+                    .and_then(|path| parse_node_path(&tree, path))
+                    .unwrap_or(VariationTree::ROOT);
+                (tree, node, None)
+            }
+            Err(error) => (VariationTree::default(), VariationTree::ROOT, Some(error)),
+        }
+    });
+    let tree = create_rw_signal(initial_tree);
+    let current = create_rw_signal(initial_node);
+    let orientation = create_rw_signal(PlayerColor::Red);
+
+    let position = Signal::derive(move || {
+        tree.with(|tree| tree.node(current.get()).map(|node| node.position)).unwrap_or_else(Position::initial)
+    });
+    let last_move = Signal::derive(move || tree.with(|tree| tree.node(current.get()).and_then(|node| node.mv.clone())));
+    let parent = move || tree.with(|tree| tree.node(current.get()).and_then(|node| node.parent));
+    let next = move || tree.with(|tree| tree.node(current.get()).and_then(|node| node.children.first().copied()));
+    let last = move || tree.with(|tree| tree.main_line(current.get()).last().copied());
+    let on_move = Callback::new(move |mv: Move| {
+        if position.get_untracked().is_legal(&mv) {
+            if let Some(id) = tree.try_update(|tree| tree.play(current.get_untracked(), mv)) {
+                current.set(id);
+            }
+        }
+    });
+
+    // The link follows every change, so reloading or sharing it shows the same analysis.
+    let url = Signal::derive(move || tree.with(|tree| analysis_board_url(tree, current.get())));
+    let navigate = use_navigate();
+    create_effect(move |_| {
+        navigate(&url.get(), NavigateOptions { replace: true, scroll: false, ..Default::default() });
+    });
+    let origin = create_rw_signal(String::new());
+    create_effect(move |_| origin.set(window().location().origin().unwrap_or_default()));
+
+    view! {
+        {link_error.map(|error| view! {
+            <p class="text-error text-center" role="alert">{move || Text::Error(error.translate(locale.get())).translate(locale.get())}</p>
+        })}
+        <div class="flex flex-wrap justify-center gap-4 p-2">
+            <GameBoard
+                position=position
+                last_move=last_move
+                player_color=Signal::derive(move || Some(position.get().side_to_move))
+                on_move=on_move
+                orientation=orientation
+            />
+            <VariationTreeView tree=tree current=current/>
+        </div>
+        <div class="flex justify-center items-center p-2">
+            <button class="btn btn-xs m-1" aria-label=move || Text::FirstPosition.translate(locale.get()) disabled=move || parent().is_none() on:click=move |_| current.set(VariationTree::ROOT)>"|<"</button>
+            <button class="btn btn-xs m-1" aria-label=move || Text::PreviousMove.translate(locale.get()) disabled=move || parent().is_none() on:click=move |_| current.set(parent().unwrap_or(VariationTree::ROOT))>"<"</button>
+            <button class="btn btn-xs m-1" aria-label=move || Text::NextMove.translate(locale.get()) disabled=move || next().is_none() on:click=move |_| current.set(next().unwrap_or_else(|| current.get_untracked()))>">"</button>
+            <button class="btn btn-xs m-1" aria-label=move || Text::LastPosition.translate(locale.get()) disabled=move || next().is_none() on:click=move |_| current.set(last().unwrap_or_else(|| current.get_untracked()))>">|"</button>
+            <button class="btn btn-xs m-1" on:click=move |_| orientation.update(|orientation| *orientation = orientation.opponent())>
+                {move || Text::FlipBoard.translate(locale.get())}
+            </button>
+            <CopyToClipboardButton
+                text_to_copy=Signal::derive(move || origin.get() + &url.get())
+                text=Signal::derive(move || Text::Share.translate(locale.get()))
+                class="btn btn-primary btn-xs m-1"
+            />
+        </div>
+        <LiveEvaluation position=position on_move=on_move/>
+        <AnnotationEditor tree=tree current=current/>
+    }
+}
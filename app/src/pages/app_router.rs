@@ -5,6 +5,9 @@ use crate::error_template::{AppError, ErrorTemplate};
 use crate::pages::game_page::GamePage;
 use crate::pages::landing_page::LandingPage;
 use crate::pages::new_game_page::NewGamePage;
+use crate::pages::offline_game_page::OfflineGamePage;
+use crate::pages::hotseat_game_page::HotseatGamePage;
+use crate::pages::analysis_board_page::AnalysisBoardPage;
 
 #[component]
 pub fn AppRouter() -> impl IntoView {
@@ -19,6 +22,9 @@ pub fn AppRouter() -> impl IntoView {
                 <Routes>
                     <Route path="/" view=LandingPage>
                         <Route path="" view=NewGamePage/>
+                        <Route path="/offline" view=OfflineGamePage/>
+                        <Route path="/hotseat" view=HotseatGamePage/>
+                        <Route path="/analysis" view=AnalysisBoardPage/>
                         <Route path="/games" view=|| view! { <Outlet/> }>
                             <Route path="" view=NewGamePage/>
                             <Route path=":id" view=GamePage/>
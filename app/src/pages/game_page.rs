@@ -1,3 +1,4 @@
+use core::fmt;
 use std::{collections::HashMap, hash::Hash, sync::Arc, time::Duration};
 use leptos::*;
 use leptos_router::{Params, use_params, use_location};
@@ -12,7 +13,29 @@ use chrono::{DateTime, Utc, TimeDelta};
 use leptos_use::core::ConnectionReadyState;
 use serde_json::to_string;
 
-use crate::components::player::{self, PlayerInformation};
+use crate::components::player::{self, PlayerInformation, use_players_socket};
+use crate::components::board::{GameAnnouncer, GameBoard, MoveInput};
+use crate::components::move_history::MoveHistory;
+use crate::components::analysis::{use_game_analysis, GameAnalysisPanel};
+use crate::components::analysis_board::analysis_board_url;
+use crate::components::conditional_moves::{ConditionalMovesEditor, set_premove};
+use crate::components::appearance::use_appearance;
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::utils::sound_effect::SoundEffect;
+use crate::components::clock::GameClock;
+use crate::components::ballot::BallotInfo;
+use crate::components::game_settings::get_game_settings;
+use crate::game::{
+    board::Position, game_result::GameResult, moves::Move,
+    notation::{format_move, format_move_path, parse_moves, NotationError},
+    player_color::PlayerColor, variation_tree::VariationTree,
+};
+#[cfg(feature = "ssr")]
+use crate::game::{
+    adjournment::Adjournment, analysis::GameAnalysis, clock::Clock, conditional_moves::ConditionalMoves, game_record::{GameRecord, RecordedMove},
+    game_result::WinReason, game_settings::{GameSettings, GameSettingsError}, notation::{format_moves, parse_move},
+    pdn::{export_annotated_pdn, export_pdn}, tablebase::Tablebase,
+};
 
 
 
@@ -45,37 +68,259 @@ pub fn GamePage() -> impl IntoView {
     };
     let player_number = create_rw_signal::<Option<usize>>(None);
     let error_message = create_rw_signal::<Option<String>>(None);
+    let locale = use_locale();
+    // the page is kept when another game is opened, e.g. a return game, so the seat
+    //  of the previous game is not taken along
+    create_effect(move |previous_id: Option<Option<Uuid>>| {
+        let current_id = id();
+        if previous_id.is_some_and(|previous_id| previous_id != current_id) {
+            player_number.set(None);
+        }
+        current_id
+    });
 
     view! {
         <Show when=move || {error_message.get().is_some()}>
-            <p class="content-error">"Error: "{error_message.get().unwrap()}</p>
+            <p class="content-error">{move || Text::Error(error_message.get().unwrap_or_default()).translate(locale.get())}</p>
         </Show>
         <Show when=move || {player_number.get().is_some()}>
-            <p class="content-success">{format!("Player: {}", player_number.get().unwrap())}</p>
+            <p class="content-success">{move || Text::PlayerNumber(player_number.get().unwrap_or_default()).translate(locale.get())}</p>
         </Show>
         <Show
             when=move || { id().is_some() }
-            fallback=|| view! {
+            fallback=move || view! {
                 <ErrorMessage>
                     <div>
-                        <h3 class="font-bold">Invalid game ID!</h3>
-                        <div class="text-xs">Go back and create a new Game.</div>
+                        <h3 class="font-bold">{move || Text::InvalidGameId.translate(locale.get())}</h3>
+                        <div class="text-xs">{move || Text::CreateNewGameHint.translate(locale.get())}</div>
                     </div>
-                    <a class="btn btn-sm btn-error border-primary-content" href="/games">Back</a>
+                    <a class="btn btn-sm btn-error border-primary-content" href="/games">
+                        {move || Text::Back.translate(locale.get())}
+                    </a>
                 </ErrorMessage>
             }
         >
-            <GameInfo game_id=Signal::derive(id)/>
-            <PlayerInformation 
-                game_id=Signal::derive(move || id().unwrap())
-                player_number=player_number
-                error_message=error_message
-            />
+            {move || id().map(|game_id| {
+                let game_id = Signal::derive(move || game_id);
+                view! {
+                    <GameInfo game_id=Signal::derive(id)/>
+                    <BallotInfo game_id=game_id/>
+                    <GameClock game_id=game_id player_number=player_number/>
+                    <LiveGame
+                        game_id=game_id
+                        player_number=player_number
+                        error_message=error_message
+                    />
+                }
+            })}
             /*<PlayerAssignment game_id=Signal::derive(move || id().unwrap())/>*/
         </Show>
     }
 }
 
+/// The board and the players of a running game, both kept up to date over the players websocket.
+#[component]
+pub fn LiveGame(
+    #[prop(into)]
+    game_id: Signal<Uuid>,
+    #[prop(into)]
+    player_number: RwSignal<Option<usize>>,
+    #[prop(into)]
+    error_message: RwSignal<Option<String>>,
+) -> impl IntoView {
+    let player_secret = create_rw_signal::<Option<String>>(None);
+    let locale = use_locale();
+    let players_socket = use_players_socket(game_id, player_number, player_secret);
+    let initial_position = players_socket.initial_position;
+    let moves = players_socket.moves;
+    let move_error = players_socket.move_error;
+    let live_game = create_memo(move |_| {
+        let initial_position = Position::from_fen(&initial_position.get()?).ok()?;
+        let moves = parse_moves(&initial_position, &moves.get()).ok()?;
+        Some((initial_position, moves))
+    });
+    // The positions after every ply, starting with the initial position.
+    let positions = create_memo(move |_| {
+        live_game.get().map(|(initial_position, moves)| {
+            moves.iter().fold(vec![initial_position], |mut positions, mv| {
+                let position = positions.last().expect("There is at least the initial position.").after(mv);
+                positions.push(position);
+                positions
+            })
+        })
+    });
+    let position = create_memo(move |_| positions.get().and_then(|positions| positions.last().copied()));
+    let viewed_ply = create_rw_signal::<Option<usize>>(None);
+    let viewed_position = Signal::derive(move || {
+        positions.with(|positions| {
+            let positions = positions.as_ref()?;
+            viewed_ply.get().and_then(|ply| positions.get(ply)).or(positions.last()).copied()
+        })
+    });
+    let viewed_last_move = Signal::derive(move || {
+        live_game.with(|live_game| {
+            let (_, moves) = live_game.as_ref()?;
+            let ply = viewed_ply.get().unwrap_or(moves.len());
+            ply.checked_sub(1).and_then(|index| moves.get(index)).cloned()
+        })
+    });
+    let first_player = Signal::derive(move || {
+        live_game.with(|live_game| live_game.as_ref().map(|(initial_position, _)| initial_position.side_to_move))
+            .unwrap_or(PlayerColor::Red)
+    });
+    let player_color = Signal::derive(move || player_number.get().and_then(PlayerColor::from_player_number));
+    let play_move = players_socket.play_move.clone();
+    let on_move = Callback::new(move |mv: Move| {
+        if let Some(position) = position.get_untracked() {
+            play_move(format_move(&position, &mv));
+        }
+    });
+    let premove = create_rw_signal::<Option<Move>>(None);
+    // The server plays or drops the premove with the next move of the opponent.
+    create_effect(move |_| {
+        moves.track();
+        premove.set(None);
+    });
+    let send_premove = move |mv: Option<Move>| {
+        let (Some(player_number), Some(player_secret)) = (player_number.get_untracked(), player_secret.get_untracked()) else {
+            return;
+        };
+        premove.set(mv.clone());
+        spawn_local(async move {
+            let notation = mv.as_ref().map(format_move_path);
+            if let Err(error) = set_premove(game_id.get_untracked(), player_number, player_secret, notation).await {
+                logging::error!("Cannot queue premove: {:?}", error);
+                premove.set(None);
+                error_message.set(Some(error.translate(locale.get_untracked())));
+            }
+        });
+    };
+    let on_premove = Callback::new(move |mv: Move| send_premove(Some(mv)));
+    let typed_move_position = Signal::derive(move || {
+        position.get().filter(|position| viewed_ply.get().is_none() && player_color.get() == Some(position.side_to_move))
+    });
+    let live_last_move = Signal::derive(move || live_game.with(|live_game| {
+        live_game.as_ref().and_then(|(_, moves)| moves.last().cloned())
+    }));
+
+    let appearance = use_appearance();
+    // Sounds are only played for changes after the game was loaded.
+    create_effect(move |previous_number_of_plies: Option<Option<usize>>| {
+        let number_of_plies = positions.with(|positions| positions.as_ref().map(|positions| positions.len() - 1));
+        let is_next_ply = previous_number_of_plies.flatten().zip(number_of_plies)
+            .is_some_and(|(previous, current)| current == previous + 1);
+        if is_next_ply && appearance.get_untracked().sounds {
+            let sound = positions.with_untracked(|positions| {
+                let positions = positions.as_ref()?;
+                let [.., before, after] = positions.as_slice() else { return None };
+                let mv = live_last_move.get_untracked()?;
+                let is_promotion = before.board.get(mv.from()).is_some_and(|piece| !piece.is_king())
+                    && after.board.get(mv.to()).is_some_and(|piece| piece.is_king());
+                Some(match (is_promotion, mv.is_capture()) {
+                    (true, _) => SoundEffect::Promotion,
+                    (false, true) => SoundEffect::Capture,
+                    (false, false) => SoundEffect::Move,
+                })
+            });
+            if let Some(sound) = sound {
+                sound.play();
+            }
+        }
+        number_of_plies
+    });
+    let result = players_socket.result;
+    let analysis = use_game_analysis(game_id, result);
+    let judgements = Signal::derive(move || analysis.with(|analysis| {
+        analysis.iter().flat_map(|analysis| analysis.plies.iter().map(|ply| ply.judgement)).collect::<Vec<_>>()
+    }));
+    // The analysis board starts with the game as main line, at the viewed position.
+    let analysis_board_link = move || live_game.with(|live_game| {
+        let (initial_position, moves) = live_game.as_ref()?;
+        let mut tree = VariationTree::from_moves(*initial_position, moves);
+        for (ply, judgement) in judgements.get().into_iter().enumerate() {
+            if let (Some(judgement), Some(node)) = (judgement, tree.node_at_path(&vec![0; ply + 1])) {
+                tree.toggle_nag(node, judgement.nag());
+            }
+        }
+        let node = tree.node_at_path(&vec![0; viewed_ply.get().unwrap_or(moves.len())])?;
+        Some(analysis_board_url(&tree, node))
+    });
+    create_effect(move |previous_result: Option<Option<GameResult>>| {
+        let current_result = result.get();
+        let has_ended = previous_result.is_some_and(|previous_result| previous_result.is_none()) && current_result.is_some();
+        if has_ended && appearance.get_untracked().sounds {
+            SoundEffect::GameEnd.play();
+        }
+        current_result
+    });
+
+    view! {
+        <div class="flex flex-wrap justify-center gap-4 p-2">
+            <Show
+                when=move || position.get().is_some()
+                fallback=|| view! { <span class="loading loading-spinner text-primary"></span> }
+            >
+                <GameBoard
+                    position=Signal::derive(move || viewed_position.get().unwrap_or_else(Position::initial))
+                    last_move=viewed_last_move
+                    player_color=player_color
+                    on_move=on_move
+                    is_read_only=Signal::derive(move || viewed_ply.get().is_some())
+                    on_premove=on_premove
+                    premove=Signal::derive(move || premove.get())
+                />
+                <MoveHistory moves=moves first_player=first_player viewed_ply=viewed_ply judgements=judgements/>
+            </Show>
+        </div>
+        <MoveInput position=typed_move_position on_move=on_move/>
+        <GameAnnouncer
+            moves=moves
+            last_move=live_last_move
+            first_player=first_player
+            player_color=player_color
+            result=result
+        />
+        <Show when=move || move_error.get().is_some()>
+            <p class="text-error" role="alert">
+                {move || move_error.get().map(|move_error| move_error.translate(locale.get()))}
+            </p>
+        </Show>
+        <Show when=move || premove.get().is_some()>
+            <div class="flex justify-center items-center p-2">
+                <p class="m-1">{move || premove.get().map(|mv| Text::Premove(format_move_path(&mv)).translate(locale.get()))}</p>
+                <button class="btn btn-xs m-1" on:click=move |_| send_premove(None)>
+                    {move || Text::CancelPremove.translate(locale.get())}
+                </button>
+            </div>
+        </Show>
+        <div class="flex justify-center p-2">
+            <a class="btn btn-xs" href=analysis_board_link>
+                {move || Text::AnalysisBoard.translate(locale.get())}
+            </a>
+        </div>
+        <GameAnalysisPanel
+            analysis=analysis
+            result=result
+            moves=moves
+            first_player=first_player
+            viewed_ply=viewed_ply
+        />
+        <ConditionalMovesEditor
+            game_id=game_id
+            player_number=player_number
+            player_secret=player_secret
+            moves=moves
+        />
+        <PlayerInformation
+            game_id=game_id
+            player_number=player_number
+            player_secret=player_secret
+            players=players_socket.players
+            error_message=error_message
+        />
+    }
+}
+
 #[derive(Deserialize, Serialize, Copy, Clone, PartialEq, Eq, Debug)]
 pub enum PlayerAssignmentStatus {
     REFUSED,
@@ -95,6 +340,12 @@ pub struct Player {
     pub last_ping: Option<DateTime<Utc>>,
     pub player_number: usize,
     pub is_assigned: bool,
+    /// The seat is played by the computer, which is always connected.
+    #[serde(default)]
+    pub is_bot: bool,
+    /// The computer seat is played by an external engine over DXP instead of the built-in bot.
+    #[serde(default)]
+    pub is_engine: bool,
 }
 
 #[derive(Clone, Default, Serialize, Deserialize, Debug)]
@@ -105,14 +356,42 @@ pub struct PlayerIdentity {
 }
 
 #[derive(Clone, Serialize, Deserialize)]
-enum PlayerClientData {
+pub enum PlayerClientData {
     SelectGame(Uuid),
     Alive(PlayerIdentity),
+    PlayMove(PlayerIdentity, String),
 }
 
 #[derive(Clone, Serialize, Deserialize)]
-enum PlayerServerData {
+pub enum PlayerServerData {
     PlayerList(Vec<Player>),
+    /// The moves played so far in numeric notation, starting from the given FEN position.
+    Moves { initial_position: String, moves: Vec<String>, result: Option<GameResult> },
+    MoveRejected(MoveRejection),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MoveRejection {
+    GameFinished,
+    GameNotRunning,
+    NotYourTurn,
+    InvalidPlayerIdentity,
+    /// The move was entered before joining the game, so it was not sent.
+    NotJoined,
+    InvalidMove(NotationError),
+}
+
+impl fmt::Display for MoveRejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MoveRejection::GameFinished => write!(f, "The game is already finished."),
+            MoveRejection::GameNotRunning => write!(f, "The game is not running."),
+            MoveRejection::NotYourTurn => write!(f, "It is not your turn."),
+            MoveRejection::InvalidPlayerIdentity => write!(f, "Invalid player identity."),
+            MoveRejection::NotJoined => write!(f, "Join the game before playing a move."),
+            MoveRejection::InvalidMove(error) => write!(f, "{}", error),
+        }
+    }
 }
 
 
@@ -166,10 +445,38 @@ impl PlayerInfo {
                 last_ping: None,
                 player_number,
                 is_assigned: false,
+                is_bot: false,
+                is_engine: false,
+            },
+            secret: None,
+        }
+    }
+
+    /// A seat taken by the computer. It has no secret, nobody can act for the bot.
+    pub fn bot(player_number: usize, name: &str) -> Self {
+        Self {
+            public_data: Player {
+                name: Some(name.to_string()),
+                last_ping: None,
+                player_number,
+                is_assigned: true,
+                is_bot: true,
+                is_engine: false,
             },
             secret: None,
         }
     }
+
+    /// A seat taken by an external engine, which is driven by its DXP connection.
+    pub fn engine(player_number: usize, name: &str) -> Self {
+        let mut engine = PlayerInfo::bot(player_number, name);
+        engine.public_data.is_engine = true;
+        engine
+    }
+
+    pub fn has_secret(&self, secret: &str) -> bool {
+        self.public_data.is_assigned && self.secret.as_deref() == Some(secret)
+    }
 }
 
 #[cfg(feature = "ssr")]
@@ -193,19 +500,46 @@ impl PlayerList {
 #[derive(Clone, Debug)]
 pub struct Game {
     data: Arc<dashmap::DashMap<&'static str, String>>,
+    /// Held while the game is changed based on its current state, e.g. while a move is
+    ///  checked and played, so that websocket, bot, engine and schedulers do not interleave.
+    lock: Arc<tokio::sync::Mutex<()>>,
+    /// Adjudicates endgames as soon as a capture or promotion brings them into the tablebase.
+    tablebase: Option<Arc<Tablebase>>,
 }
 
 #[cfg(feature = "ssr")]
 impl Game {
-    pub async fn new(id: Uuid) -> Self {
+    pub async fn new(id: Uuid, settings: GameSettings) -> Result<Self, GameSettingsError> {
+        let initial_position = settings.initial_position()?;
         let data = Arc::new(dashmap::DashMap::new());
         data.insert("id", serde_json::to_string(&id).unwrap());
         let players_string = serde_json::to_string(&PlayerList::new(2)).unwrap();        
         data.insert("players", players_string);
+        let mut clock = Clock::new(settings.time_control, settings.handicap.time_odds());
+        clock.active_player = initial_position.side_to_move;
+        data.insert("clock", serde_json::to_string(&clock).unwrap());
+        data.insert("initial_position", serde_json::to_string(&initial_position).unwrap());
+        data.insert("position", serde_json::to_string(&initial_position).unwrap());
+        data.insert("result", serde_json::to_string(&None::<GameResult>).unwrap());
+        data.insert("settings", serde_json::to_string(&settings).unwrap());
+        data.insert("adjournment", serde_json::to_string(&Adjournment::default()).unwrap());
+        data.insert("return_game", serde_json::to_string(&None::<Uuid>).unwrap());
+        data.insert("created_at", serde_json::to_string(&Utc::now()).unwrap());
+        data.insert("moves", serde_json::to_string(&Vec::<RecordedMove>::new()).unwrap());
+        data.insert("conditional_moves", serde_json::to_string(&<[ConditionalMoves; 2]>::default()).unwrap());
+        data.insert("analysis", serde_json::to_string(&None::<GameAnalysis>).unwrap());
 
-        Self {
-            data
-        }
+        Ok(Self {
+            data,
+            lock: Arc::new(tokio::sync::Mutex::new(())),
+            tablebase: None,
+        })
+    }
+
+    /// Locks the game for a change which depends on its state. The lock is not reentrant,
+    ///  so `play_move` must not be called while holding it.
+    pub async fn lock(&self) -> tokio::sync::MutexGuard<'_, ()> {
+        self.lock.lock().await
     }
 
     pub async fn id(&self) -> Uuid {
@@ -232,30 +566,283 @@ impl Game {
             *v = serde_json::to_string(&updated_players).expect("Cannot serialize player list.");
         });
     }
+
+    pub async fn created_at(&self) -> DateTime<Utc> {
+        self.value("created_at")
+    }
+
+    pub async fn settings(&self) -> GameSettings {
+        self.value("settings")
+    }
+
+    pub async fn with_settings<F>(&self, update_func: F)
+    where 
+        F: FnOnce(GameSettings) -> GameSettings
+    {
+        self.with_value("settings", update_func);
+    }
+
+    pub async fn clock(&self) -> Clock {
+        self.value("clock")
+    }
+
+    pub async fn with_clock<F>(&self, update_func: F)
+    where 
+        F: FnOnce(Clock) -> Clock
+    {
+        self.with_value("clock", update_func);
+    }
+
+    pub async fn result(&self) -> Option<GameResult> {
+        self.value("result")
+    }
+
+    pub async fn set_result(&self, result: Option<GameResult>) {
+        self.with_value("result", |_: Option<GameResult>| result);
+    }
+
+    /// The engine analysis of the finished game, once the analysis worker got to it.
+    pub async fn analysis(&self) -> Option<GameAnalysis> {
+        self.value("analysis")
+    }
+
+    pub async fn set_analysis(&self, analysis: Option<GameAnalysis>) {
+        self.with_value("analysis", |_: Option<GameAnalysis>| analysis);
+    }
+
+    pub async fn initial_position(&self) -> Position {
+        self.value("initial_position")
+    }
+
+    pub async fn position(&self) -> Position {
+        self.value("position")
+    }
+
+    pub async fn moves(&self) -> Vec<RecordedMove> {
+        self.value("moves")
+    }
+
+    /// The moves played so far in numeric notation.
+    pub async fn move_notations(&self) -> Vec<String> {
+        let moves: Vec<_> = self.moves().await.into_iter().map(|recorded_move| recorded_move.mv).collect();
+        format_moves(&self.initial_position().await, &moves)
+    }
+
+    /// Plays a move given in numeric notation for the player, if it is their turn.
+    pub async fn play_move(&self, player_number: usize, notation: &str) -> Result<Move, MoveRejection> {
+        let _lock = self.lock().await;
+        if self.result().await.is_some() {
+            return Err(MoveRejection::GameFinished);
+        }
+        let clock = self.clock().await;
+        if !clock.is_started || clock.is_paused {
+            return Err(MoveRejection::GameNotRunning);
+        }
+        let position = self.position().await;
+        if PlayerColor::from_player_number(player_number) != Some(position.side_to_move) {
+            return Err(MoveRejection::NotYourTurn);
+        }
+
+        let mv = parse_move(&position, notation).map_err(MoveRejection::InvalidMove)?;
+        self.apply_move(&position, &mv).await;
+        self.play_conditional_moves(position, mv.clone()).await;
+        Ok(mv)
+    }
+
+    /// Answers the move with the moves the opponent prepared, as long as they have one.
+    async fn play_conditional_moves(&self, mut position: Position, mut last_move: Move) {
+        while self.result().await.is_none() {
+            let replying_player = position.side_to_move.opponent();
+            let mut reply = None;
+            self.with_conditional_moves(replying_player, |mut conditional_moves| {
+                // checked again, so moves prepared for another position are dropped instead of played
+                if conditional_moves.validate(&position).is_err() {
+                    return ConditionalMoves::default();
+                }
+                reply = conditional_moves.reply_to(&position, &last_move);
+                conditional_moves
+            }).await;
+            let Some(reply) = reply else {
+                break;
+            };
+            position = position.after(&last_move);
+            logging::log!("Playing prepared move of {} in game {}", replying_player, self.id().await);
+            self.apply_move(&position, &reply).await;
+            last_move = reply;
+        }
+    }
+
+    /// Plays a legal move and ends the game once the opponent has no moves left, or once the
+    ///  tablebase knows the result after a capture or promotion.
+    async fn apply_move(&self, position: &Position, mv: &Move) {
+        let next_position = position.after(mv);
+        let is_promotion = position.board.get(mv.from()).is_some_and(|piece| !piece.is_king())
+            && next_position.board.get(mv.to()).is_some_and(|piece| piece.is_king());
+        self.with_value("moves", |mut moves: Vec<RecordedMove>| {
+            moves.push(RecordedMove::new(mv.clone()));
+            moves
+        });
+        self.with_value("position", |_: Position| next_position);
+        self.with_clock(|mut clock| {
+            clock.switch_player(Utc::now());
+            clock
+        }).await;
+        let result = if next_position.legal_moves().is_empty() {
+            Some(GameResult::Win {
+                winner: position.side_to_move,
+                reason: WinReason::NoMovesLeft,
+            })
+        }
+        else if mv.is_capture() || is_promotion {
+            self.tablebase.as_ref().and_then(|tablebase| tablebase.adjudicate(&next_position))
+        }
+        else {
+            None
+        };
+        if let Some(result) = result {
+            self.set_result(Some(result)).await;
+            self.with_clock(|mut clock| {
+                clock.stop();
+                clock
+            }).await;
+        }
+    }
+
+    pub async fn conditional_moves(&self, player: PlayerColor) -> ConditionalMoves {
+        let conditional_moves: [ConditionalMoves; 2] = self.value("conditional_moves");
+        conditional_moves[player.player_number()].clone()
+    }
+
+    pub async fn with_conditional_moves<F>(&self, player: PlayerColor, update_func: F)
+    where 
+        F: FnOnce(ConditionalMoves) -> ConditionalMoves
+    {
+        self.with_value("conditional_moves", |mut conditional_moves: [ConditionalMoves; 2]| {
+            let index = player.player_number();
+            conditional_moves[index] = update_func(std::mem::take(&mut conditional_moves[index]));
+            conditional_moves
+        });
+    }
+
+    pub async fn record(&self) -> GameRecord {
+        GameRecord {
+            game_id: self.id().await,
+            settings: self.settings().await,
+            created_at: self.created_at().await,
+            player_names: self.players().await.into_iter().map(|player| player.public_data.name).collect(),
+            initial_position: self.initial_position().await,
+            moves: self.moves().await,
+            result: self.result().await,
+        }
+    }
+
+    /// Creates a finished game from a record, e.g. for imported games.
+    pub async fn from_record(record: &GameRecord) -> Result<Self, GameSettingsError> {
+        let game = Game::new(record.game_id, record.settings.clone()).await?;
+        game.data.insert("created_at", serde_json::to_string(&record.created_at).unwrap());
+        game.data.insert("initial_position", serde_json::to_string(&record.initial_position).unwrap());
+        game.data.insert("position", serde_json::to_string(&record.position()).unwrap());
+        game.data.insert("moves", serde_json::to_string(&record.moves).unwrap());
+        game.set_result(record.result).await;
+        game.with_player(|mut players| {
+            for (player, name) in players.players.iter_mut().zip(record.player_names.iter()) {
+                player.public_data.name = name.clone();
+            }
+            players
+        }).await;
+        Ok(game)
+    }
+
+    pub async fn return_game(&self) -> Option<Uuid> {
+        self.value("return_game")
+    }
+
+    pub async fn with_return_game<F>(&self, update_func: F)
+    where 
+        F: FnOnce(Option<Uuid>) -> Option<Uuid>
+    {
+        self.with_value("return_game", update_func);
+    }
+
+    pub async fn adjournment(&self) -> Adjournment {
+        self.value("adjournment")
+    }
+
+    pub async fn with_adjournment<F>(&self, update_func: F)
+    where 
+        F: FnOnce(Adjournment) -> Adjournment
+    {
+        self.with_value("adjournment", update_func);
+    }
+
+    fn value<T>(&self, key: &'static str) -> T
+    where
+        T: serde::de::DeserializeOwned
+    {
+        self.data.get(key).map(|v| {
+            serde_json::from_str(v.value())
+                .expect(format!("Cannot deserialize '{}' from string: '{:?}'", key, v.value()).as_str())
+        }).expect(format!("Cannot find key '{}' in game data.", key).as_str())
+    }
+
+    fn with_value<T, F>(&self, key: &'static str, update_func: F)
+    where
+        T: Serialize + serde::de::DeserializeOwned,
+        F: FnOnce(T) -> T
+    {
+        self.data.entry(key).and_modify(|v| {
+            let value: T = serde_json::from_str(v)
+                .expect(format!("Cannot deserialize '{}' from string: '{:?}'", key, v).as_str());
+            *v = serde_json::to_string(&update_func(value))
+                .expect(format!("Cannot serialize '{}'.", key).as_str());
+        });
+    }
 }
 
 #[cfg(feature = "ssr")]
 pub struct GameState {
     game: tokio::sync::RwLock<HashMap<Uuid, Game>>,
+    tablebase: Option<Arc<Tablebase>>,
 }
 
 #[cfg(feature = "ssr")]
 impl GameState {
-    pub fn new() -> Self {
+    /// All games adjudicate their endgames with the tablebase, if one is loaded.
+    pub fn new(tablebase: Option<Arc<Tablebase>>) -> Self {
         Self {
             game: tokio::sync::RwLock::new(HashMap::new()),
+            tablebase,
         }
     }
 
-    pub async fn get_or_create_game(&self, game_id: Uuid) -> Game {        
+    pub async fn get_game(&self, game_id: Uuid) -> Option<Game> {
+        self.game.read().await.get(&game_id).cloned()
+    }
+
+    /// Creates a game when the settings describe a valid game, `None` if the id is taken.
+    pub async fn create_game(&self, game_id: Uuid, settings: GameSettings) -> Result<Option<Game>, GameSettingsError> {
         let mut games = self.game.write().await;
-        let new_game = Game::new(game_id).await;
-        let game = games.entry(game_id).or_insert_with( || {
-            logging::log!("Creating new game: {:?}", game_id);
-            new_game
-        }).clone();
-        game
-    }    
+        if games.contains_key(&game_id) {
+            return Ok(None);
+        }
+        let mut new_game = Game::new(game_id, settings).await?;
+        new_game.tablebase = self.tablebase.clone();
+        logging::log!("Creating new game: {:?}", game_id);
+        games.insert(game_id, new_game.clone());
+        Ok(Some(new_game))
+    }
+
+    pub async fn import_game(&self, record: &GameRecord) -> Result<Game, GameSettingsError> {
+        let mut game = Game::from_record(record).await?;
+        game.tablebase = self.tablebase.clone();
+        logging::log!("Importing game: {:?}", record.game_id);
+        self.game.write().await.insert(record.game_id, game.clone());
+        Ok(game)
+    }
+
+    pub async fn games(&self) -> Vec<Game> {
+        self.game.read().await.values().cloned().collect()
+    }
 }
 
 #[cfg(feature = "ssr")]
@@ -280,7 +867,10 @@ pub async fn handle_players_websocket(
                     Ok(PlayerClientData::SelectGame(game_id)) => { 
                         if game.is_none() {
                             logging::log!("Selecting game {}", game_id);
-                            game = Some(game_state.get_or_create_game(game_id).await);
+                            game = game_state.get_game(game_id).await;
+                            if game.is_none() {
+                                logging::error!("Cannot select unknown game {}", game_id);
+                            }
                         }
                         else if game.as_ref().unwrap().id().await != game_id {
                             logging::error!("Already selected game {}", game_id);
@@ -289,8 +879,17 @@ pub async fn handle_players_websocket(
                     Ok(PlayerClientData::Alive(player_identity)) => { 
                         if let Some(game) = &game {
                             if player_identity.game_id == game.id().await {
-                                // check if player secret is correct
-                                // update last_ping time of player
+                                game.with_player(|mut players| {
+                                    match players.players.get_mut(player_identity.player_number) {
+                                        Some(player) if player.has_secret(&player_identity.secret) => {
+                                            player.public_data.last_ping = Some(Utc::now());
+                                        }
+                                        _ => {
+                                            logging::error!("Received alive message with invalid identity for player {}.", player_identity.player_number);
+                                        }
+                                    }
+                                    players
+                                }).await;
                             }
                             else {
                                 logging::error!("Received message for wrong game: {:?}", player_identity.game_id);
@@ -300,6 +899,33 @@ pub async fn handle_players_websocket(
                             logging::error!("Received message before selecting a game: {:?}", message);
                         }
                     }
+                    Ok(PlayerClientData::PlayMove(player_identity, notation)) => {
+                        let Some(game) = &game else {
+                            logging::error!("Received move before selecting a game: {:?}", notation);
+                            continue;
+                        };
+                        let is_identified = player_identity.game_id == game.id().await && game.players().await
+                            .get(player_identity.player_number)
+                            .is_some_and(|player| player.has_secret(&player_identity.secret));
+                        let result = match is_identified {
+                            true => game.play_move(player_identity.player_number, &notation).await.map(|_| ()),
+                            false => Err(MoveRejection::InvalidPlayerIdentity),
+                        };
+                        let reply = match result {
+                            Ok(()) => PlayerServerData::Moves {
+                                initial_position: game.initial_position().await.to_fen(),
+                                moves: game.move_notations().await,
+                                result: game.result().await,
+                            },
+                            Err(error) => {
+                                logging::log!("Rejected move '{}': {}", notation, error);
+                                PlayerServerData::MoveRejected(error)
+                            }
+                        };
+                        if socket.send_player_server_data(&reply).await.is_err() {
+                            break;
+                        }
+                    }
                     Err(error) => { 
                         logging::error!("Cannot receive player data: {:?} for {:?}.", error, message);
                         break; 
@@ -324,6 +950,14 @@ pub async fn handle_players_websocket(
                 if socket.send_player_server_data(&PlayerServerData::PlayerList(players)).await.is_err() {
                     break;
                 }            
+                let moves = PlayerServerData::Moves {
+                    initial_position: game.initial_position().await.to_fen(),
+                    moves: game.move_notations().await,
+                    result: game.result().await,
+                };
+                if socket.send_player_server_data(&moves).await.is_err() {
+                    break;
+                }
             }    
         }
     }
@@ -331,6 +965,45 @@ pub async fn handle_players_websocket(
     logging::log!("Players websocket closed by client.");
 }
 
+// Downloads only read games, an unknown id is not found instead of creating a new game.
+#[cfg(feature = "ssr")]
+pub async fn handle_game_record_request(
+    axum::extract::Path(game_id): axum::extract::Path<Uuid>,
+    axum::extract::Extension(game_state): axum::extract::Extension<Arc<GameState>>
+) -> Result<axum::Json<GameRecord>, axum::http::StatusCode> {
+    let game = game_state.get_game(game_id).await.ok_or(axum::http::StatusCode::NOT_FOUND)?;
+    Ok(axum::Json(game.record().await))
+}
+
+#[cfg(feature = "ssr")]
+pub async fn handle_game_fen_request(
+    axum::extract::Path(game_id): axum::extract::Path<Uuid>,
+    axum::extract::Extension(game_state): axum::extract::Extension<Arc<GameState>>
+) -> Result<String, axum::http::StatusCode> {
+    let game = game_state.get_game(game_id).await.ok_or(axum::http::StatusCode::NOT_FOUND)?;
+    Ok(game.position().await.to_fen())
+}
+
+#[cfg(feature = "ssr")]
+pub async fn handle_game_pdn_request(
+    axum::extract::Path(game_id): axum::extract::Path<Uuid>,
+    axum::extract::Extension(game_state): axum::extract::Extension<Arc<GameState>>
+) -> Result<impl axum::response::IntoResponse, axum::http::StatusCode> {
+    use axum::http::header;
+
+    let game = game_state.get_game(game_id).await.ok_or(axum::http::StatusCode::NOT_FOUND)?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/x-pdn; charset=utf-8".to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}.pdn\"", game_id)),
+        ],
+        match game.analysis().await {
+            Some(analysis) => export_annotated_pdn(&game.record().await, &analysis),
+            None => export_pdn(&game.record().await),
+        },
+    ))
+}
+
 
 #[server(AssignPlayerToGame, "/api")]
 pub async fn assign_player_to_game(
@@ -344,7 +1017,8 @@ pub async fn assign_player_to_game(
 
     let game_state= extract::<Extension<Arc<GameState>>>().await
         .expect("Cannot get the game-state extension.");
-    let game = game_state.get_or_create_game(game_id).await;
+    let game = game_state.get_game(game_id).await
+        .ok_or_else(|| ServerFnError::ServerError(format!("Game {} not found.", game_id)))?;
 
     let mut player_secret: Option<String> = None;
     let mut status = PlayerAssignmentStatus::REFUSED;    
@@ -396,8 +1070,9 @@ pub fn PlayerAssignment(
             .same_site(SameSite::Lax)
             .path(location.pathname.get_untracked()),
     );
+    let locale = use_locale();
     let (player_number, set_player_number) = create_signal::<Option<usize>>(None);
-    let (player_name, set_player_name) = create_signal("Player".to_string());
+    let (player_name, set_player_name) = create_signal(Text::DefaultPlayerName.translate(locale.get_untracked()));
     let is_player_assigned = move || { player_secret_cookie.get().is_some() };
     let (player_assignment_pending, set_player_assignment_pending) = create_signal(false);
     let player_assignment_possible = move || {!is_player_assigned() && !player_assignment_pending.get()};
@@ -477,7 +1152,7 @@ pub fn PlayerAssignment(
         </div>
         <Show when=move || !is_player_assigned()>
             <div class="p-2 w-full flex justify-center">
-                <p>"Join game as "</p>
+                <p>{move || Text::JoinGameAs.translate(locale.get())}</p>
                 <input
                     disabled={move || !player_assignment_possible()}
                     type="text"
@@ -510,7 +1185,7 @@ pub fn PlayerAssignment(
                         });
                     }}
                 >
-                    "Red Player"
+                    {move || Text::JoinAs(PlayerColor::Red).translate(locale.get())}
                 </button>
                 <button
                     disabled={move || !player_assignment_possible()}
@@ -520,7 +1195,7 @@ pub fn PlayerAssignment(
                     }}
                     class="btn bg-blue-800 hover:bg-blue-700 text-content btn-xs ml-2"
                 >
-                    "Blue Player"
+                    {move || Text::JoinAs(PlayerColor::Blue).translate(locale.get())}
                 </button>
                 <Show when=player_assignment_pending>
                     <span class="loading loading-spinner text-primary"></span>
@@ -537,27 +1212,33 @@ pub fn PlayerInfo(
     #[prop(into)]
     now: Signal<DateTime<Utc>>,
 ) -> impl IntoView {
+    let locale = use_locale();
+    let name = player.name.clone();
+
     view! {
         <tr>
             <th>
-                {move || match player.last_ping {                    
+                {move || match player.last_ping {
+                    _ if player.is_bot => {
+                        view! {<div class="badge badge-neutral badge-xs">{Text::Bot.translate(locale.get())}</div>}
+                    },
                     Some(last_ping) if (now.get() - last_ping) < TimeDelta::seconds(10) => {
-                        view! {<div class="badge badge-success badge-xs"></div>}
+                        view! {<div class="badge badge-success badge-xs" role="img" aria-label=Text::Online.translate(locale.get())></div>}
                     },
                     Some(last_ping) if (now.get()- last_ping) < TimeDelta::seconds(120) => {
-                        view! {<div class="badge badge-warning  badge-xs"></div>}
+                        view! {<div class="badge badge-warning  badge-xs" role="img" aria-label=Text::Away.translate(locale.get())></div>}
                     },
-                    _ => {view! {<div class="badge badge-error badge-xs"></div>} }
+                    _ => {view! {<div class="badge badge-error badge-xs" role="img" aria-label=Text::Offline.translate(locale.get())></div>} }
                 }}
             </th>
-            <th>{player.name.unwrap_or_else(|| "Unknown".to_string())}</th>
+            <th>{move || name.clone().unwrap_or_else(|| Text::UnknownPlayerName.translate(locale.get()))}</th>
             <th>
                 {move || match player.player_number {
-                    0 => view! {<div class="badge bg-red-700">Player Red</div>},
-                    1 => view! {<div class="badge bg-blue-700">Player Blue</div>},
+                    0 => view! {<div class="badge bg-red-700">{Text::Seat(PlayerColor::Red).translate(locale.get())}</div>},
+                    1 => view! {<div class="badge bg-blue-700">{Text::Seat(PlayerColor::Blue).translate(locale.get())}</div>},
                     i => {
                         logging::error!("Unknown player number: {}", i);
-                        view! { <div>Unknown player assignment</div> }
+                        view! { <div>{Text::UnknownSeat.translate(locale.get())}</div> }
                     }
                 }}
             </th>
@@ -570,18 +1251,45 @@ pub fn GameInfo(
     #[prop(into)]
     game_id: Signal<Option<Uuid>>
 ) -> impl IntoView {
+    let settings = create_resource(
+        move || game_id.get(),
+        |game_id| async move {
+            match game_id {
+                Some(game_id) => get_game_settings(game_id).await.ok(),
+                None => None,
+            }
+        },
+    );
     let game_id = {move || {game_id().map(|id| format!("{}", id)).unwrap_or_default()}};
     let game_url = use_url();
+    let locale = use_locale();
 
     view! {
         <div class="flex justify-start p-2">
-            <p class="m-1">"This is game "</p>
+            <p class="m-1">{move || Text::ThisIsGame.translate(locale.get())}</p>
             <code class="bg-base-200 m-1 px-1">{move || {game_id()}}</code>
             <CopyToClipboardButton
                 text_to_copy=Signal::derive(game_url)
-                text="Share"
+                text=Signal::derive(move || Text::Share.translate(locale.get()))
                 class="btn btn-primary btn-xs m-1"
             />
+            <a class="btn btn-xs m-1" href=move || format!("/games/{}/pdn", game_id()) download rel="external">
+                {move || Text::DownloadPdn.translate(locale.get())}
+            </a>
+            <Transition fallback=|| ()>
+                {move || settings.get().flatten()
+                    .filter(|settings| !settings.handicap.is_none())
+                    .map(|settings| view! {
+                        <p class="m-1 badge badge-outline">{settings.handicap.translate(locale.get())}</p>
+                    })
+                }
+                {move || settings.get().flatten()
+                    .and_then(|settings| settings.bot)
+                    .map(|bot_settings| view! {
+                        <p class="m-1 badge badge-outline">{bot_settings.translate(locale.get())}</p>
+                    })
+                }
+            </Transition>
         </div>
     }
 }
@@ -618,6 +1326,7 @@ pub fn CopyToClipboardButton(
     class: &'static str,
 ) -> impl IntoView {
     let clipboard = use_clipboard();
+    let locale = use_locale();
 
     view! {
         <button
@@ -631,7 +1340,7 @@ pub fn CopyToClipboardButton(
             }
         >
             <Show when=move || clipboard.copied.get() fallback=move || text.get()>
-                "Copied!"
+                {move || Text::Copied.translate(locale.get())}
             </Show>
         </button>
     }
@@ -0,0 +1,101 @@
+use leptos::*;
+use leptos_use::storage::use_local_storage;
+use codee::string::JsonSerdeCodec;
+
+use crate::components::board::{GameAnnouncer, GameBoard, MoveInput};
+use crate::components::move_history::MoveHistory;
+use crate::components::offline::{use_local_game, LocalGame, UseLocalGameResult};
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::moves::Move;
+
+const HOTSEAT_GAME_STORAGE_KEY: &str = "hotseat_game";
+
+
+/// Pass and play: both players share one device, the board is turned towards the side to
+///  move after every move. The game lives in the local storage only, so reloading the page
+///  resumes it.
+#[component]
+pub fn HotseatGamePage() -> impl IntoView {
+    // the server cannot see the stored game, so it is only read once the page is hydrated,
+    //  until then the page renders like on the server.
+    let (is_hydrated, set_is_hydrated) = create_signal(false);
+    create_effect(move |_| set_is_hydrated.set(true));
+
+    view! {
+        <Show when=move || is_hydrated.get()>
+            <HotseatGame/>
+        </Show>
+    }
+}
+
+#[component]
+fn HotseatGame() -> impl IntoView {
+    let locale = use_locale();
+    let (game, set_game, _) = use_local_storage::<LocalGame, JsonSerdeCodec>(HOTSEAT_GAME_STORAGE_KEY);
+    if !game.with_untracked(LocalGame::is_valid) {
+        logging::warn!("Dropping the stored hotseat game, it contains an illegal move.");
+        set_game.set(LocalGame::default());
+    }
+    let UseLocalGameResult { positions, position, result, notations, last_move, first_player } = use_local_game(game);
+    let viewed_ply = create_rw_signal::<Option<usize>>(None);
+    let viewed_position = Signal::derive(move || {
+        positions.with(|positions| {
+            viewed_ply.get().and_then(|ply| positions.get(ply)).or(positions.last()).copied()
+                .expect("There is at least the initial position.")
+        })
+    });
+    let viewed_last_move = Signal::derive(move || {
+        game.with(|game| {
+            let ply = viewed_ply.get().unwrap_or(game.moves.len());
+            ply.checked_sub(1).and_then(|index| game.moves.get(index)).cloned()
+        })
+    });
+    // The player to move sits in front of the board.
+    let player_color = Signal::derive(move || Some(position.get().side_to_move));
+    let on_move = Callback::new(move |mv: Move| {
+        if result.get_untracked().is_none() && position.get_untracked().is_legal(&mv) {
+            set_game.update(|game| game.moves.push(mv));
+        }
+    });
+    let new_game = move |_| {
+        set_game.set(LocalGame::default());
+        viewed_ply.set(None);
+    };
+
+    view! {
+        <div class="flex flex-wrap justify-center gap-4 p-2">
+            <GameBoard
+                position=viewed_position
+                last_move=viewed_last_move
+                player_color=player_color
+                on_move=on_move
+                is_read_only=Signal::derive(move || viewed_ply.get().is_some() || result.get().is_some())
+            />
+            <MoveHistory moves=notations first_player=first_player viewed_ply=viewed_ply/>
+        </div>
+        <MoveInput
+            position=Signal::derive(move || Some(position.get()).filter(|_| viewed_ply.get().is_none() && result.get().is_none()))
+            on_move=on_move
+        />
+        <GameAnnouncer
+            moves=notations
+            last_move=last_move
+            first_player=first_player
+            player_color=player_color
+            result=result
+        />
+        <div class="p-2 flex justify-center items-center">
+            <Show
+                when=move || result.get().is_some()
+                fallback=move || view! {
+                    <p class="m-1">{move || Text::ToMove(position.get().side_to_move).translate(locale.get())}</p>
+                }
+            >
+                <p class="m-1">{move || result.get().map(|result| Text::GameOver(result).translate(locale.get()))}</p>
+            </Show>
+            <button class="btn btn-xs m-1" on:click=new_game>
+                {move || Text::NewGame.translate(locale.get())}
+            </button>
+        </div>
+    }
+}
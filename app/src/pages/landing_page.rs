@@ -1,14 +1,24 @@
 use leptos::*;
+use leptos_meta::Html;
 use leptos_router::*;
 
+use crate::components::appearance::{provide_appearance, AppearanceSettings};
+use crate::components::i18n::LanguageSelect;
+
 
 #[component]
 pub fn LandingPage() -> impl IntoView {
+    let appearance = provide_appearance().appearance;
+
     view! {
-        <html data-theme="dark"></html>
+        <Html attr:data-theme=move || appearance.get().color_theme.data_theme()/>
         <div class="w-full">
-            <div class="navbar bg-base-200 w-full px-10">
+            <div class="navbar bg-base-200 w-full px-10 flex justify-between">
                 <p class="text-3xl">Checker</p>
+                <div class="flex items-center gap-2">
+                    <LanguageSelect/>
+                    <AppearanceSettings/>
+                </div>
             </div>
             <div class="px-10 py-5 w-full flex flex-col items-center">
                 <Outlet/>
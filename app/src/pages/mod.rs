@@ -1,4 +1,7 @@
 pub mod landing_page;
 pub mod new_game_page;
 pub mod app_router;
-pub mod game_page;
\ No newline at end of file
+pub mod game_page;
+pub mod offline_game_page;
+pub mod hotseat_game_page;
+pub mod analysis_board_page;
\ No newline at end of file
@@ -1,16 +1,52 @@
 use leptos::*;
+use leptos_router::use_navigate;
 use uuid::Uuid;
 
+use crate::components::game_settings::{create_game, GameSettingsForm};
+use crate::components::pdn::PdnImport;
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::game_settings::GameSettings;
+
 #[component]
 pub fn NewGamePage() -> impl IntoView {
     let new_game_id = Uuid::now_v7();
     let (existing_game_id, set_existing_game_id) = create_signal("".to_string());
+    let settings = create_rw_signal(GameSettings::default());
+    let fen_error = create_rw_signal::<Option<String>>(None);
+    let (game_creation_pending, set_game_creation_pending) = create_signal(false);
+    let (game_creation_error, set_game_creation_error) = create_signal::<Option<String>>(None);
+    let navigate = use_navigate();
+    let locale = use_locale();
 
     view! {
+        <GameSettingsForm settings=settings fen_error=fen_error/>
         <div class="p-2">
-            <a class="btn btn-primary" href={format!("/games/{}", new_game_id)}>
-                "New Game"
-            </a>
+            <button
+                class="btn btn-primary"
+                disabled=move || game_creation_pending.get() || fen_error.with(Option::is_some)
+                on:click=move |_| {
+                    let navigate = navigate.clone();
+                    set_game_creation_pending.set(true);
+                    set_game_creation_error.set(None);
+                    spawn_local(async move {
+                        match create_game(new_game_id, settings.get_untracked()).await {
+                            Ok(_) => {
+                                navigate(&format!("/games/{}", new_game_id), Default::default());
+                            }
+                            Err(error) => {
+                                logging::error!("Cannot create new game: {:?}", error);
+                                set_game_creation_error.set(Some(error.translate(locale.get_untracked())));
+                            }
+                        }
+                        set_game_creation_pending.set(false);
+                    });
+                }
+            >
+                {move || Text::NewGame.translate(locale.get())}
+            </button>
+            <Show when=move || game_creation_error.get().is_some()>
+                <p class="content-error">{move || Text::Error(game_creation_error.get().unwrap_or_default()).translate(locale.get())}</p>
+            </Show>
         </div>
         <div class="p-2 w-full flex justify-center">
             <input
@@ -22,8 +58,20 @@ pub fn NewGamePage() -> impl IntoView {
                 prop:value=existing_game_id
             />
             <a class="btn btn-primary mx-5" href={move || {format!("/games/{}", existing_game_id.get())}}>
-                "Join Game"
+                {move || Text::JoinGame.translate(locale.get())}
+            </a>
+        </div>
+        <div class="p-2">
+            <a class="btn" href="/offline">
+                {move || Text::PlayOffline.translate(locale.get())}
+            </a>
+            <a class="btn ml-2" href="/hotseat">
+                {move || Text::PlayHotseat.translate(locale.get())}
+            </a>
+            <a class="btn ml-2" href="/analysis">
+                {move || Text::AnalysisBoard.translate(locale.get())}
             </a>
         </div>
+        <PdnImport/>
     }
 }
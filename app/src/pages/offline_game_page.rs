@@ -0,0 +1,255 @@
+use leptos::*;
+use leptos_router::use_navigate;
+use leptos_use::use_cookie;
+use codee::string::FromToStringCodec;
+use chrono::Utc;
+
+use crate::components::board::{GameAnnouncer, GameBoard, MoveInput};
+use crate::components::move_history::MoveHistory;
+use crate::components::offline::{upload_game, use_bot_worker, use_local_game, BotMoveRequest, LocalGame, UseLocalGameResult};
+use crate::components::player::PLAYER_NAME_COOKIE_NAME;
+use crate::components::i18n::{use_locale, Text, Translate};
+use crate::game::bot_settings::{BotLevel, BotSettings, BotStyle};
+use crate::game::engine::accepts_draw;
+use crate::game::game_result::{DrawReason, GameResult, WinReason};
+use crate::game::moves::Move;
+use crate::game::notation::parse_move;
+use crate::game::player_color::PlayerColor;
+
+
+/// A game against the computer, which runs entirely in the browser. The bot thinks in a web
+///  worker and nothing is sent to the server, unless the finished game is uploaded.
+#[component]
+pub fn OfflineGamePage() -> impl IntoView {
+    let locale = use_locale();
+    let bot_settings = create_rw_signal(BotSettings::default());
+    let player_color = create_rw_signal(PlayerColor::Red);
+    let is_started = create_rw_signal(false);
+    let game = create_rw_signal(LocalGame::default());
+    let created_at = create_rw_signal(Utc::now());
+    let UseLocalGameResult { positions, position, result, notations, last_move, first_player } = use_local_game(game.into());
+    let viewed_ply = create_rw_signal::<Option<usize>>(None);
+    let viewed_position = Signal::derive(move || {
+        positions.with(|positions| {
+            viewed_ply.get().and_then(|ply| positions.get(ply)).or(positions.last()).copied()
+                .expect("There is at least the initial position.")
+        })
+    });
+    let viewed_last_move = Signal::derive(move || {
+        game.with(|game| {
+            let ply = viewed_ply.get().unwrap_or(game.moves.len());
+            ply.checked_sub(1).and_then(|index| game.moves.get(index)).cloned()
+        })
+    });
+    let is_players_turn = move || {
+        is_started.get() && result.get().is_none() && position.get().side_to_move == player_color.get()
+    };
+
+    let bot_worker = use_bot_worker();
+    let request_bot_move = bot_worker.request_move.clone();
+    create_effect(move |_| {
+        let position = position.get();
+        if is_started.get() && result.get().is_none() && position.side_to_move != player_color.get() {
+            request_bot_move(BotMoveRequest {
+                settings: bot_settings.get_untracked(),
+                // the start of the game seeds the bot once for the whole game
+                seed: created_at.get_untracked().timestamp_micros() as u64,
+                fen: position.to_fen(),
+            });
+        }
+    });
+    let bot_response = bot_worker.response;
+    create_effect(move |_| {
+        let Some(response) = bot_response.get() else {
+            return;
+        };
+        let position = position.get_untracked();
+        // answers for an earlier game or position, or after the game ended, are outdated
+        if !is_started.get_untracked() || result.get_untracked().is_some() || response.fen != position.to_fen() || position.side_to_move == player_color.get_untracked() {
+            return;
+        }
+        match response.notation.as_deref().map(|notation| parse_move(&position, notation)) {
+            Some(Ok(mv)) => game.update(|game| game.moves.push(mv)),
+            Some(Err(error)) => logging::error!("The bot played an invalid move: {}", error),
+            None => {}
+        }
+    });
+
+    let on_move = Callback::new(move |mv: Move| {
+        if is_players_turn() && position.get_untracked().is_legal(&mv) {
+            game.update(|game| game.moves.push(mv));
+        }
+    });
+    let (draw_declined, set_draw_declined) = create_signal(false);
+    let resign = move |_| {
+        let winner = player_color.get_untracked().opponent();
+        game.update(|game| game.result = Some(GameResult::Win { winner, reason: WinReason::Resignation }));
+    };
+    // the bot answers at once, it takes the draw unless it is ahead
+    let offer_draw = move |_| {
+        let bot_color = player_color.get_untracked().opponent();
+        let is_accepted = accepts_draw(&position.get_untracked(), bot_color);
+        if is_accepted {
+            game.update(|game| game.result = Some(GameResult::Draw { reason: DrawReason::Agreement }));
+        }
+        set_draw_declined.set(!is_accepted);
+    };
+    let start_game = move |_| {
+        game.set(LocalGame::default());
+        set_draw_declined.set(false);
+        viewed_ply.set(None);
+        created_at.set(Utc::now());
+        is_started.set(true);
+    };
+
+    let (player_name, _) = use_cookie::<String, FromToStringCodec>(PLAYER_NAME_COOKIE_NAME);
+    let (upload_pending, set_upload_pending) = create_signal(false);
+    let (upload_error, set_upload_error) = create_signal::<Option<String>>(None);
+    let navigate = use_navigate();
+    let upload = Callback::new(move |_: ()| {
+        let bot_color = player_color.get_untracked().opponent();
+        let initial_position = game.with_untracked(|game| game.initial_position);
+        let moves = game.with_untracked(|game| game.moves.clone());
+        let Some(game_result) = result.get_untracked() else {
+            return;
+        };
+        let name = player_name.get_untracked().unwrap_or_else(|| Text::DefaultPlayerName.translate(locale.get_untracked()));
+        let navigate = navigate.clone();
+        set_upload_pending.set(true);
+        spawn_local(async move {
+            match upload_game(bot_settings.get_untracked(), bot_color, name, created_at.get_untracked(), initial_position, moves, game_result).await {
+                Ok(game_id) => navigate(&format!("/games/{}", game_id), Default::default()),
+                Err(error) => {
+                    logging::error!("Cannot upload game: {:?}", error);
+                    set_upload_error.set(Some(Text::UploadFailed.translate(locale.get_untracked())));
+                }
+            }
+            set_upload_pending.set(false);
+        });
+    });
+
+    view! {
+        <Show
+            when=move || is_started.get()
+            fallback=move || view! {
+                <div class="p-2 flex justify-center items-center">
+                    <p class="mr-2">{move || Text::ComputerOpponent.translate(locale.get())}</p>
+                    <select
+                        class="select select-bordered select-sm"
+                        on:change=move |ev| {
+                            let level = event_target_value(&ev).parse::<usize>().ok()
+                                .and_then(|index| BotLevel::all().get(index).copied());
+                            if let Some(level) = level {
+                                bot_settings.update(|bot_settings| bot_settings.level = level);
+                            }
+                        }
+                    >
+                        {BotLevel::all().into_iter().enumerate().map(|(index, level)| view! {
+                            <option
+                                value=index.to_string()
+                                selected=move || bot_settings.with(|bot_settings| bot_settings.level == level)
+                            >
+                                {move || level.translate(locale.get())}
+                            </option>
+                        }).collect_view()}
+                    </select>
+                    <p class="ml-4 mr-2">{move || Text::PlayingStyle.translate(locale.get())}</p>
+                    <select
+                        class="select select-bordered select-sm"
+                        on:change=move |ev| {
+                            let style = event_target_value(&ev).parse::<usize>().ok()
+                                .and_then(|index| BotStyle::all().get(index).copied());
+                            if let Some(style) = style {
+                                bot_settings.update(|bot_settings| bot_settings.style = style);
+                            }
+                        }
+                    >
+                        {BotStyle::all().into_iter().enumerate().map(|(index, style)| view! {
+                            <option
+                                value=index.to_string()
+                                selected=move || bot_settings.with(|bot_settings| bot_settings.style == style)
+                            >
+                                {move || style.translate(locale.get())}
+                            </option>
+                        }).collect_view()}
+                    </select>
+                    <p class="ml-4 mr-2">{move || Text::PlayAs.translate(locale.get())}</p>
+                    <select
+                        class="select select-bordered select-sm"
+                        on:change=move |ev| {
+                            let color = event_target_value(&ev).parse::<usize>().ok()
+                                .and_then(PlayerColor::from_player_number);
+                            if let Some(color) = color {
+                                player_color.set(color);
+                            }
+                        }
+                    >
+                        {[PlayerColor::Red, PlayerColor::Blue].into_iter().map(|color| view! {
+                            <option
+                                value=color.player_number().to_string()
+                                selected=move || player_color.get() == color
+                            >
+                                {move || color.translate(locale.get())}
+                            </option>
+                        }).collect_view()}
+                    </select>
+                </div>
+                <div class="p-2 flex justify-center">
+                    <button class="btn btn-primary" on:click=start_game>
+                        {move || Text::StartOfflineGame.translate(locale.get())}
+                    </button>
+                </div>
+            }
+        >
+            <div class="flex flex-wrap justify-center gap-4 p-2">
+                <GameBoard
+                    position=viewed_position
+                    last_move=viewed_last_move
+                    player_color=Signal::derive(move || Some(player_color.get()))
+                    on_move=on_move
+                    is_read_only=Signal::derive(move || viewed_ply.get().is_some() || !is_players_turn())
+                />
+                <MoveHistory moves=notations first_player=first_player viewed_ply=viewed_ply/>
+            </div>
+            <MoveInput
+                position=Signal::derive(move || is_players_turn().then(|| position.get()).filter(|_| viewed_ply.get().is_none()))
+                on_move=on_move
+            />
+            <GameAnnouncer
+                moves=notations
+                last_move=last_move
+                first_player=first_player
+                player_color=Signal::derive(move || Some(player_color.get()))
+                result=result
+            />
+            <div class="p-2 flex justify-center items-center">
+                <Show when=move || is_started.get() && result.get().is_none() && !is_players_turn()>
+                    <span class="loading loading-spinner text-primary m-1" role="status" aria-label=move || Text::ComputerThinking.translate(locale.get())></span>
+                </Show>
+                <Show when=move || is_started.get() && result.get().is_none()>
+                    <button class="btn btn-xs m-1" on:click=offer_draw>
+                        {move || Text::OfferDraw.translate(locale.get())}
+                    </button>
+                    <button class="btn btn-xs m-1" on:click=resign>
+                        {move || Text::Resign.translate(locale.get())}
+                    </button>
+                </Show>
+                <Show when=move || result.get().is_some()>
+                    <p class="m-1">{move || result.get().map(|result| Text::GameOver(result).translate(locale.get()))}</p>
+                    <button class="btn btn-primary btn-xs m-1" disabled=upload_pending on:click=move |_| upload.call(())>
+                        {move || Text::UploadGame.translate(locale.get())}
+                    </button>
+                </Show>
+                <button class="btn btn-xs m-1" on:click=move |_| is_started.set(false)>
+                    {move || Text::NewGame.translate(locale.get())}
+                </button>
+            </div>
+            <Show when=move || draw_declined.get() && result.get().is_none()>
+                <p class="text-center" role="status">{move || Text::DrawDeclined.translate(locale.get())}</p>
+            </Show>
+            <Show when=move || upload_error.get().is_some()>
+                <p class="text-error text-center" role="alert">{move || upload_error.get().unwrap_or_default()}</p>
+            </Show>
+        </Show>
+    }
+}
@@ -1 +1,4 @@
-pub mod use_cookie_signal;
\ No newline at end of file
+pub mod use_cookie_signal;
+pub mod sound_effect;
+pub mod use_worker;
+pub mod url_encoding;
\ No newline at end of file
@@ -0,0 +1,68 @@
+use std::cell::RefCell;
+use leptos::*;
+use web_sys::{AudioContext, OscillatorType};
+
+
+const SOUND_VOLUME: f32 = 0.2;
+
+thread_local! {
+    // Browsers limit the number of audio contexts, so all sounds share one.
+    static AUDIO_CONTEXT: RefCell<Option<AudioContext>> = const { RefCell::new(None) };
+}
+
+
+/// Short synthesized sounds, so no audio files have to be served.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum SoundEffect {
+    Move,
+    Capture,
+    Promotion,
+    LowTime,
+    GameEnd,
+}
+
+impl SoundEffect {
+    /// The tones of the sound as frequency in Hz and duration in seconds.
+    fn tones(&self) -> &'static [(f32, f64)] {
+        match self {
+            SoundEffect::Move => &[(440.0, 0.06)],
+            SoundEffect::Capture => &[(330.0, 0.05), (220.0, 0.08)],
+            SoundEffect::Promotion => &[(523.0, 0.08), (659.0, 0.08), (784.0, 0.12)],
+            SoundEffect::LowTime => &[(880.0, 0.1), (0.0, 0.1), (880.0, 0.1)],
+            SoundEffect::GameEnd => &[(392.0, 0.15), (330.0, 0.15), (262.0, 0.3)],
+        }
+    }
+
+    pub fn play(&self) {
+        if let Err(error) = self.try_play() {
+            logging::error!("Cannot play sound {:?}: {:?}", self, error);
+        }
+    }
+
+    fn try_play(&self) -> Result<(), wasm_bindgen::JsValue> {
+        let context = AUDIO_CONTEXT.with(|audio_context| {
+            let mut audio_context = audio_context.borrow_mut();
+            match audio_context.as_ref() {
+                Some(context) => Ok(context.clone()),
+                None => AudioContext::new().inspect(|context| *audio_context = Some(context.clone())),
+            }
+        })?;
+        let mut start_time = context.current_time();
+        for &(frequency, duration) in self.tones() {
+            if frequency > 0.0 {
+                let oscillator = context.create_oscillator()?;
+                let gain = context.create_gain()?;
+                oscillator.set_type(OscillatorType::Triangle);
+                oscillator.frequency().set_value(frequency);
+                gain.gain().set_value_at_time(SOUND_VOLUME, start_time)?;
+                gain.gain().linear_ramp_to_value_at_time(0.0, start_time + duration)?;
+                oscillator.connect_with_audio_node(&gain)?;
+                gain.connect_with_audio_node(&context.destination())?;
+                oscillator.start_with_when(start_time)?;
+                oscillator.stop_with_when(start_time + duration)?;
+            }
+            start_time += duration;
+        }
+        Ok(())
+    }
+}
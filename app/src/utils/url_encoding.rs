@@ -0,0 +1,14 @@
+/// Percent-encodes everything but the unreserved characters, so the value can be part of
+///  the query of a link.
+pub fn encode_query_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            encoded.push(byte as char);
+        }
+        else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
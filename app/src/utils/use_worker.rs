@@ -0,0 +1,82 @@
+use leptos::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+
+pub struct UseWorkerResult<Request, Response, PostFn>
+where
+    Response: 'static,
+    PostFn: Fn(Request) + Clone + 'static,
+{
+    /// The latest answer of the worker.
+    pub response: Signal<Option<Response>>,
+    /// Sends a request as JSON.
+    pub post: PostFn,
+    _request: std::marker::PhantomData<Request>,
+}
+
+
+/// Runs a module web worker which exchanges JSON strings with the page. The worker is started
+///  with the component and terminated when it is cleaned up. Without a browser, e.g. while
+///  rendering on the server, requests are ignored.
+pub fn use_worker<Request, Response>(
+    url: &'static str,
+) -> UseWorkerResult<Request, Response, impl Fn(Request) + Clone + 'static>
+where
+    Request: Serialize + 'static,
+    Response: DeserializeOwned + Clone + 'static,
+{
+    let (response, set_response) = create_signal::<Option<Response>>(None);
+
+    #[cfg(feature = "hydrate")]
+    let worker = {
+        use wasm_bindgen::{closure::Closure, JsCast};
+
+        let options = web_sys::WorkerOptions::new();
+        options.set_type(web_sys::WorkerType::Module);
+        let worker = web_sys::Worker::new_with_options(url, &options)
+            .map_err(|error| logging::error!("Cannot start the worker {}: {:?}", url, error))
+            .ok();
+        if let Some(worker) = &worker {
+            let on_message = Closure::<dyn Fn(web_sys::MessageEvent)>::new(move |event: web_sys::MessageEvent| {
+                let response = event.data().as_string()
+                    .and_then(|data| serde_json::from_str::<Response>(&data).ok());
+                if response.is_none() {
+                    logging::error!("Cannot read the answer of the worker {}.", url);
+                }
+                set_response.set(response);
+            });
+            worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+            // the worker holds on to the handler as long as it lives
+            on_message.forget();
+
+            let worker = worker.clone();
+            on_cleanup(move || worker.terminate());
+        }
+        store_value(worker)
+    };
+    #[cfg(not(feature = "hydrate"))]
+    let _ = (url, set_response);
+
+    let post = move |request: Request| {
+        #[cfg(feature = "hydrate")]
+        worker.with_value(|worker| {
+            let Some(worker) = worker else {
+                return;
+            };
+            let Ok(request) = serde_json::to_string(&request) else {
+                return;
+            };
+            if let Err(error) = worker.post_message(&wasm_bindgen::JsValue::from_str(&request)) {
+                logging::error!("Cannot send request to the worker {}: {:?}", url, error);
+            }
+        });
+        #[cfg(not(feature = "hydrate"))]
+        let _ = request;
+    };
+
+    UseWorkerResult {
+        response: response.into(),
+        post,
+        _request: std::marker::PhantomData,
+    }
+}
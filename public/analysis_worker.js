@@ -0,0 +1,23 @@
+// Evaluates the position of the analysis board in the background. The worker loads the same
+// wasm module as the page and answers after every depth, until a new position arrives.
+import init, { analyze_position } from "/pkg/checker.js";
+
+const initialized = init();
+let latestRequest = 0;
+
+self.onmessage = async (event) => {
+    const request = ++latestRequest;
+    await initialized;
+    const { fen, depth: maxDepth } = JSON.parse(event.data);
+    for (let depth = 1; depth <= maxDepth; depth++) {
+        if (latestRequest !== request) {
+            return;
+        }
+        const response = analyze_position(JSON.stringify({ fen, depth }));
+        if (response) {
+            self.postMessage(response);
+        }
+        // lets a newer request arrive before the next depth
+        await new Promise((resolve) => setTimeout(resolve, 0));
+    }
+};
@@ -0,0 +1,10 @@
+// Runs the bot of offline games in the background. The worker loads the same wasm module
+// as the page and answers every request with the move chosen by `choose_bot_move`.
+import init, { choose_bot_move } from "/pkg/checker.js";
+
+const initialized = init();
+
+self.onmessage = async (event) => {
+    await initialized;
+    self.postMessage(choose_bot_move(event.data));
+};
@@ -0,0 +1,65 @@
+//! Builds the opening book, which the server loads at startup, from PDN archives, e.g.
+//!  games downloaded from `/games/:id/pdn`, and from games played on the server, stored
+//!  as `.json` files downloaded from `/games/:id/record`:
+//!
+//!     cargo run --release --bin build_opening_book -- <output path> <PDN or JSON files>...
+use app::game::game_record::GameRecord;
+use app::game::opening_book::OpeningBook;
+use app::game::pdn::parse_pdn;
+
+const RECORD_EXTENSION: &str = ".json";
+
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let Some(output_path) = args.next() else {
+        eprintln!("Usage: build_opening_book <output path> <PDN or JSON files>...");
+        std::process::exit(1);
+    };
+
+    let mut opening_book = OpeningBook::new();
+    let mut number_of_games = 0;
+    for input_path in args {
+        let text = match std::fs::read_to_string(&input_path) {
+            Ok(text) => text,
+            Err(error) => {
+                eprintln!("Cannot read {}: {}", input_path, error);
+                std::process::exit(1);
+            }
+        };
+        if input_path.ends_with(RECORD_EXTENSION) {
+            match serde_json::from_str::<GameRecord>(&text) {
+                Ok(record) => {
+                    opening_book.add_record(&record);
+                    number_of_games += 1;
+                }
+                Err(error) => eprintln!("Skipping {}: {}", input_path, error),
+            }
+            continue;
+        }
+        let games = match parse_pdn(&text) {
+            Ok(games) => games,
+            Err(error) => {
+                eprintln!("Cannot parse {}: {}", input_path, error);
+                std::process::exit(1);
+            }
+        };
+        for game in games {
+            // a broken game should not spoil the whole archive
+            match opening_book.add_pdn_game(&game) {
+                Ok(()) => number_of_games += 1,
+                Err(error) => eprintln!("Skipping a game in {}: {}", input_path, error),
+            }
+        }
+    }
+
+    let bytes = opening_book.to_bytes();
+    if let Err(error) = std::fs::write(&output_path, &bytes) {
+        eprintln!("Cannot write {}: {}", output_path, error);
+        std::process::exit(1);
+    }
+    println!(
+        "Wrote {} positions from {} games, {} bytes, to {}",
+        opening_book.number_of_positions(), number_of_games, bytes.len(), output_path,
+    );
+}
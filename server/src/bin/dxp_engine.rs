@@ -0,0 +1,138 @@
+//! A DXP engine playing with the built-in bot, as a local stand-in for a real engine when
+//!  trying out the external engine support or the DXP server:
+//!
+//!     cargo run --release --bin dxp_engine -- [address] [level]
+//!     DXP_ENGINE_ADDRESS=localhost:27531 cargo leptos watch
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use tokio::net::TcpListener;
+
+use app::components::dxp::DxpConnection;
+use app::game::board::Position;
+use app::game::bot_settings::{BotLevel, BotSettings};
+use app::game::dxp::{DxpAcceptance, DxpBackupAcceptance, DxpError, DxpGameEndReason, DxpMessage};
+use app::game::engine::Bot;
+use app::game::player_color::PlayerColor;
+
+const DEFAULT_ADDRESS: &str = "localhost:27531";
+const ENGINE_NAME: &str = "Stand-in engine";
+
+
+#[tokio::main]
+async fn main() {
+    let mut args = std::env::args().skip(1);
+    let address = args.next().unwrap_or_else(|| DEFAULT_ADDRESS.to_string());
+    let level = match args.next() {
+        None => BotLevel::default(),
+        Some(name) => match BotLevel::all().into_iter().find(|level| level.to_string().eq_ignore_ascii_case(&name)) {
+            Some(level) => level,
+            None => {
+                let levels: Vec<String> = BotLevel::all().iter().map(BotLevel::to_string).collect();
+                eprintln!("Unknown level '{}', expected one of {}.", name, levels.join(", "));
+                std::process::exit(1);
+            }
+        },
+    };
+
+    let listener = match TcpListener::bind(&address).await {
+        Ok(listener) => listener,
+        Err(error) => {
+            eprintln!("Cannot listen on {}: {}", address, error);
+            std::process::exit(1);
+        }
+    };
+    println!("Waiting for game requests on {}, playing on level {}", address, level);
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(error) => {
+                eprintln!("Cannot accept connection: {}", error);
+                continue;
+            }
+        };
+        let connection = DxpConnection::new(stream);
+        tokio::spawn(async move {
+            let peer = connection.peer().to_string();
+            match play_session(connection, level).await {
+                Ok(()) => println!("Session with {} ended", peer),
+                Err(error) => eprintln!("Session with {} ended: {}", peer, error),
+            }
+        });
+    }
+}
+
+/// Accepts every game request of the peer, until it ends the session.
+async fn play_session(mut connection: DxpConnection, level: BotLevel) -> Result<(), DxpError> {
+    loop {
+        let (color, position) = loop {
+            match connection.receive().await? {
+                DxpMessage::GameRequest { initiator_name, follower_color, position, .. } => {
+                    println!("{} requests a game, playing {}", initiator_name, follower_color);
+                    break (follower_color, position.unwrap_or_else(Position::initial));
+                }
+                DxpMessage::Chat(text) => println!("Chat: {}", text),
+                other => eprintln!("Unexpected message before the game: {}", other),
+            }
+        };
+        connection.send(&DxpMessage::GameAcceptance {
+            follower_name: ENGINE_NAME.to_string(),
+            acceptance: DxpAcceptance::Accepted,
+        }).await?;
+        let seed = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_nanos() as u64).unwrap_or_default();
+        let bot = Bot::new(BotSettings { level, ..Default::default() }, seed);
+        if play_game(&mut connection, bot, color, position).await? {
+            return Ok(());
+        }
+    }
+}
+
+/// Plays one game and returns whether the peer wants to end the session.
+async fn play_game(connection: &mut DxpConnection, mut bot: Bot, color: PlayerColor, mut position: Position) -> Result<bool, DxpError> {
+    let mut has_lost = false;
+    loop {
+        if position.side_to_move == color && !has_lost {
+            let started_at = Instant::now();
+            let search = tokio::task::spawn_blocking(move || {
+                let mv = bot.choose_move(&position);
+                (bot, mv)
+            }).await;
+            let (used_bot, mv) = search.map_err(|error| DxpError::Io(error.to_string()))?;
+            bot = used_bot;
+            let Some(mv) = mv else {
+                // without moves the game is lost, the peer confirms with its own game end
+                connection.send(&DxpMessage::GameEnd { reason: DxpGameEndReason::Loss, stop_session: false }).await?;
+                has_lost = true;
+                continue;
+            };
+            let seconds = started_at.elapsed().as_secs().min(u16::MAX as u64) as u16;
+            connection.send(&DxpMessage::from_move(&mv, seconds)).await?;
+            position.play(&mv);
+            continue;
+        }
+        match connection.receive().await {
+            Ok(message @ DxpMessage::Move { .. }) => {
+                let mv = message.to_move(&position)?;
+                position.play(&mv);
+            }
+            Ok(DxpMessage::GameEnd { reason, stop_session }) => {
+                println!("Game over, the peer reports {:?}", reason);
+                // a game end is confirmed with a game end, unless it is the confirmation
+                if !has_lost {
+                    let reply = match reason {
+                        DxpGameEndReason::Loss => DxpGameEndReason::Win,
+                        DxpGameEndReason::Win => DxpGameEndReason::Loss,
+                        other => other,
+                    };
+                    connection.send(&DxpMessage::GameEnd { reason: reply, stop_session }).await?;
+                }
+                return Ok(stop_session);
+            }
+            Ok(DxpMessage::BackupRequest { .. }) => {
+                connection.send(&DxpMessage::BackupAcceptance(DxpBackupAcceptance::NotSupported)).await?;
+            }
+            Ok(DxpMessage::Chat(text)) => println!("Chat: {}", text),
+            Ok(other) => eprintln!("Unexpected message during the game: {}", other),
+            Err(error @ (DxpError::InvalidMessage(_) | DxpError::UnknownMessageType(_))) => eprintln!("{}", error),
+            Err(error) => return Err(error),
+        }
+    }
+}
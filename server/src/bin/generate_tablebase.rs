@@ -0,0 +1,36 @@
+//! Generates the endgame tablebase, which the server loads at startup:
+//!
+//!     cargo run --release --bin generate_tablebase -- [max pieces] [output path]
+//!
+//! Four pieces take a few minutes, every additional piece takes a lot longer.
+use std::time::Instant;
+
+use app::game::tablebase::Tablebase;
+
+const DEFAULT_MAX_PIECES: u8 = 4;
+const DEFAULT_OUTPUT_PATH: &str = "tablebase.bin";
+
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let max_pieces = match args.next().map(|arg| arg.parse::<u8>()) {
+        None => DEFAULT_MAX_PIECES,
+        Some(Ok(max_pieces)) if max_pieces >= 2 => max_pieces,
+        Some(_) => {
+            eprintln!("The maximum number of pieces must be a number of at least 2.");
+            std::process::exit(1);
+        }
+    };
+    let output_path = args.next().unwrap_or_else(|| DEFAULT_OUTPUT_PATH.to_string());
+
+    let start = Instant::now();
+    let tablebase = Tablebase::generate(max_pieces, |materials| {
+        println!("Solved {:?} after {:.1?}", materials, start.elapsed());
+    });
+    let bytes = tablebase.to_bytes();
+    if let Err(error) = std::fs::write(&output_path, &bytes) {
+        eprintln!("Cannot write {}: {}", output_path, error);
+        std::process::exit(1);
+    }
+    println!("Wrote {} bytes to {} after {:.1?}", bytes.len(), output_path, start.elapsed());
+}
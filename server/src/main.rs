@@ -6,14 +6,44 @@ use leptos::*;
 use leptos_axum::{generate_route_list, LeptosRoutes};
 use std::sync::Arc;
 
-use app::pages::game_page::{handle_players_websocket, GameState};
+use app::pages::game_page::{
+    handle_game_fen_request, handle_game_pdn_request, handle_game_record_request, handle_players_websocket, GameState,
+};
+use app::components::analysis::run_analysis_scheduler;
+use app::components::clock::run_clock_scheduler;
+use app::components::dxp::{run_dxp_server, DXP_ADDRESS_VARIABLE};
+use app::components::hub::{HubEngine, HUB_ANALYSIS_COMMAND_VARIABLE, HUB_BOT_COMMAND_VARIABLE};
+use app::components::player::{run_bot_scheduler, run_seat_scheduler};
+use app::game::opening_book::OpeningBook;
+use app::game::tablebase::Tablebase;
 
 pub mod fileserv;
 
+const OPENING_BOOK_PATH_VARIABLE: &str = "OPENING_BOOK_PATH";
+const DEFAULT_OPENING_BOOK_PATH: &str = "opening_book.bin";
+const TABLEBASE_PATH_VARIABLE: &str = "TABLEBASE_PATH";
+const DEFAULT_TABLEBASE_PATH: &str = "tablebase.bin";
+
 #[tokio::main]
 async fn main() {    
     simple_logger::init_with_level(log::Level::Debug).expect("couldn't initialize logging");
-    let game_state = Arc::new(GameState::new());
+    let opening_book = load_file(OPENING_BOOK_PATH_VARIABLE, DEFAULT_OPENING_BOOK_PATH, "opening book", OpeningBook::from_bytes)
+        .map(Arc::new);
+    let tablebase = load_file(TABLEBASE_PATH_VARIABLE, DEFAULT_TABLEBASE_PATH, "endgame tablebase", Tablebase::from_bytes)
+        .map(Arc::new);
+    let game_state = Arc::new(GameState::new(tablebase.clone()));
+    tokio::spawn(run_clock_scheduler(game_state.clone()));
+    tokio::spawn(run_seat_scheduler(game_state.clone()));
+    // Moves and analyses come from the built-in engine, unless a Hub engine is configured.
+    //  Each task gets its own engine process, so analyses do not hold up the bots.
+    let hub_bot_engine = load_hub_engine(HUB_BOT_COMMAND_VARIABLE, "bot moves");
+    let hub_analysis_engine = load_hub_engine(HUB_ANALYSIS_COMMAND_VARIABLE, "game analyses");
+    tokio::spawn(run_bot_scheduler(game_state.clone(), opening_book.clone(), tablebase.clone(), hub_bot_engine));
+    tokio::spawn(run_analysis_scheduler(game_state.clone(), opening_book, tablebase, hub_analysis_engine));
+    // External engines can only play matches against the bot, if an address is configured.
+    if let Ok(dxp_address) = std::env::var(DXP_ADDRESS_VARIABLE) {
+        tokio::spawn(run_dxp_server(game_state.clone(), dxp_address));
+    }
 
     // Setting get_configuration(None) means we'll be using cargo-leptos's env values
     // For deployment these variables are:
@@ -30,6 +60,9 @@ async fn main() {
         .leptos_routes(&leptos_options, routes, App)
         //.route("/ssws", get(server_signal_websocket))
         .route("/players", get(players_websocket))
+        .route("/games/:id/record", get(handle_game_record_request))
+        .route("/games/:id/pdn", get(handle_game_pdn_request))
+        .route("/games/:id/fen", get(handle_game_fen_request))
         .fallback(file_and_error_handler)
         .layer(Extension(game_state))
         .with_state(leptos_options);
@@ -43,6 +76,43 @@ async fn main() {
         .unwrap();
 }
 
+/// Reads a file written by the `build_opening_book` or `generate_tablebase` binaries from
+///  the path in the environment variable. The bots play without it, if the file is missing
+///  or broken.
+fn load_file<T, E: std::fmt::Display>(
+    path_variable: &str,
+    default_path: &str,
+    description: &str,
+    from_bytes: impl FnOnce(&[u8]) -> Result<T, E>,
+) -> Option<T> {
+    let path = std::env::var(path_variable).unwrap_or_else(|_| default_path.to_string());
+    let bytes = match std::fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            log::info!("No {} loaded from {}: {}", description, path, error);
+            return None;
+        }
+    };
+    match from_bytes(&bytes) {
+        Ok(value) => {
+            log::info!("Loaded {} from {}", description, path);
+            Some(value)
+        }
+        Err(error) => {
+            log::error!("Cannot load {} from {}: {}", description, path, error);
+            None
+        }
+    }
+}
+
+/// The Hub engine with the command line in the environment variable. It is started on its
+///  first search, so a broken command line shows up in the log of the first game.
+fn load_hub_engine(command_variable: &str, description: &str) -> Option<Arc<HubEngine>> {
+    let hub_engine = HubEngine::from_variable(command_variable)?;
+    log::info!("Using Hub engine '{}' for {}", hub_engine.command_line(), description);
+    Some(Arc::new(hub_engine))
+}
+
 async fn players_websocket(
     ws: axum::extract::WebSocketUpgrade,
     Extension(game_state): Extension<Arc<GameState>>,